@@ -1,38 +1,24 @@
 #![cfg_attr(not(any(feature = "library", test)), no_std)]
 #![cfg_attr(not(test), no_main)]
+#![deny(clippy::panic)]
 
 #[cfg(any(feature = "library", test))]
 extern crate alloc;
 
-#[cfg(not(any(feature = "library", test)))]
-ckb_std::entry!(program_entry);
-#[cfg(not(any(feature = "library", test)))]
-// By default, the following heap configuration is used:
-// * 16KB fixed heap
-// * 1.2MB(rounded up to be 16-byte aligned) dynamic heap
-// * Minimal memory block in dynamic heap is 64 bytes
-// For more details, please refer to ckb-std's default_alloc macro
-// and the buddy-alloc alloc implementation.
-ckb_std::default_alloc!(16384, 1258306, 64);
-
-use ckb_hash::new_blake2b;
 use ckb_std::{
     ckb_constants::Source,
     ckb_types::prelude::*,
     debug,
     high_level::{load_cell_data, load_witness_args},
 };
-use common::schema::distribution::{ClaimWitness, DistributionCellData};
+use common::{
+    merkle::{verify_merkle_proof, witness_leaf},
+    schema::distribution::{ClaimWitness, DistributionCellData},
+    version::parse_distribution_data,
+};
 use distribution_lock::error::{BizError, Error};
 
-const MAX_MERKLE_PROOF_SIBLINGS: usize = 64;
-
-pub fn program_entry() -> i8 {
-    match entry() {
-        Ok(()) => 0,
-        Err(err) => err.into(),
-    }
-}
+common::contract_entry!(entry);
 
 fn entry() -> Result<(), Error> {
     debug!("distribution lock contract is executing");
@@ -41,8 +27,8 @@ fn entry() -> Result<(), Error> {
         Ok(witness_args) => {
             // Witness is present: this is a CLAIM action.
             let dist_data_bytes = load_cell_data(0, Source::GroupInput)?;
-            let dist_data = DistributionCellData::from_slice(&dist_data_bytes)
-                .map_err(|_| BizError::DistributionDataInvalid)?;
+            let dist_data = parse_distribution_data(&dist_data_bytes)
+                .ok_or(BizError::DistributionDataInvalid)?;
 
             let witness_args_bytes = witness_args
                 .lock()
@@ -53,7 +39,7 @@ fn entry() -> Result<(), Error> {
             let claim_witness = ClaimWitness::from_slice(&witness_args_bytes)
                 .map_err(|_| BizError::WitnessDataInvalid)?;
 
-            verify_merkle_proof(&dist_data, &claim_witness)
+            verify_claim(&dist_data, &claim_witness)
         }
         Err(_) => {
             // No witness: this is a RECLAMATION action.
@@ -66,39 +52,19 @@ fn entry() -> Result<(), Error> {
     }
 }
 
-fn verify_merkle_proof(
-    dist_data: &DistributionCellData,
-    witness: &ClaimWitness,
-) -> Result<(), Error> {
-    // Prevent cycle exhaustion attacks by limiting proof length.
-    if witness.merkle_proof().len() > MAX_MERKLE_PROOF_SIBLINGS {
-        Err(BizError::MerkleProofInvalid)?;
-    }
-
-    // Create the leaf hash from the proof cell outpoint and subscriber lock hash
-    let mut leaf_hasher = new_blake2b();
-    leaf_hasher.update(&witness.proof_cell_out_point().as_bytes());
-    leaf_hasher.update(&witness.subscriber_lock_hash().as_bytes());
-    let mut leaf_hash = [0u8; 32];
-    leaf_hasher.finalize(&mut leaf_hash);
-
-    // Verify the merkle path
-    let mut computed_hash = leaf_hash;
-    for sibling_hash in witness.merkle_proof().into_iter() {
-        // Compute parent hash by concatenating and hashing the two child hashes
-        let mut parent_hasher = new_blake2b();
-        if computed_hash.as_ref() < sibling_hash.as_slice() {
-            parent_hasher.update(&computed_hash);
-            parent_hasher.update(&sibling_hash.as_bytes());
-        } else {
-            parent_hasher.update(&sibling_hash.as_bytes());
-            parent_hasher.update(&computed_hash);
-        }
-        parent_hasher.finalize(&mut computed_hash);
-    }
+fn verify_claim(dist_data: &DistributionCellData, witness: &ClaimWitness) -> Result<(), Error> {
+    // Create the leaf hash from the proof cell Type ID and subscriber lock hash,
+    // or the hash of the revealed secret for a private claim, followed by the
+    // referrer lock hash and bonus amount when the claim has a referral
+    let leaf_hash = witness_leaf(witness);
 
-    // Final computed hash must match the merkle root in the distribution cell
-    if computed_hash != dist_data.merkle_root().as_slice() {
+    // Verify the merkle path against the merkle root in the distribution cell.
+    // Overlong proofs are rejected to prevent cycle exhaustion attacks.
+    if !verify_merkle_proof(
+        dist_data.merkle_root().as_slice(),
+        leaf_hash,
+        &witness.merkle_proof(),
+    ) {
         Err(BizError::MerkleProofInvalid)?;
     }
 
@@ -1,6 +1,7 @@
 #![cfg_attr(not(feature = "library"), no_std)]
 #![allow(special_module_name)]
 #![allow(unused_attributes)]
+#![deny(clippy::panic)]
 #[cfg(feature = "library")]
 mod main;
 #[cfg(feature = "library")]
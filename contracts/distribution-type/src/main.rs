@@ -1,45 +1,42 @@
 #![cfg_attr(not(any(feature = "library", test)), no_std)]
 #![cfg_attr(not(test), no_main)]
+#![deny(clippy::panic)]
 
 #[cfg(any(feature = "library", test))]
 extern crate alloc;
 
-#[cfg(not(any(feature = "library", test)))]
-ckb_std::entry!(program_entry);
-#[cfg(not(any(feature = "library", test)))]
-// By default, the following heap configuration is used:
-// * 16KB fixed heap
-// * 1.2MB(rounded up to be 16-byte aligned) dynamic heap
-// * Minimal memory block in dynamic heap is 64 bytes
-// For more details, please refer to ckb-std's default_alloc macro
-// and the buddy-alloc alloc implementation.
-ckb_std::default_alloc!(16384, 1258306, 64);
-
+use ckb_hash::new_blake2b;
 use ckb_std::{
     ckb_constants::Source,
+    ckb_types::packed::CellOutput,
     ckb_types::prelude::*,
     debug,
     high_level::{
-        load_cell, load_cell_data, load_cell_lock_hash, load_input_out_point, load_input_since,
+        load_cell, load_cell_data, load_cell_lock_hash, load_cell_type, load_input_since,
         load_script, load_witness_args, QueryIter,
     },
     since::Since,
 };
 use common::{
+    base::Byte32,
+    bitmap::is_bit_set,
+    contract::find_unique_output,
+    merkle::verify_merkle_proof,
+    proof::is_subscriber_lock,
     schema::{
         distribution::{ClaimWitness, DistributionCellData},
-        proof::ProofCellData,
+        entity::EntityCellData,
+        proof::{ProofCellData, ProofTypeArgs},
+        vesting::VestingLockArgs,
     },
+    script::ScriptRef,
+    version::{parse_distribution_data, parse_proof_data, to_versioned_bytes},
     NULL_HASH,
 };
 use distribution_type::error::{BizError, Error};
+use molecule::prelude::{Builder, Entity};
 
-pub fn program_entry() -> i8 {
-    match entry() {
-        Ok(()) => 0,
-        Err(err) => err.into(),
-    }
-}
+common::contract_entry!(entry);
 
 fn entry() -> Result<(), Error> {
     debug!("distribution type contract is executing");
@@ -60,8 +57,8 @@ fn entry() -> Result<(), Error> {
             }
 
             let dist_data_bytes = load_cell_data(0, Source::GroupInput)?;
-            let dist_data = DistributionCellData::from_slice(&dist_data_bytes)
-                .map_err(|_| BizError::ShardCreationDataInvalid)?;
+            let dist_data = parse_distribution_data(&dist_data_bytes)
+                .ok_or(BizError::ShardCreationDataInvalid)?;
 
             let witness_args = load_witness_args(0, Source::GroupInput)?;
             let witness_args_bytes = witness_args
@@ -80,8 +77,8 @@ fn entry() -> Result<(), Error> {
             let since = load_input_since(0, Source::GroupInput)?;
 
             let dist_data_bytes = load_cell_data(0, Source::GroupInput)?;
-            let dist_data = DistributionCellData::from_slice(&dist_data_bytes)
-                .map_err(|_| BizError::ShardCreationDataInvalid)?;
+            let dist_data = parse_distribution_data(&dist_data_bytes)
+                .ok_or(BizError::ShardCreationDataInvalid)?;
 
             verify_destruction(&dist_data, since)
         }
@@ -91,8 +88,8 @@ fn entry() -> Result<(), Error> {
 
 fn verify_creation(outputs_count: usize) -> Result<(), Error> {
     let first_shard_data_bytes = load_cell_data(0, Source::GroupOutput)?;
-    let first_shard_data = DistributionCellData::from_slice(&first_shard_data_bytes)
-        .map_err(|_| BizError::ShardCreationDataInvalid)?;
+    let first_shard_data = parse_distribution_data(&first_shard_data_bytes)
+        .ok_or(BizError::ShardCreationDataInvalid)?;
 
     let campaign_id = first_shard_data.campaign_id();
     if campaign_id.as_slice() == NULL_HASH {
@@ -126,17 +123,39 @@ fn verify_creation(outputs_count: usize) -> Result<(), Error> {
         Err(BizError::ShardCreationDataInvalid)?;
     }
 
+    let entity_registry = first_shard_data.entity_registry();
+    if entity_registry.code_hash().as_slice() == NULL_HASH {
+        Err(BizError::ShardCreationDataInvalid)?;
+    }
+    let entity_creator_lock_hash = first_shard_data.entity_creator_lock_hash();
+
+    verify_remaining_claims(0, &first_shard_data)?;
+
+    let vesting = first_shard_data.vesting();
+    let vesting_start_epoch: u64 = vesting.start_epoch().unpack();
+    let vesting_end_epoch: u64 = vesting.end_epoch().unpack();
+    if vesting.lock_code_hash().as_slice() == NULL_HASH {
+        let lock_hash_type: u8 = vesting.lock_hash_type().into();
+        if lock_hash_type != 0 || vesting_start_epoch != 0 || vesting_end_epoch != 0 {
+            Err(BizError::ShardCreationDataInvalid)?;
+        }
+    } else if vesting_end_epoch <= vesting_start_epoch {
+        Err(BizError::ShardCreationDataInvalid)?;
+    }
+
     if outputs_count > 1 {
         for i in 1..outputs_count {
             let current_shard_data_bytes = load_cell_data(i, Source::GroupOutput)?;
-            let current_shard_data = DistributionCellData::from_slice(&current_shard_data_bytes)
-                .map_err(|_| BizError::ShardCreationDataInvalid)?;
+            let current_shard_data = parse_distribution_data(&current_shard_data_bytes)
+                .ok_or(BizError::ShardCreationDataInvalid)?;
 
             if current_shard_data.campaign_id().as_bytes() != campaign_id.as_bytes() {
                 Err(BizError::ShardCreationDataInconsistent)?;
             }
             if current_shard_data.proof_script_code_hash().as_bytes()
                 != proof_script_code_hash.as_bytes()
+                || current_shard_data.proof_script_hash_type().as_slice()
+                    != first_shard_data.proof_script_hash_type().as_slice()
             {
                 Err(BizError::ShardCreationDataInconsistent)?;
             }
@@ -151,31 +170,161 @@ fn verify_creation(outputs_count: usize) -> Result<(), Error> {
             if current_shard_data.deadline().as_bytes() != deadline.as_bytes() {
                 Err(BizError::ShardCreationDataInconsistent)?;
             }
+            if current_shard_data.vesting().as_bytes() != vesting.as_bytes() {
+                Err(BizError::ShardCreationDataInconsistent)?;
+            }
+            if current_shard_data.entity_root().as_bytes()
+                != first_shard_data.entity_root().as_bytes()
+            {
+                Err(BizError::ShardCreationDataInconsistent)?;
+            }
+            if current_shard_data.entity_registry().as_slice() != entity_registry.as_slice()
+                || current_shard_data.entity_creator_lock_hash().as_slice()
+                    != entity_creator_lock_hash.as_slice()
+            {
+                Err(BizError::ShardCreationDataInconsistent)?;
+            }
             if current_shard_data.merkle_root().as_slice() == NULL_HASH {
                 Err(BizError::ShardCreationDataInconsistent)?;
             }
+
+            verify_remaining_claims(i, &current_shard_data)?;
         }
     }
 
     Ok(())
 }
 
-fn verify_and_get_proof_cell_capacity(
+fn verify_remaining_claims(index: usize, shard_data: &DistributionCellData) -> Result<(), Error> {
+    // The shard must be able to pay out every remaining claim plus its referral
+    // budget, and nothing more, so the claim counter reaches zero exactly when
+    // the capacity does.
+    let remaining_claims: u32 = shard_data.remaining_claims().unpack();
+    let reward_amount: u64 = shard_data.uniform_reward_amount().unpack();
+    let referral_budget: u64 = shard_data.referral_budget().unpack();
+    let shard_capacity: u64 = load_cell(index, Source::GroupOutput)?.capacity().unpack();
+
+    if remaining_claims == 0
+        || (remaining_claims as u128 * reward_amount as u128 + referral_budget as u128)
+            != shard_capacity as u128
+    {
+        Err(BizError::RemainingClaimsInvalid)?;
+    }
+
+    Ok(())
+}
+
+/// Returns the lock hash the claim's reward is paid to: the destination chosen
+/// in a private claim, or the subscriber otherwise.
+fn payout_lock_hash(claim_witness: &ClaimWitness) -> Byte32 {
+    match claim_witness.private_claim().to_opt() {
+        Some(private_claim) => private_claim.destination_lock_hash(),
+        None => claim_witness.subscriber_lock_hash(),
+    }
+}
+
+/// Returns the lock args a vested reward cell must carry, or `None` when
+/// rewards are paid out immediately to the payout lock.
+fn vesting_lock_args(
+    dist_data: &DistributionCellData,
+    claim_witness: &ClaimWitness,
+) -> Option<VestingLockArgs> {
+    let vesting = dist_data.vesting();
+    if vesting.lock_code_hash().as_slice() == NULL_HASH {
+        return None;
+    }
+
+    Some(
+        VestingLockArgs::new_builder()
+            .beneficiary_lock_hash(payout_lock_hash(claim_witness))
+            .locked_amount(dist_data.uniform_reward_amount())
+            .start_epoch(vesting.start_epoch())
+            .end_epoch(vesting.end_epoch())
+            .build(),
+    )
+}
+
+fn is_reward_cell(
+    dist_data: &DistributionCellData,
+    output_cell: &CellOutput,
+    output_lock_hash: &[u8; 32],
+    expected_reward_lock_hash: &[u8; 32],
+    expected_reward_lock_args: &Option<VestingLockArgs>,
+    expected_reward_capacity: u64,
+) -> bool {
+    let output_capacity: u64 = output_cell.capacity().unpack();
+    if output_capacity != expected_reward_capacity || output_cell.type_().to_opt().is_some() {
+        return false;
+    }
+
+    match expected_reward_lock_args {
+        // The reward vests: it must be locked by the vesting lock, releasing to the subscriber.
+        Some(args) => {
+            let vesting = dist_data.vesting();
+            let vesting_lock = ScriptRef {
+                code_hash: vesting.lock_code_hash().into(),
+                hash_type: vesting.lock_hash_type().into(),
+            };
+            let lock = output_cell.lock();
+            vesting_lock.matches(&lock) && lock.args().raw_data() == args.as_slice()
+        }
+        // The reward is paid out immediately to the subscriber's own lock.
+        None => output_lock_hash == expected_reward_lock_hash,
+    }
+}
+
+/// Returns the referrer's lock hash and bonus committed in the claim witness,
+/// or `None` when the claim has no referral.
+fn referral_payout(claim_witness: &ClaimWitness) -> Result<Option<([u8; 32], u64)>, Error> {
+    let referral = match claim_witness.referral().to_opt() {
+        Some(referral) => referral,
+        None => return Ok(None),
+    };
+
+    let referrer_lock_hash: [u8; 32] = referral.referrer_lock_hash().into();
+    let bonus_amount: u64 = referral.bonus_amount().unpack();
+    if referrer_lock_hash == NULL_HASH
+        || referral.referrer_lock_hash().as_slice() == payout_lock_hash(claim_witness).as_slice()
+        || bonus_amount == 0
+    {
+        Err(BizError::ReferralInvalid)?;
+    }
+
+    Ok(Some((referrer_lock_hash, bonus_amount)))
+}
+
+/// Checks whether an output is a plain cell paying exactly `expected_capacity`
+/// to `expected_lock_hash`.
+fn is_payout_cell(
+    output_cell: &CellOutput,
+    output_lock_hash: &[u8; 32],
+    expected_lock_hash: &[u8; 32],
+    expected_capacity: u64,
+) -> bool {
+    let output_capacity: u64 = output_cell.capacity().unpack();
+    output_lock_hash == expected_lock_hash
+        && output_capacity == expected_capacity
+        && output_cell.type_().to_opt().is_none()
+}
+
+/// Verifies the proof cell backing the claim and returns the capacity it
+/// releases to the reward cell: all of it when the proof is consumed, or none
+/// when it is kept to claim from other campaigns.
+fn verify_proof_cell(
     dist_data: &DistributionCellData,
     claim_witness: &ClaimWitness,
 ) -> Result<u64, Error> {
-    let expected_proof_code_hash = dist_data.proof_script_code_hash();
+    let expected_proof_script = ScriptRef {
+        code_hash: dist_data.proof_script_code_hash().into(),
+        hash_type: dist_data.proof_script_hash_type().into(),
+    };
 
     let proof_indices = QueryIter::new(load_cell, Source::Input)
         .enumerate()
-        .filter_map(|(idx, cell)| {
-            let code_hash_opt = cell.type_().to_opt().map(|s| s.code_hash().as_bytes());
-            if Some(expected_proof_code_hash.as_bytes()) == code_hash_opt {
-                let capacity: u64 = cell.capacity().unpack();
-                Some((idx, capacity, cell.calc_lock_hash()))
-            } else {
-                None
-            }
+        .filter(|(_idx, cell)| {
+            cell.type_()
+                .to_opt()
+                .is_some_and(|script| expected_proof_script.matches(&script))
         })
         .collect::<alloc::vec::Vec<_>>();
 
@@ -183,49 +332,215 @@ fn verify_and_get_proof_cell_capacity(
         Err(BizError::ProofCellCountInvalid)?;
     }
 
-    let (index, proof_capacity, proof_lock_hash) = proof_indices[0].clone();
-
-    let actual_proof_outpoint = load_input_out_point(index, Source::Input)?;
-    if actual_proof_outpoint.as_bytes() != claim_witness.proof_cell_out_point().as_bytes() {
-        Err(BizError::ProofOutPointMismatch)?;
+    let (index, proof_cell) = proof_indices[0].clone();
+    let proof_capacity: u64 = proof_cell.capacity().unpack();
+    let proof_lock_hash = proof_cell.calc_lock_hash();
+    let proof_type_script = proof_cell
+        .type_()
+        .to_opt()
+        .ok_or(BizError::ProofDataInvalid)?;
+
+    // The proof args are its Type ID followed by the entity registry that
+    // vouched for its entity and the shard script it accepts claims from, and
+    // nothing else.
+    let proof_args = ProofTypeArgs::from_slice(&proof_type_script.args().raw_data())
+        .map_err(|_| BizError::ProofScriptArgsInvalid)?;
+    if proof_args.type_id().as_slice() != claim_witness.proof_type_id().as_slice() {
+        Err(BizError::ProofTypeIdMismatch)?;
+    }
+    if !ScriptRef::from(proof_args.distribution_type()).matches(&load_script()?) {
+        Err(BizError::ProofDistributionTypeMismatch)?;
     }
 
     let proof_cell_data_bytes = load_cell_data(index, Source::Input)?;
-    let proof_data = ProofCellData::from_slice(&proof_cell_data_bytes)
-        .map_err(|_| BizError::ProofDataInvalid)?;
+    let proof_data = parse_proof_data(&proof_cell_data_bytes).ok_or(BizError::ProofDataInvalid)?;
+    verify_entity_registry(dist_data, &proof_args, &proof_data)?;
+
+    let campaign_index = proof_data
+        .campaign_ids()
+        .into_iter()
+        .position(|campaign_id| campaign_id.as_slice() == dist_data.campaign_id().as_slice())
+        .ok_or(BizError::ProofCampaignIdMismatch)?;
 
-    if proof_data.campaign_id().as_bytes() != dist_data.campaign_id().as_bytes() {
-        Err(BizError::ProofCampaignIdMismatch)?;
+    if is_bit_set(&proof_data.claimed_campaigns().raw_data(), campaign_index) {
+        Err(BizError::ProofCampaignAlreadyClaimed)?;
     }
 
-    if proof_data.subscriber_lock_hash().as_bytes()
-        != claim_witness.subscriber_lock_hash().as_bytes()
-    {
-        Err(BizError::ProofSubscriberLockHashMismatch)?;
+    verify_proof_reveal(&proof_data, claim_witness)?;
+    verify_entity_targeted(dist_data, &proof_data, claim_witness)?;
+
+    // A private claim is bound to the proof by its Type ID and the secret alone,
+    // so the proof owner does not have to match anything in the witness.
+    if claim_witness.private_claim().is_none() {
+        if proof_data.subscriber_lock_hash().as_bytes()
+            != claim_witness.subscriber_lock_hash().as_bytes()
+        {
+            Err(BizError::ProofSubscriberLockHashMismatch)?;
+        }
+
+        if !is_subscriber_lock(
+            &proof_args,
+            &proof_cell.lock(),
+            proof_lock_hash.as_slice(),
+            claim_witness.subscriber_lock_hash().as_slice(),
+        ) {
+            Err(BizError::ProofLockHashMismatch)?;
+        }
+    }
+
+    // The proof is either consumed, or kept with this campaign marked as claimed
+    // so it cannot be used for the same campaign again.
+    let proof_type_hash = proof_type_script.calc_script_hash();
+    let kept_proof_index = QueryIter::new(load_cell, Source::Output).position(|cell| {
+        cell.type_()
+            .to_opt()
+            .is_some_and(|script| script.calc_script_hash() == proof_type_hash)
+    });
+
+    match kept_proof_index {
+        None => Ok(proof_capacity),
+        Some(output_index) => {
+            let output_data_bytes = load_cell_data(output_index, Source::Output)?;
+            let output_data =
+                parse_proof_data(&output_data_bytes).ok_or(BizError::ProofDataInvalid)?;
+            if !is_bit_set(&output_data.claimed_campaigns().raw_data(), campaign_index) {
+                Err(BizError::ProofCampaignNotMarked)?;
+            }
+            Ok(0)
+        }
+    }
+}
+
+fn verify_proof_reveal(
+    proof_data: &ProofCellData,
+    claim_witness: &ClaimWitness,
+) -> Result<(), Error> {
+    // The revealed proof must open the commitment made when the proof cell was
+    // created, which binds it to the original subscriber.
+    let proof_reveal = claim_witness.proof_reveal();
+    if proof_reveal.proof().as_slice() == NULL_HASH {
+        Err(BizError::ProofRevealMismatch)?;
+    }
+
+    let mut hasher = new_blake2b();
+    hasher.update(proof_reveal.proof().as_slice());
+    hasher.update(proof_data.subscriber_lock_hash().as_slice());
+    hasher.update(proof_reveal.salt().as_slice());
+    let mut proof_commit = [0u8; 32];
+    hasher.finalize(&mut proof_commit);
+
+    if proof_commit != proof_data.proof_commit().as_slice() {
+        Err(BizError::ProofRevealMismatch)?;
+    }
+
+    Ok(())
+}
+
+/// Checks that the proof was vouched for by the campaign's entity registry and,
+/// when the campaign is scoped to a creator, that the registry cell of the
+/// proof's entity, provided as a cell dep, was published by that creator.
+fn verify_entity_registry(
+    dist_data: &DistributionCellData,
+    proof_args: &ProofTypeArgs,
+    proof_data: &ProofCellData,
+) -> Result<(), Error> {
+    // Shards created with data version 1 pin no registry, and accept proofs
+    // from any registry as they did then.
+    if dist_data.entity_registry().code_hash().as_slice() == NULL_HASH {
+        return Ok(());
+    }
+
+    if proof_args.entity_registry().as_slice() != dist_data.entity_registry().as_slice() {
+        Err(BizError::EntityRegistryMismatch)?;
+    }
+
+    let creator_lock_hash = dist_data.entity_creator_lock_hash();
+    if creator_lock_hash.as_slice() == NULL_HASH {
+        return Ok(());
+    }
+
+    // A registry cell's Type ID is its entity id.
+    let entity_registry = ScriptRef::from(dist_data.entity_registry());
+    let entity_index = QueryIter::new(load_cell_type, Source::CellDep)
+        .position(|type_script| {
+            type_script.is_some_and(|script| {
+                entity_registry.matches(&script)
+                    && script.args().raw_data().as_ref() == proof_data.entity_id().as_slice()
+            })
+        })
+        .ok_or(BizError::EntityCreatorMismatch)?;
+
+    let entity_data = EntityCellData::from_slice(&load_cell_data(entity_index, Source::CellDep)?)
+        .map_err(|_| BizError::EntityCreatorMismatch)?;
+    if entity_data.creator_lock_hash().as_slice() != creator_lock_hash.as_slice() {
+        Err(BizError::EntityCreatorMismatch)?;
+    }
+
+    Ok(())
+}
+
+fn verify_entity_targeted(
+    dist_data: &DistributionCellData,
+    proof_data: &ProofCellData,
+    claim_witness: &ClaimWitness,
+) -> Result<(), Error> {
+    // A shard without an entity root accepts proofs for any entity.
+    let entity_root = dist_data.entity_root();
+    if entity_root.as_slice() == NULL_HASH {
+        return Ok(());
     }
 
-    if proof_lock_hash.as_bytes() != claim_witness.subscriber_lock_hash().as_bytes() {
-        Err(BizError::ProofLockHashMismatch)?;
+    // The entity ids are the leaves themselves, so a single targeted entity
+    // is its own root and needs an empty path.
+    if !verify_merkle_proof(
+        entity_root.as_slice(),
+        proof_data.entity_id().into(),
+        &claim_witness.entity_proof(),
+    ) {
+        Err(BizError::EntityNotTargeted)?;
     }
 
-    Ok(proof_capacity)
+    Ok(())
 }
 
 fn verify_claim_update(
     dist_data: &DistributionCellData,
     claim_witness: &ClaimWitness,
 ) -> Result<(), Error> {
-    let proof_cell_capacity = verify_and_get_proof_cell_capacity(dist_data, claim_witness)?;
+    let released_proof_capacity = verify_proof_cell(dist_data, claim_witness)?;
 
     let input_dist_cell = load_cell(0, Source::GroupInput)?;
     let input_capacity: u64 = input_dist_cell.capacity().unpack();
     let reward_amount_unpacked: u64 = dist_data.uniform_reward_amount().unpack();
-    let expected_reward_capacity = reward_amount_unpacked + proof_cell_capacity;
+    let expected_reward_capacity = reward_amount_unpacked + released_proof_capacity;
 
-    let expected_reward_lock_hash: [u8; 32] = claim_witness.subscriber_lock_hash().into();
+    // A normal claim must leave at least one claim in the shard; the last one is a final claim.
+    let remaining_claims: u32 = dist_data.remaining_claims().unpack();
+    if remaining_claims < 2 {
+        Err(BizError::RemainingClaimsInvalid)?;
+    }
+
+    // A referral bonus is drawn from the shard's referral budget.
+    let referral = referral_payout(claim_witness)?;
+    let bonus_amount = referral.map_or(0, |(_, bonus_amount)| bonus_amount);
+    let referral_budget: u64 = dist_data.referral_budget().unpack();
+    if bonus_amount > referral_budget {
+        Err(BizError::ReferralBudgetExceeded)?;
+    }
+
+    let expected_output_data = dist_data
+        .clone()
+        .as_builder()
+        .remaining_claims((remaining_claims - 1).pack())
+        .referral_budget((referral_budget - bonus_amount).pack())
+        .build();
+
+    let expected_reward_lock_hash: [u8; 32] = payout_lock_hash(claim_witness).into();
+    let expected_reward_lock_args = vesting_lock_args(dist_data, claim_witness);
     let script_hash = load_script()?.calc_script_hash();
 
     let mut reward_cell_found = false;
+    let mut referral_cell_found = false;
     let mut new_shard_cell_found = false;
 
     // Iterate over all outputs to find the required reward cell and new shard cell.
@@ -235,16 +550,33 @@ fn verify_claim_update(
         let output_lock_hash = load_cell_lock_hash(i, Source::Output)?;
         let output_capacity: u64 = output_cell.capacity().unpack();
 
-        if output_lock_hash == expected_reward_lock_hash
-            && output_capacity == expected_reward_capacity
-            && output_cell.type_().to_opt().is_none()
-        {
+        if is_reward_cell(
+            dist_data,
+            &output_cell,
+            &output_lock_hash,
+            &expected_reward_lock_hash,
+            &expected_reward_lock_args,
+            expected_reward_capacity,
+        ) {
             // This is the claimant's reward cell.
             if reward_cell_found {
                 // Cannot have more than one reward cell.
                 Err(BizError::ClaimTransactionInvalid)?;
             }
             reward_cell_found = true;
+        } else if referral.is_some_and(|(referrer_lock_hash, bonus_amount)| {
+            is_payout_cell(
+                &output_cell,
+                &output_lock_hash,
+                &referrer_lock_hash,
+                bonus_amount,
+            )
+        }) {
+            // This is the referrer's bonus cell.
+            if referral_cell_found {
+                Err(BizError::ClaimTransactionInvalid)?;
+            }
+            referral_cell_found = true;
         } else if let Some(type_script) = output_cell.type_().to_opt() {
             if type_script.calc_script_hash() == script_hash {
                 // This is the updated distribution shard cell.
@@ -254,10 +586,11 @@ fn verify_claim_update(
                 if output_cell.lock() != input_dist_cell.lock() {
                     Err(BizError::ShardTypeScriptImmutable)?;
                 }
-                if load_cell_data(i, Source::Output)? != load_cell_data(0, Source::GroupInput)? {
+                // Only the claim counter may change, and it must go down by one.
+                if load_cell_data(i, Source::Output)? != to_versioned_bytes(&expected_output_data) {
                     Err(BizError::ShardDataImmutable)?;
                 }
-                if output_capacity != input_capacity - reward_amount_unpacked {
+                if output_capacity != input_capacity - reward_amount_unpacked - bonus_amount {
                     Err(BizError::ShardCapacityUpdateInvalid)?;
                 }
                 new_shard_cell_found = true;
@@ -269,6 +602,9 @@ fn verify_claim_update(
     if !reward_cell_found || !new_shard_cell_found {
         Err(BizError::ClaimTransactionInvalid)?;
     }
+    if referral.is_some() && !referral_cell_found {
+        Err(BizError::ReferralPayoutMissing)?;
+    }
 
     Ok(())
 }
@@ -289,32 +625,77 @@ fn verify_destruction(dist_data: &DistributionCellData, since: u64) -> Result<()
             let claim_witness = ClaimWitness::from_slice(&witness_args_bytes)
                 .map_err(|_| BizError::WitnessDataInvalid)?;
 
-            let proof_cell_capacity =
-                verify_and_get_proof_cell_capacity(dist_data, &claim_witness)?;
+            let released_proof_capacity = verify_proof_cell(dist_data, &claim_witness)?;
             let input_capacity: u64 = load_cell(0, Source::GroupInput)?.capacity().unpack();
             let reward_amount_unpacked: u64 = dist_data.uniform_reward_amount().unpack();
-            if input_capacity != reward_amount_unpacked {
+            let referral_budget: u64 = dist_data.referral_budget().unpack();
+            if input_capacity as u128 != reward_amount_unpacked as u128 + referral_budget as u128 {
                 Err(BizError::FinalClaimCapacityInvalid)?;
             }
 
-            let expected_reward_lock_hash: [u8; 32] = claim_witness.subscriber_lock_hash().into();
-            let expected_reward_capacity = reward_amount_unpacked + proof_cell_capacity;
+            // The last claim pays its referral bonus, if any, and returns the rest of
+            // the referral budget to the admin.
+            let referral = referral_payout(&claim_witness)?;
+            let bonus_amount = referral.map_or(0, |(_, bonus_amount)| bonus_amount);
+            if bonus_amount > referral_budget {
+                Err(BizError::ReferralBudgetExceeded)?;
+            }
+            let unused_referral_budget = referral_budget - bonus_amount;
+            let admin_lock_hash: [u8; 32] = dist_data.admin_lock_hash().into();
+
+            let remaining_claims: u32 = dist_data.remaining_claims().unpack();
+            if remaining_claims != 1 {
+                Err(BizError::RemainingClaimsInvalid)?;
+            }
+
+            let expected_reward_lock_hash: [u8; 32] = payout_lock_hash(&claim_witness).into();
+            let expected_reward_lock_args = vesting_lock_args(dist_data, &claim_witness);
+            let expected_reward_capacity = reward_amount_unpacked + released_proof_capacity;
 
             let mut reward_cell_found = false;
+            let mut referral_cell_found = false;
+            let mut budget_refund_cell_found = false;
             for i in 0..QueryIter::new(load_cell, Source::Output).count() {
                 let output_cell = load_cell(i, Source::Output)?;
                 let output_lock_hash = load_cell_lock_hash(i, Source::Output)?;
-                let output_capacity: u64 = output_cell.capacity().unpack();
 
-                if output_lock_hash == expected_reward_lock_hash
-                    && output_capacity == expected_reward_capacity
-                    && output_cell.type_().to_opt().is_none()
-                {
+                if is_reward_cell(
+                    dist_data,
+                    &output_cell,
+                    &output_lock_hash,
+                    &expected_reward_lock_hash,
+                    &expected_reward_lock_args,
+                    expected_reward_capacity,
+                ) {
                     if reward_cell_found {
                         // Cannot have more than one reward cell.
                         Err(BizError::ClaimTransactionInvalid)?;
                     }
                     reward_cell_found = true;
+                } else if referral.is_some_and(|(referrer_lock_hash, bonus_amount)| {
+                    is_payout_cell(
+                        &output_cell,
+                        &output_lock_hash,
+                        &referrer_lock_hash,
+                        bonus_amount,
+                    )
+                }) {
+                    if referral_cell_found {
+                        Err(BizError::ClaimTransactionInvalid)?;
+                    }
+                    referral_cell_found = true;
+                } else if unused_referral_budget > 0
+                    && is_payout_cell(
+                        &output_cell,
+                        &output_lock_hash,
+                        &admin_lock_hash,
+                        unused_referral_budget,
+                    )
+                {
+                    if budget_refund_cell_found {
+                        Err(BizError::ClaimTransactionInvalid)?;
+                    }
+                    budget_refund_cell_found = true;
                 }
                 // Any other cell is considered a change cell and is ignored.
             }
@@ -322,6 +703,12 @@ fn verify_destruction(dist_data: &DistributionCellData, since: u64) -> Result<()
             if !reward_cell_found {
                 Err(BizError::RewardLockHashMismatch)?;
             }
+            if referral.is_some() && !referral_cell_found {
+                Err(BizError::ReferralPayoutMissing)?;
+            }
+            if unused_referral_budget > 0 && !budget_refund_cell_found {
+                Err(BizError::ReferralPayoutMissing)?;
+            }
         }
         Err(_) => {
             // Reclamation
@@ -343,28 +730,20 @@ fn verify_destruction(dist_data: &DistributionCellData, since: u64) -> Result<()
             let expected_admin_lock_hash: [u8; 32] = dist_data.admin_lock_hash().into();
             let input_capacity: u64 = load_cell(0, Source::GroupInput)?.capacity().unpack();
 
-            let mut reclamation_cell_found = false;
-            for i in 0..QueryIter::new(load_cell, Source::Output).count() {
-                let output_cell = load_cell(i, Source::Output)?;
-                let output_lock_hash = load_cell_lock_hash(i, Source::Output)?;
-                let output_capacity: u64 = output_cell.capacity().unpack();
-
-                if output_lock_hash == expected_admin_lock_hash
-                    && output_capacity == input_capacity
-                    && output_cell.type_().to_opt().is_none()
-                {
-                    if reclamation_cell_found {
-                        // Cannot have more than one reclamation cell.
-                        Err(BizError::DistributionTransactionInvalid)?;
-                    }
-                    reclamation_cell_found = true;
-                }
-                // Any other cell is considered a change cell and is ignored.
-            }
-
-            if !reclamation_cell_found {
-                Err(BizError::ReclamationLockHashMismatch)?;
-            }
+            // Cannot have more than one reclamation cell. Any other cell is
+            // considered a change cell and is ignored.
+            find_unique_output(
+                Error::from(BizError::DistributionTransactionInvalid),
+                |output_cell, output_lock_hash| {
+                    is_payout_cell(
+                        output_cell,
+                        output_lock_hash,
+                        &expected_admin_lock_hash,
+                        input_capacity,
+                    )
+                },
+            )?
+            .ok_or(BizError::ReclamationLockHashMismatch)?;
         }
     }
 
@@ -0,0 +1,82 @@
+#![cfg_attr(not(any(feature = "library", test)), no_std)]
+#![cfg_attr(not(test), no_main)]
+#![deny(clippy::panic)]
+
+#[cfg(any(feature = "library", test))]
+extern crate alloc;
+
+use ckb_std::{
+    ckb_constants::Source,
+    debug,
+    high_level::{load_cell, load_cell_lock_hash, load_script, QueryIter},
+    type_id::check_type_id,
+};
+use common::{contract::load_group_data, schema::entity::EntityCellData, NULL_HASH};
+use entity_type::error::{BizError, Error};
+use molecule::prelude::Entity;
+
+common::contract_entry!(entry);
+
+fn entry() -> Result<(), Error> {
+    debug!("entity type contract is executing");
+
+    check_type_id(0)?;
+
+    let inputs_count = QueryIter::new(load_cell, Source::GroupInput).count();
+    let outputs_count = QueryIter::new(load_cell, Source::GroupOutput).count();
+
+    match (inputs_count, outputs_count) {
+        (0, 1) => verify_creation(),
+        (1, 1) => verify_update(),
+        // The creator can unpublish the content at any time.
+        (1, 0) => Ok(()),
+        _ => Err(BizError::EntityTransactionInvalid)?,
+    }
+}
+
+fn load_entity_data(source: Source) -> Result<EntityCellData, Error> {
+    let entity_data =
+        load_group_data::<EntityCellData>(source)?.ok_or(BizError::EntityDataInvalid)?;
+
+    if entity_data.metadata_hash().as_slice() == NULL_HASH {
+        Err(BizError::MetadataHashInvalid)?;
+    }
+
+    // The creator keeps ownership of the registry cell.
+    let actual_lock_hash = load_cell_lock_hash(0, source)?;
+    if entity_data.creator_lock_hash().as_slice() != actual_lock_hash {
+        Err(BizError::CreatorLockHashMismatch)?;
+    }
+
+    Ok(entity_data)
+}
+
+fn verify_creation() -> Result<(), Error> {
+    let entity_data = load_entity_data(Source::GroupOutput)?;
+
+    if entity_data.creator_lock_hash().as_slice() == NULL_HASH {
+        Err(BizError::CreatorLockHashInvalid)?;
+    }
+
+    // The entity id is the cell's Type ID, which makes it globally unique.
+    let type_id = load_script()?.args().raw_data();
+    if type_id.len() < 32 || entity_data.entity_id().as_slice() != &type_id[..32] {
+        Err(BizError::EntityIdMismatch)?;
+    }
+
+    Ok(())
+}
+
+fn verify_update() -> Result<(), Error> {
+    // Only the metadata can change.
+    let input_data = load_entity_data(Source::GroupInput)?;
+    let output_data = load_entity_data(Source::GroupOutput)?;
+
+    if input_data.creator_lock_hash().as_slice() != output_data.creator_lock_hash().as_slice()
+        || input_data.entity_id().as_slice() != output_data.entity_id().as_slice()
+    {
+        Err(BizError::EntityDataImmutable)?;
+    }
+
+    Ok(())
+}
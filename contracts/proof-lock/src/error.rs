@@ -0,0 +1 @@
+common::contract_error!(common::error::ProofLockError, "proof lock");
@@ -0,0 +1,59 @@
+#![cfg_attr(not(any(feature = "library", test)), no_std)]
+#![cfg_attr(not(test), no_main)]
+#![deny(clippy::panic)]
+
+#[cfg(any(feature = "library", test))]
+extern crate alloc;
+
+use ckb_std::{
+    ckb_constants::Source,
+    debug,
+    high_level::{
+        load_cell_capacity, load_cell_data, load_cell_lock_hash, load_input_since, load_script,
+        QueryIter,
+    },
+};
+use common::{
+    base::Byte32, contract::sum_capacity_by_lock_hash, proof::is_expired, version::parse_proof_data,
+};
+use molecule::prelude::Entity;
+use proof_lock::error::{BizError, Error};
+
+common::contract_entry!(entry);
+
+fn entry() -> Result<(), Error> {
+    debug!("proof lock contract is executing");
+
+    let subscriber_lock_hash = Byte32::from_slice(&load_script()?.args().raw_data())
+        .map_err(|_| BizError::ArgumentInvalid)?;
+
+    // The subscriber can spend its proofs at any time by providing one of its
+    // own cells as an input. The proof type script checks how they are spent.
+    let subscriber_signed = QueryIter::new(load_cell_lock_hash, Source::Input)
+        .any(|lock_hash| lock_hash == subscriber_lock_hash.as_slice());
+    if subscriber_signed {
+        debug!("Subscriber action authorized");
+        return Ok(());
+    }
+
+    // Anyone else can only clean up the subscriber's expired proofs, returning
+    // their whole capacity to the subscriber.
+    let mut capacity = 0u64;
+    for (i, data) in QueryIter::new(load_cell_data, Source::GroupInput).enumerate() {
+        let proof_data = parse_proof_data(&data).ok_or(BizError::ProofDataInvalid)?;
+        if proof_data.subscriber_lock_hash().as_slice() != subscriber_lock_hash.as_slice()
+            || !is_expired(&proof_data, load_input_since(i, Source::GroupInput)?)
+        {
+            Err(BizError::UnauthorizedAction)?;
+        }
+        capacity = capacity.saturating_add(load_cell_capacity(i, Source::GroupInput)?);
+    }
+
+    let refunded = sum_capacity_by_lock_hash(subscriber_lock_hash.as_slice(), Source::Output)?;
+    if refunded < capacity {
+        Err(BizError::RefundInvalid)?;
+    }
+
+    debug!("Expired proof cleanup authorized");
+    Ok(())
+}
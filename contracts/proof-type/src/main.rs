@@ -1,36 +1,36 @@
 #![cfg_attr(not(any(feature = "library", test)), no_std)]
 #![cfg_attr(not(test), no_main)]
+#![deny(clippy::panic)]
 
 #[cfg(any(feature = "library", test))]
 extern crate alloc;
 
-#[cfg(not(any(feature = "library", test)))]
-ckb_std::entry!(program_entry);
-#[cfg(not(any(feature = "library", test)))]
-// By default, the following heap configuration is used:
-// * 16KB fixed heap
-// * 1.2MB(rounded up to be 16-byte aligned) dynamic heap
-// * Minimal memory block in dynamic heap is 64 bytes
-// For more details, please refer to ckb-std's default_alloc macro
-// and the buddy-alloc alloc implementation.
-ckb_std::default_alloc!(16384, 1258306, 64);
-
 use ckb_std::{
     ckb_constants::Source,
+    ckb_types::prelude::Unpack,
     debug,
-    high_level::{load_cell, load_cell_data, load_cell_lock_hash, QueryIter},
+    high_level::{
+        load_cell, load_cell_data, load_cell_lock, load_cell_lock_hash, load_cell_type,
+        load_input_since, load_script, QueryIter,
+    },
     type_id::check_type_id,
 };
-use common::{schema::proof::ProofCellData, NULL_HASH};
+use common::{
+    bitmap::{bitmap_len, is_superset},
+    contract::sum_capacity_by_lock_hash,
+    proof::{is_expired, is_subscriber_lock},
+    schema::proof::{ProofCellData, ProofTypeArgs},
+    script::ScriptRef,
+    version::{parse_distribution_data, parse_proof_data},
+    NULL_HASH,
+};
 use molecule::prelude::Entity;
 use proof_type::error::{BizError, Error};
 
-pub fn program_entry() -> i8 {
-    match entry() {
-        Ok(()) => 0,
-        Err(err) => err.into(),
-    }
-}
+/// The maximum number of campaigns a single proof can cover.
+const MAX_PROOF_CAMPAIGNS: usize = 256;
+
+common::contract_entry!(entry);
 
 fn entry() -> Result<(), Error> {
     debug!("proof type contract is executing");
@@ -43,7 +43,7 @@ fn entry() -> Result<(), Error> {
     match (inputs_count, outputs_count) {
         (0, 1) => verify_creation(),
         (1, 0) => verify_consumption(),
-        (1, 1) => Err(BizError::ProofCellUpdateForbidden)?,
+        (1, 1) => verify_update(),
         _ => Err(BizError::ProofTransactionInvalid)?,
     }
 }
@@ -51,19 +51,31 @@ fn entry() -> Result<(), Error> {
 fn verify_creation() -> Result<(), Error> {
     // 1. Check data structure validity.
     let proof_data_bytes = load_cell_data(0, Source::GroupOutput)?;
-    let proof_data =
-        ProofCellData::from_slice(&proof_data_bytes).map_err(|_| BizError::ProofDataInvalid)?;
+    let proof_data = parse_proof_data(&proof_data_bytes).ok_or(BizError::ProofDataInvalid)?;
 
     // 2. Ensure critical identifier hashes are not null/empty.
     if proof_data.entity_id().as_slice() == NULL_HASH {
         Err(BizError::ProofEntityIdInvalid)?;
     }
+    verify_entity_registered(&proof_data)?;
 
-    if proof_data.campaign_id().as_slice() == NULL_HASH {
+    let campaign_ids = proof_data.campaign_ids();
+    if campaign_ids.is_empty() || campaign_ids.len() > MAX_PROOF_CAMPAIGNS {
         Err(BizError::ProofCampaignIdInvalid)?;
     }
+    for (i, campaign_id) in campaign_ids.clone().into_iter().enumerate() {
+        if campaign_id.as_slice() == NULL_HASH
+            || campaign_ids
+                .clone()
+                .into_iter()
+                .take(i)
+                .any(|other| other.as_slice() == campaign_id.as_slice())
+        {
+            Err(BizError::ProofCampaignIdInvalid)?;
+        }
+    }
 
-    if proof_data.proof().as_slice() == NULL_HASH {
+    if proof_data.proof_commit().as_slice() == NULL_HASH {
         Err(BizError::ProofHashInvalid)?;
     }
 
@@ -71,20 +83,177 @@ fn verify_creation() -> Result<(), Error> {
         Err(BizError::SubscriberLockHashInvalid)?;
     }
 
-    // 3. Check lock hash is correct.
-    let actual_lock_hash = load_cell_lock_hash(0, Source::GroupOutput)?;
-    if proof_data.subscriber_lock_hash().as_slice() != actual_lock_hash {
+    // 3. No campaign can be claimed before the proof exists.
+    let claimed_campaigns = proof_data.claimed_campaigns().raw_data();
+    if claimed_campaigns.len() != bitmap_len(campaign_ids.len())
+        || claimed_campaigns.iter().any(|byte| *byte != 0)
+    {
+        Err(BizError::ClaimedCampaignsInvalid)?;
+    }
+
+    // 4. Check the proof is guarded by the subscriber's lock, or by the proof
+    // lock on the subscriber's behalf.
+    let args = ProofTypeArgs::from_slice(&load_script()?.args().raw_data())
+        .map_err(|_| BizError::ProofScriptArgsInvalid)?;
+    let lock = load_cell_lock(0, Source::GroupOutput)?;
+    let lock_hash = load_cell_lock_hash(0, Source::GroupOutput)?;
+    if !is_subscriber_lock(
+        &args,
+        &lock,
+        &lock_hash,
+        proof_data.subscriber_lock_hash().as_slice(),
+    ) {
         Err(BizError::SubscriberLockHashMismatch)?;
     }
 
     Ok(())
 }
 
+/// Checks that the proof's entity is published in the entity registry named by
+/// the script args, which hold the Type ID followed by the registry's code hash
+/// and hash type. The registry cell must be provided as a cell dep.
+///
+/// The subscriber picks the registry, so claims only accept proofs naming the
+/// registry their campaign pins.
+fn verify_entity_registered(proof_data: &ProofCellData) -> Result<(), Error> {
+    let args = ProofTypeArgs::from_slice(&load_script()?.args().raw_data())
+        .map_err(|_| BizError::ProofScriptArgsInvalid)?;
+    let entity_registry = ScriptRef::from(args.entity_registry());
+
+    // A registry cell's Type ID is its entity id.
+    let registered = QueryIter::new(load_cell_type, Source::CellDep).any(|type_script| {
+        type_script.is_some_and(|script| {
+            entity_registry.matches(&script)
+                && script.args().raw_data().as_ref() == proof_data.entity_id().as_slice()
+        })
+    });
+    if !registered {
+        Err(BizError::EntityNotRegistered)?;
+    }
+
+    Ok(())
+}
+
+fn verify_update() -> Result<(), Error> {
+    // An update can only mark more campaigns as claimed. It is driven by a
+    // claim, and `distribution-type` checks that the claimed campaign is the
+    // one being marked.
+    let input_data_bytes = load_cell_data(0, Source::GroupInput)?;
+    let input_data = parse_proof_data(&input_data_bytes).ok_or(BizError::ProofDataInvalid)?;
+    let output_data_bytes = load_cell_data(0, Source::GroupOutput)?;
+    let output_data = parse_proof_data(&output_data_bytes).ok_or(BizError::ProofDataInvalid)?;
+
+    if input_data.entity_id().as_slice() != output_data.entity_id().as_slice()
+        || input_data.campaign_ids().as_slice() != output_data.campaign_ids().as_slice()
+        || input_data.proof_commit().as_slice() != output_data.proof_commit().as_slice()
+        || input_data.subscriber_lock_hash().as_slice()
+            != output_data.subscriber_lock_hash().as_slice()
+        || input_data.expiry().as_slice() != output_data.expiry().as_slice()
+    {
+        Err(BizError::ProofCellUpdateInvalid)?;
+    }
+
+    let input_claimed = input_data.claimed_campaigns().raw_data();
+    let output_claimed = output_data.claimed_campaigns().raw_data();
+    if input_claimed == output_claimed || !is_superset(&output_claimed, &input_claimed) {
+        Err(BizError::ClaimedCampaignsInvalid)?;
+    }
+
+    if !is_claim(&input_data)? {
+        Err(BizError::ProofConsumptionInvalid)?;
+    }
+
+    // The cell itself stays with its owner, with its capacity untouched.
+    let input_cell = load_cell(0, Source::GroupInput)?;
+    let output_cell = load_cell(0, Source::GroupOutput)?;
+    let input_capacity: u64 = input_cell.capacity().unpack();
+    let output_capacity: u64 = output_cell.capacity().unpack();
+    if input_cell.lock().as_slice() != output_cell.lock().as_slice()
+        || input_capacity != output_capacity
+    {
+        Err(BizError::ProofCellUpdateInvalid)?;
+    }
+
+    Ok(())
+}
+
 fn verify_consumption() -> Result<(), Error> {
-    // When a Proof Cell is consumed, we don't need additional validation
-    // beyond what's already enforced by the transaction structure checks.
-    // The cell is being destroyed, which is allowed. The user who owns the cell
-    // can choose to do this to reclaim the CKB capacity, forgoing their
-    // right to claim a reward.
+    let proof_data_bytes = load_cell_data(0, Source::GroupInput)?;
+    let proof_data = parse_proof_data(&proof_data_bytes).ok_or(BizError::ProofDataInvalid)?;
+
+    if is_expired(&proof_data, load_input_since(0, Source::GroupInput)?) {
+        // Anyone may clean up an expired proof, as long as its owner gets the
+        // exact capacity back.
+        return verify_expiry_refund(&proof_data);
+    }
+
+    // Otherwise the proof can only be spent by a claim, or withdrawn by its owner
+    // to reclaim the CKB capacity, forgoing their right to claim a reward. This
+    // keeps a proof from being destroyed by mistake in an unrelated transaction.
+    if !is_claim(&proof_data)? && !is_withdrawal(&proof_data)? {
+        Err(BizError::ProofConsumptionInvalid)?;
+    }
+
+    Ok(())
+}
+
+/// Checks whether the transaction claims from a distribution shard of one of the
+/// proof's campaigns. A shard is recognised by its type script, which must be
+/// the distribution type named in the proof's args, and by its data, which must
+/// reference this proof script.
+fn is_claim(proof_data: &ProofCellData) -> Result<bool, Error> {
+    let proof_script = load_script()?;
+    let args = ProofTypeArgs::from_slice(&proof_script.args().raw_data())
+        .map_err(|_| BizError::ProofScriptArgsInvalid)?;
+    let distribution_type = ScriptRef::from(args.distribution_type());
+
+    for (i, data) in QueryIter::new(load_cell_data, Source::Input).enumerate() {
+        let is_shard = load_cell_type(i, Source::Input)?
+            .is_some_and(|script| distribution_type.matches(&script));
+        if !is_shard {
+            continue;
+        }
+        let Some(shard_data) = parse_distribution_data(&data) else {
+            continue;
+        };
+
+        let shard_proof_script = ScriptRef {
+            code_hash: shard_data.proof_script_code_hash().into(),
+            hash_type: shard_data.proof_script_hash_type().into(),
+        };
+        if shard_proof_script.matches(&proof_script)
+            && proof_data
+                .campaign_ids()
+                .into_iter()
+                .any(|campaign_id| campaign_id.as_slice() == shard_data.campaign_id().as_slice())
+        {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Checks whether the proof's capacity goes back to `subscriber_lock_hash`.
+fn is_withdrawal(proof_data: &ProofCellData) -> Result<bool, Error> {
+    let capacity: u64 = load_cell(0, Source::GroupInput)?.capacity().unpack();
+    let returned =
+        sum_capacity_by_lock_hash(proof_data.subscriber_lock_hash().as_slice(), Source::Output)?;
+
+    Ok(returned >= capacity)
+}
+
+fn verify_expiry_refund(proof_data: &ProofCellData) -> Result<(), Error> {
+    let capacity: u64 = load_cell(0, Source::GroupInput)?.capacity().unpack();
+    let refunded = QueryIter::new(load_cell, Source::Output).any(|cell| {
+        let cell_capacity: u64 = cell.capacity().unpack();
+        cell.lock().calc_script_hash().as_slice() == proof_data.subscriber_lock_hash().as_slice()
+            && cell_capacity == capacity
+            && cell.type_().to_opt().is_none()
+    });
+    if !refunded {
+        Err(BizError::ProofRefundInvalid)?;
+    }
+
     Ok(())
 }
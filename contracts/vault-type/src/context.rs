@@ -1,9 +1,12 @@
 use ckb_std::{
     ckb_constants::Source,
-    ckb_types::prelude::Unpack,
+    ckb_types::{packed::CellOutput, prelude::Unpack},
     high_level::{load_cell, load_cell_data},
 };
-use common::schema::vault::VaultCellData;
+use common::{
+    schema::vault::{VaultCellData, VaultLockArgs},
+    version::parse_vault_data,
+};
 use molecule::prelude::Entity;
 
 use crate::error::{BizError, Error};
@@ -18,21 +21,22 @@ pub struct VmContext {
 pub fn load_context() -> Result<VmContext, Error> {
     let input_vault_cell = load_cell(0, Source::GroupInput)?;
     let vault_data_bytes = load_cell_data(0, Source::GroupInput)?;
-    let vault_data =
-        VaultCellData::from_slice(&vault_data_bytes).map_err(|_| BizError::VaultDataInvalid)?;
-
-    let vault_lock_args = input_vault_cell.lock().args().raw_data();
-    if vault_lock_args.len() != 64 {
-        Err(BizError::VaultTransactionInvalid)?;
-    }
+    let vault_data = parse_vault_data(&vault_data_bytes).ok_or(BizError::VaultDataInvalid)?;
 
-    let admin_lock_hash: [u8; 32] = vault_lock_args[32..64].try_into().unwrap();
-    let creator_lock_hash: [u8; 32] = vault_lock_args[0..32].try_into().unwrap();
+    let vault_lock_args = parse_vault_lock_args(&input_vault_cell)?;
 
     Ok(VmContext {
-        admin_lock_hash,
-        creator_lock_hash,
+        admin_lock_hash: vault_lock_args.admin_lock_hash().into(),
+        creator_lock_hash: vault_lock_args.creator_lock_hash().into(),
         vault_data,
         vault_capacity: input_vault_cell.capacity().unpack(),
     })
 }
+
+/// Parses the `VaultLockArgs` of the vault cell's lock, failing with the same
+/// error as malformed `VaultTypeArgs`.
+pub fn parse_vault_lock_args(vault_cell: &CellOutput) -> Result<VaultLockArgs, Error> {
+    let args = VaultLockArgs::from_slice(&vault_cell.lock().args().raw_data())
+        .map_err(|_| BizError::ArgumentLengthInvalid)?;
+    Ok(args)
+}
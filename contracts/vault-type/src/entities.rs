@@ -0,0 +1,37 @@
+use alloc::vec::Vec;
+use common::{merkle::merkle_root, schema::vault::VaultCellData, NULL_HASH};
+
+use crate::error::{BizError, Error};
+
+/// The maximum number of entity ids a vault can list directly. Longer lists
+/// must be committed to through `entity_root` instead.
+pub const MAX_ENTITY_IDS: usize = 16;
+
+/// Returns the entity root every distribution shard must carry.
+///
+/// A vault sets either an `entity_root` or a short `entity_ids` list, never
+/// both. A list is turned into the Merkle root of its ids, so shards and claims
+/// only ever deal with a root. A null root means the campaign targets no
+/// specific entity.
+pub fn expected_entity_root(vault_data: &VaultCellData) -> Result<[u8; 32], Error> {
+    let entity_root: [u8; 32] = vault_data.entity_root().into();
+    let entity_ids = vault_data.entity_ids();
+
+    if entity_ids.is_empty() {
+        return Ok(entity_root);
+    }
+    if entity_root != NULL_HASH || entity_ids.len() > MAX_ENTITY_IDS {
+        Err(BizError::EntityTargetingInvalid)?;
+    }
+
+    let mut leaves: Vec<[u8; 32]> = Vec::with_capacity(entity_ids.len());
+    for entity_id in entity_ids.into_iter() {
+        let entity_id: [u8; 32] = entity_id.into();
+        if entity_id == NULL_HASH || leaves.contains(&entity_id) {
+            Err(BizError::EntityTargetingInvalid)?;
+        }
+        leaves.push(entity_id);
+    }
+
+    Ok(merkle_root(&leaves))
+}
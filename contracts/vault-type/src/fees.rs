@@ -0,0 +1,19 @@
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::prelude::Unpack,
+    high_level::{load_cell, QueryIter},
+};
+use molecule::prelude::Entity;
+
+/// Counts the outputs that qualify as a fee cell for `lock_hash`: a plain cell
+/// without a type script carrying exactly `capacity`.
+pub fn count_fee_cells(lock_hash: &[u8; 32], capacity: u64) -> usize {
+    QueryIter::new(load_cell, Source::Output)
+        .filter(|cell| {
+            let cell_capacity: u64 = cell.capacity().unpack();
+            cell.lock().calc_script_hash().as_slice() == lock_hash
+                && cell_capacity == capacity
+                && cell.type_().to_opt().is_none()
+        })
+        .count()
+}
@@ -1,6 +1,7 @@
 #![cfg_attr(not(feature = "library"), no_std)]
 #![allow(special_module_name)]
 #![allow(unused_attributes)]
+#![deny(clippy::panic)]
 #[cfg(feature = "library")]
 mod main;
 #[cfg(feature = "library")]
@@ -9,4 +10,6 @@ pub use main::program_entry;
 extern crate alloc;
 
 pub mod context;
+pub mod entities;
 pub mod error;
+pub mod fees;
@@ -1,20 +1,10 @@
 #![cfg_attr(not(any(feature = "library", test)), no_std)]
 #![cfg_attr(not(test), no_main)]
+#![deny(clippy::panic)]
 
 #[cfg(any(feature = "library", test))]
 extern crate alloc;
 
-#[cfg(not(any(feature = "library", test)))]
-ckb_std::entry!(program_entry);
-#[cfg(not(any(feature = "library", test)))]
-// By default, the following heap configuration is used:
-// * 16KB fixed heap
-// * 1.2MB(rounded up to be 16-byte aligned) dynamic heap
-// * Minimal memory block in dynamic heap is 64 bytes
-// For more details, please refer to ckb-std's default_alloc macro
-// and the buddy-alloc alloc implementation.
-ckb_std::default_alloc!(16384, 1258306, 64);
-
 use ckb_std::{
     ckb_constants::Source,
     ckb_types::prelude::*,
@@ -22,21 +12,23 @@ use ckb_std::{
     high_level::{load_cell, load_cell_data, load_script, QueryIter},
 };
 use common::{
-    schema::{distribution::DistributionCellData, vault::VaultCellData},
+    contract::sum_capacity_by_lock_hash,
+    fees::{expected_fee, fee_shares, parse_fee_schedule, verify_fee_capacity, verify_fee_model},
+    funders::{load_funders, parse_funders, pro_rata_shares},
+    schema::vault::VaultTypeArgs,
+    script::ScriptRef,
+    version::{parse_distribution_data, parse_vault_data},
     NULL_HASH,
 };
-use molecule::prelude::Entity;
+use molecule::prelude::{Builder, Entity};
 use vault_type::{
-    context::{load_context, VmContext},
+    context::{load_context, parse_vault_lock_args, VmContext},
+    entities::expected_entity_root,
     error::{BizError, Error},
+    fees::count_fee_cells,
 };
 
-pub fn program_entry() -> i8 {
-    match entry() {
-        Ok(()) => 0,
-        Err(err) => err.into(),
-    }
-}
+common::contract_entry!(entry);
 
 fn entry() -> Result<(), Error> {
     debug!("vault type contract is executing");
@@ -65,26 +57,20 @@ fn entry() -> Result<(), Error> {
         (1, 0) => {
             // Destruction of the vault. This can be either a distribution or a full refund.
             let context = load_context()?;
-            let args = load_script()?.args();
-            let args_bytes = args.raw_data();
+            let args = load_vault_type_args()?;
 
-            if args_bytes.len() != 64 {
-                Err(BizError::ArgumentLengthInvalid)?;
-            }
             // Args are valid, so this *could* be a distribution.
-            let mut dist_lock_code_hash = [0u8; 32];
-            dist_lock_code_hash.copy_from_slice(&args_bytes[0..32]);
+            let dist_lock = ScriptRef::from(args.distribution_lock());
 
             let has_dist_shard = QueryIter::new(load_cell, Source::Output)
-                .any(|cell| cell.lock().code_hash().as_slice() == dist_lock_code_hash);
+                .any(|cell| dist_lock.matches(&cell.lock()));
 
             if has_dist_shard {
                 // This is a distribution.
                 // vault-lock ensures this is signed by the admin.
                 debug!("vault distribution transaction");
-                let mut dist_type_code_hash = [0u8; 32];
-                dist_type_code_hash.copy_from_slice(&args_bytes[32..64]);
-                verify_distribution(&context, &dist_lock_code_hash, &dist_type_code_hash)
+                let dist_type = ScriptRef::from(args.distribution_type());
+                verify_distribution(&context, &dist_lock, &dist_type)
             } else {
                 // No distribution shards found, so it's a full refund.
                 debug!("vault destruction transaction (full refund)");
@@ -95,18 +81,36 @@ fn entry() -> Result<(), Error> {
     }
 }
 
+fn load_vault_type_args() -> Result<VaultTypeArgs, Error> {
+    let args = VaultTypeArgs::from_slice(&load_script()?.args().raw_data())
+        .map_err(|_| BizError::ArgumentLengthInvalid)?;
+    Ok(args)
+}
+
 fn verify_creation() -> Result<(), Error> {
-    if load_script()?.args().raw_data().len() != 64 {
-        Err(BizError::ArgumentLengthInvalid)?;
-    }
+    load_vault_type_args()?;
 
     let vault_data_bytes = load_cell_data(0, Source::GroupOutput)?;
-    let vault_data =
-        VaultCellData::from_slice(&vault_data_bytes).map_err(|_| BizError::VaultDataInvalid)?;
-
-    let fee_percentage: u16 = vault_data.fee_percentage().unpack();
-    if fee_percentage > 10000 {
-        Err(BizError::FeePercentageOutOfRange)?;
+    let vault_data = parse_vault_data(&vault_data_bytes).ok_or(BizError::VaultDataInvalid)?;
+
+    // The vault lock must be spendable later, which requires its args to decode.
+    let vault_cell = load_cell(0, Source::GroupOutput)?;
+    parse_vault_lock_args(&vault_cell)?;
+
+    let vault_capacity: u64 = vault_cell.capacity().unpack();
+    let fee_model = verify_fee_model(&vault_data)?;
+    verify_fee_capacity(&fee_model, vault_capacity)?;
+
+    let vesting = vault_data.vesting();
+    let vesting_start_epoch: u64 = vesting.start_epoch().unpack();
+    let vesting_end_epoch: u64 = vesting.end_epoch().unpack();
+    if vesting.lock_code_hash().as_slice() == NULL_HASH {
+        let lock_hash_type: u8 = vesting.lock_hash_type().into();
+        if lock_hash_type != 0 || vesting_start_epoch != 0 || vesting_end_epoch != 0 {
+            Err(BizError::VaultDataInvalid)?;
+        }
+    } else if vesting_end_epoch <= vesting_start_epoch {
+        Err(BizError::VaultDataInvalid)?;
     }
 
     if vault_data.campaign_id().as_slice() == NULL_HASH {
@@ -117,21 +121,38 @@ fn verify_creation() -> Result<(), Error> {
         Err(BizError::VaultDataInvalid)?;
     }
 
+    // Proofs are only accepted from the registry the campaign pins.
+    if vault_data.entity_registry().code_hash().as_slice() == NULL_HASH {
+        Err(BizError::VaultDataInvalid)?;
+    }
+
+    // A fee schedule, if present, must be well formed.
+    parse_fee_schedule(&vault_data, &NULL_HASH)?;
+
+    // The targeted entities, if any, must be either a root or a short list.
+    expected_entity_root(&vault_data)?;
+
+    // A funders ledger, if present, must account for the whole vault.
+    if !vault_data.funders().is_empty() {
+        load_funders(&vault_data, &NULL_HASH, vault_capacity)?;
+    }
+
     Ok(())
 }
 
 fn verify_distribution(
     context: &VmContext,
-    dist_lock_code_hash: &[u8; 32],
-    dist_type_code_hash: &[u8; 32],
+    dist_lock: &ScriptRef,
+    dist_type: &ScriptRef,
 ) -> Result<(), Error> {
     // 1. Find all distribution shards and validate their data.
     let mut total_dist_shards_capacity: u64 = 0;
     let mut uniform_reward_amount: Option<u64> = None;
+    let entity_root = expected_entity_root(&context.vault_data)?;
 
     let dist_shards = QueryIter::new(load_cell, Source::Output)
         .enumerate()
-        .filter(|(_i, cell)| cell.lock().code_hash().as_slice() == dist_lock_code_hash)
+        .filter(|(_i, cell)| dist_lock.matches(&cell.lock()))
         .collect::<alloc::vec::Vec<_>>();
 
     if dist_shards.is_empty() {
@@ -145,7 +166,7 @@ fn verify_distribution(
             .type_()
             .to_opt()
             .ok_or(BizError::DistributionDataInvalid)?;
-        if type_script.code_hash().as_slice() != dist_type_code_hash {
+        if !dist_type.matches(&type_script) {
             Err(BizError::DistributionDataInvalid)?;
         }
 
@@ -153,8 +174,8 @@ fn verify_distribution(
         total_dist_shards_capacity += shard_capacity;
 
         let shard_data_bytes = load_cell_data(i, Source::Output)?;
-        let shard_data = DistributionCellData::from_slice(&shard_data_bytes)
-            .map_err(|_| BizError::DistributionDataInvalid)?;
+        let shard_data =
+            parse_distribution_data(&shard_data_bytes).ok_or(BizError::DistributionDataInvalid)?;
 
         // On the first shard, capture the reward amount for consistency checks.
         if uniform_reward_amount.is_none() {
@@ -169,6 +190,17 @@ fn verify_distribution(
         if shard_data.campaign_id().as_bytes() != context.vault_data.campaign_id().as_bytes()
             || shard_data.proof_script_code_hash().as_bytes()
                 != context.vault_data.proof_script_code_hash().as_bytes()
+            || shard_data.proof_script_hash_type().as_slice()
+                != context.vault_data.proof_script_hash_type().as_slice()
+            // Rewards must vest exactly as the creator configured.
+            || shard_data.vesting().as_slice() != context.vault_data.vesting().as_slice()
+            // Claims can only be made for the entities the campaign targets,
+            // registered in its registry by its creator, if any.
+            || shard_data.entity_root().as_slice() != entity_root
+            || shard_data.entity_registry().as_slice()
+                != context.vault_data.entity_registry().as_slice()
+            || shard_data.entity_creator_lock_hash().as_slice()
+                != context.vault_data.entity_creator_lock_hash().as_slice()
             // The admin lock hash in the shard must match the one from the vault's lock.
             || shard_data.admin_lock_hash().as_slice() != context.admin_lock_hash
             || shard_data.uniform_reward_amount().unpack() != uniform_reward_amount.unwrap()
@@ -178,36 +210,25 @@ fn verify_distribution(
     }
 
     // 2. Calculate expected fee
-    let fee_percentage: u128 = context.vault_data.fee_percentage().unpack().into();
-    if fee_percentage > 10000 {
-        Err(BizError::FeePercentageOutOfRange)?;
-    }
     let total_capacity = context.vault_capacity;
-    let expected_fee_capacity = (total_capacity as u128 * fee_percentage / 10000) as u64;
-
-    if total_capacity < expected_fee_capacity {
-        Err(BizError::VaultTransactionInvalid)?;
-    }
+    let expected_fee_capacity = expected_fee(&context.vault_data, total_capacity)?;
 
     // 3. Verify capacity partitioning
     if total_dist_shards_capacity + expected_fee_capacity != total_capacity {
         Err(BizError::CapacityMismatch)?;
     }
 
-    // 4. Find and validate the fee cell.
-    let fee_cells_count = QueryIter::new(load_cell, Source::Output)
-        .filter(|cell| {
-            let lock_hash = cell.lock().calc_script_hash();
-            let capacity: u64 = cell.capacity().unpack();
-            lock_hash.as_slice() == context.admin_lock_hash
-                && capacity == expected_fee_capacity
-                && cell.type_().to_opt().is_none()
-        })
-        .count();
-
-    if fee_cells_count != 1 {
-        // Must be exactly one cell matching the fee criteria.
-        Err(BizError::FeeCapacityMismatch)?;
+    // 4. Find and validate the fee cells, one per beneficiary of the fee schedule.
+    // A beneficiary whose share is zero, e.g. in the zero-fee model, gets no fee cell.
+    let beneficiaries = parse_fee_schedule(&context.vault_data, &context.admin_lock_hash)?;
+    for (lock_hash, fee_capacity) in fee_shares(&beneficiaries, expected_fee_capacity) {
+        if fee_capacity == 0 {
+            continue;
+        }
+        if count_fee_cells(&lock_hash, fee_capacity) != 1 {
+            // Must be exactly one cell matching the fee criteria.
+            Err(BizError::FeeCapacityMismatch)?;
+        }
     }
 
     Ok(())
@@ -217,62 +238,82 @@ fn verify_capacity_adjustment(context: &VmContext) -> Result<(), Error> {
     let input_vault_cell = load_cell(0, Source::GroupInput)?;
     let output_vault_cell = load_cell(0, Source::GroupOutput)?;
     let output_vault_data_bytes = load_cell_data(0, Source::GroupOutput)?;
-
-    if context.vault_data.as_slice() != output_vault_data_bytes {
+    let output_vault_data =
+        parse_vault_data(&output_vault_data_bytes).ok_or(BizError::VaultDataInvalid)?;
+
+    // Only the funders ledger may change alongside the capacity.
+    let expected_vault_data = output_vault_data
+        .clone()
+        .as_builder()
+        .funders(context.vault_data.funders())
+        .build();
+    if context.vault_data.as_slice() != expected_vault_data.as_slice() {
         Err(BizError::VaultDataImmutable)?;
     }
 
+    // Once a vault has a ledger, it cannot be dropped.
+    if !context.vault_data.funders().is_empty() && output_vault_data.funders().is_empty() {
+        Err(BizError::FunderLedgerInvalid)?;
+    }
+
     if input_vault_cell.lock().as_slice() != output_vault_cell.lock().as_slice() {
         Err(BizError::VaultLockScriptImmutable)?;
     }
 
     let output_vault_capacity: u64 = output_vault_cell.capacity().unpack();
 
-    if output_vault_capacity >= context.vault_capacity {
-        return Ok(());
-    }
-
-    // Sum capacity of all outputs going to the creator.
-    // Note: The output vault cell itself is NOT locked by the creator, so it won't be counted here.
-    let creator_output_capacity: u64 = QueryIter::new(load_cell, Source::Output)
-        .filter_map(|cell| {
-            if cell.lock().calc_script_hash().as_slice() == context.creator_lock_hash {
-                let cell_capacity: u64 = cell.capacity().unpack();
-                Some(cell_capacity)
-            } else {
-                None
-            }
-        })
-        .sum();
-
-    if output_vault_capacity + creator_output_capacity < context.vault_capacity {
-        Err(BizError::CapacityAdjustmentInvalid)?;
+    // A vault without a ledger is treated as owned by the creator, so a plain
+    // top-up or partial refund by the creator follows the same rules.
+    let input_funders = load_funders(
+        &context.vault_data,
+        &context.creator_lock_hash,
+        context.vault_capacity,
+    )?;
+    let output_funders = load_funders(
+        &output_vault_data,
+        &context.creator_lock_hash,
+        output_vault_capacity,
+    )?;
+
+    // Every funder whose amount went down must be paid the difference.
+    // Note: The output vault cell itself is NOT locked by a funder, so it won't be counted here.
+    for (lock_hash, input_amount) in input_funders {
+        let output_amount = output_funders
+            .iter()
+            .find(|(hash, _)| *hash == lock_hash)
+            .map(|(_, amount)| *amount)
+            .unwrap_or(0);
+
+        if output_amount < input_amount
+            && sum_capacity_by_lock_hash(&lock_hash, Source::Output)? < input_amount - output_amount
+        {
+            Err(BizError::CapacityAdjustmentInvalid)?;
+        }
     }
 
     Ok(())
 }
 
 fn verify_full_refund(context: &VmContext) -> Result<(), Error> {
-    // In a full refund, the vault's capacity must be returned to the creator.
-    // We verify this by checking that the sum of capacities of output cells
-    // locked with the creator's lock hash is at least the vault's capacity.
+    // In a full refund, the vault's capacity must be returned to its funders.
+    // Without a ledger, everything goes back to the creator. Otherwise every
+    // funder is paid its pro-rata share of the vault capacity. We verify this by
+    // checking that the sum of capacities of output cells locked with each
+    // funder's lock hash is at least its share.
     // The vault-lock script ensures the creator signed the transaction, so they
     // are in control of any other inputs/outputs. The CKB VM's balance check
     // handles the rest.
 
-    let creator_output_capacity: u64 = QueryIter::new(load_cell, Source::Output)
-        .filter_map(|cell| {
-            if cell.lock().calc_script_hash().as_slice() == context.creator_lock_hash {
-                let cell_capacity: u64 = cell.capacity().unpack();
-                Some(cell_capacity)
-            } else {
-                None
-            }
-        })
-        .sum();
+    let funders = parse_funders(
+        &context.vault_data,
+        &context.creator_lock_hash,
+        context.vault_capacity,
+    )?;
 
-    if creator_output_capacity < context.vault_capacity {
-        Err(BizError::CapacityAdjustmentInvalid)?;
+    for (lock_hash, share) in pro_rata_shares(&funders, context.vault_capacity) {
+        if sum_capacity_by_lock_hash(&lock_hash, Source::Output)? < share {
+            Err(BizError::CapacityAdjustmentInvalid)?;
+        }
     }
 
     Ok(())
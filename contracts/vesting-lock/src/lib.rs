@@ -0,0 +1,12 @@
+#![cfg_attr(not(feature = "library"), no_std)]
+#![allow(special_module_name)]
+#![allow(unused_attributes)]
+#![deny(clippy::panic)]
+#[cfg(feature = "library")]
+mod main;
+#[cfg(feature = "library")]
+pub use main::program_entry;
+
+extern crate alloc;
+
+pub mod error;
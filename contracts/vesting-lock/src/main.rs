@@ -0,0 +1,139 @@
+#![cfg_attr(not(any(feature = "library", test)), no_std)]
+#![cfg_attr(not(test), no_main)]
+#![deny(clippy::panic)]
+
+#[cfg(any(feature = "library", test))]
+extern crate alloc;
+
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::{packed::Script, prelude::*},
+    debug,
+    high_level::{
+        load_cell, load_cell_lock, load_cell_lock_hash, load_cell_occupied_capacity,
+        load_input_since, load_script, QueryIter,
+    },
+    since::Since,
+};
+use common::{
+    schema::vesting::VestingLockArgs,
+    vesting::{relock_start_epoch, same_position, unvested_amount_of},
+};
+use molecule::prelude::Entity;
+use vesting_lock::error::{BizError, Error};
+
+common::contract_entry!(entry);
+
+fn entry() -> Result<(), Error> {
+    debug!("vesting lock contract is executing");
+
+    let script = load_script()?;
+    let args = VestingLockArgs::from_slice(&script.args().raw_data())
+        .map_err(|_| BizError::ArgumentInvalid)?;
+
+    let beneficiary_lock_hash: [u8; 32] = args.beneficiary_lock_hash().into();
+    let start_epoch: u64 = args.start_epoch().unpack();
+    let end_epoch: u64 = args.end_epoch().unpack();
+
+    if end_epoch <= start_epoch {
+        Err(BizError::ArgumentInvalid)?;
+    }
+
+    // The beneficiary must authorize every withdrawal by providing one of its
+    // own cells as an input. The vesting cells themselves never match, since
+    // they are locked by this script.
+    let beneficiary_signed = QueryIter::new(load_cell_lock_hash, Source::Input)
+        .any(|lock_hash| lock_hash == beneficiary_lock_hash);
+    if !beneficiary_signed {
+        Err(BizError::UnauthorizedAction)?;
+    }
+
+    // The cells of the beneficiary's position vest together: any vesting cell
+    // releasing to the same beneficiary by the same end epoch, whatever its
+    // locked amount and start epoch. Each one was created holding at least its
+    // locked amount, so summing their unvested amounts is exact.
+    let position_args = |lock: Script| -> Option<VestingLockArgs> {
+        if lock.code_hash() != script.code_hash() || lock.hash_type() != script.hash_type() {
+            return None;
+        }
+        VestingLockArgs::from_slice(&lock.args().raw_data())
+            .ok()
+            .filter(|other| same_position(&args, other))
+    };
+
+    let mut inputs = alloc::vec::Vec::new();
+    let mut epoch = 0;
+    for (i, lock) in QueryIter::new(load_cell_lock, Source::Input).enumerate() {
+        if let Some(input_args) = position_args(lock) {
+            // Every input's `since` holds, so the latest one is the current epoch.
+            epoch = epoch.max(current_epoch(load_input_since(i, Source::Input)?)?);
+            inputs.push(input_args);
+        }
+    }
+
+    let unvested = inputs.iter().fold(0u64, |unvested, input_args| {
+        unvested.saturating_add(unvested_amount_of(input_args, epoch))
+    });
+    if unvested == 0 {
+        debug!("Fully vested, withdrawal authorized");
+        return Ok(());
+    }
+
+    // Whatever has not vested yet must go back into vesting cells of the same
+    // position, restarting their release no earlier than now and no earlier
+    // than any of the inputs would have started.
+    let latest_start_epoch = inputs
+        .iter()
+        .map(|input_args| input_args.start_epoch().unpack())
+        .max()
+        .unwrap_or(start_epoch);
+    let min_start_epoch = relock_start_epoch(latest_start_epoch, epoch);
+
+    let mut relocked: u64 = 0;
+    for (i, output) in QueryIter::new(load_cell, Source::Output).enumerate() {
+        let Some(relock_args) = position_args(output.lock()) else {
+            continue;
+        };
+        // A relock must be a cell the chain accepts, whatever the amount it holds.
+        let relock_capacity: u64 = output.capacity().unpack();
+        if relock_capacity < load_cell_occupied_capacity(i, Source::Output)? {
+            Err(BizError::RelockInvalid)?;
+        }
+        let relock_amount: u64 = relock_args.locked_amount().unpack();
+        let relock_start_epoch: u64 = relock_args.start_epoch().unpack();
+        if relock_start_epoch < min_start_epoch
+            || relock_start_epoch >= end_epoch
+            || relock_capacity < relock_amount
+        {
+            Err(BizError::RelockInvalid)?;
+        }
+        relocked = relocked.saturating_add(relock_amount);
+    }
+
+    if relocked < unvested {
+        Err(BizError::VestedAmountExceeded)?;
+    }
+
+    Ok(())
+}
+
+fn current_epoch(since: u64) -> Result<u64, Error> {
+    // The input's `since` is the only trusted view of the current epoch: the
+    // CKB VM rejects the transaction until the chain has reached it. Without a
+    // `since`, nothing is considered vested.
+    if since == 0 {
+        return Ok(0);
+    }
+
+    let since = Since::new(since);
+    if !since.is_absolute() {
+        Err(BizError::SinceInvalid)?;
+    }
+
+    let epoch = since
+        .extract_lock_value()
+        .and_then(|v| v.epoch())
+        .ok_or(BizError::SinceInvalid)?;
+
+    Ok(epoch.number())
+}
@@ -5,9 +5,12 @@ use molecule_codegen::{Compiler, Language};
 fn main() {
     println!("cargo:rerun-if-changed=build.rs");
     println!("cargo:rerun-if-changed=../../schemas/base.mol");
+    println!("cargo:rerun-if-changed=../../schemas/bundle.mol");
     println!("cargo:rerun-if-changed=../../schemas/distribution.mol");
+    println!("cargo:rerun-if-changed=../../schemas/entity.mol");
     println!("cargo:rerun-if-changed=../../schemas/proof.mol");
     println!("cargo:rerun-if-changed=../../schemas/vault.mol");
+    println!("cargo:rerun-if-changed=../../schemas/vesting.mol");
 
     {
         let schema_path = "../../schemas/base.mol";
@@ -22,6 +25,19 @@ fn main() {
             .unwrap();
     }
 
+    {
+        let schema_path = "../../schemas/bundle.mol";
+        let mut compiler = Compiler::new();
+        let outdir = PathBuf::from("src/generated");
+
+        compiler
+            .input_schema_file(schema_path)
+            .generate_code(Language::Rust)
+            .output_dir(outdir)
+            .run()
+            .unwrap();
+    }
+
     {
         let schema_path = "../../schemas/distribution.mol";
         let mut compiler = Compiler::new();
@@ -35,6 +51,19 @@ fn main() {
             .unwrap();
     }
 
+    {
+        let schema_path = "../../schemas/entity.mol";
+        let mut compiler = Compiler::new();
+        let outdir = PathBuf::from("src/generated");
+
+        compiler
+            .input_schema_file(schema_path)
+            .generate_code(Language::Rust)
+            .output_dir(outdir)
+            .run()
+            .unwrap();
+    }
+
     {
         let schema_path = "../../schemas/proof.mol";
         let mut compiler = Compiler::new();
@@ -61,6 +90,19 @@ fn main() {
             .unwrap();
     }
 
+    {
+        let schema_path = "../../schemas/vesting.mol";
+        let mut compiler = Compiler::new();
+        let outdir = PathBuf::from("src/generated");
+
+        compiler
+            .input_schema_file(schema_path)
+            .generate_code(Language::Rust)
+            .output_dir(outdir)
+            .run()
+            .unwrap();
+    }
+
     Command::new("cargo")
         .args(["fmt"])
         .status()
@@ -0,0 +1,21 @@
+/// Returns the number of bytes a bitmap of `bits` entries occupies.
+pub fn bitmap_len(bits: usize) -> usize {
+    bits.div_ceil(8)
+}
+
+/// Checks whether bit `index` is set, with bit `index % 8` of byte `index / 8`
+/// holding it. Bits beyond the end of the bitmap are never set.
+pub fn is_bit_set(bitmap: &[u8], index: usize) -> bool {
+    bitmap
+        .get(index / 8)
+        .is_some_and(|byte| byte & (1 << (index % 8)) != 0)
+}
+
+/// Checks that every bit set in `before` is still set in `after`.
+pub fn is_superset(after: &[u8], before: &[u8]) -> bool {
+    after.len() == before.len()
+        && after
+            .iter()
+            .zip(before.iter())
+            .all(|(after, before)| after & before == *before)
+}
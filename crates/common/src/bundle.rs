@@ -0,0 +1,199 @@
+//! Portable claim bundles.
+//!
+//! A bundle carries everything a claimant needs to build a claim: the shard
+//! and proof out points, the leaf fields and the Merkle proof. It is exchanged
+//! as the `ClaimBundle` molecule table, or as its JSON mirror `ClaimBundleJson`.
+
+use alloc::{string::String, vec, vec::Vec};
+use ckb_std::ckb_types::prelude::{Pack, Unpack};
+use faster_hex::{hex_decode, hex_encode};
+use molecule::prelude::{Builder, Entity};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    base::Byte32Vec,
+    merkle::{claimant_leaf, verify_merkle_proof},
+    schema::{
+        bundle::{ClaimBundle, OutPoint},
+        distribution::{DistributionCellData, Referral, ReferralOpt},
+    },
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BundleError {
+    /// A field of the JSON mirror is not valid hex of the expected length.
+    FieldInvalid(&'static str),
+    /// The bundle belongs to another campaign than the shard.
+    CampaignMismatch,
+    /// The bundle was generated for another shard.
+    MerkleRootMismatch,
+    /// The Merkle proof does not lead from the claimant's leaf to the root.
+    MerkleProofInvalid,
+}
+
+/// Returns the leaf the bundle's claimant is committed to.
+pub fn bundle_leaf(bundle: &ClaimBundle) -> [u8; 32] {
+    let referral = bundle.referral().to_opt();
+    claimant_leaf(
+        bundle.proof_type_id().as_slice(),
+        bundle.subscriber_lock_hash().as_slice(),
+        referral.as_ref().map(|referral| referral.as_slice()),
+    )
+}
+
+/// Checks a bundle against the data of its shard, with the same Merkle proof
+/// verification as `distribution-lock`.
+pub fn verify_bundle(
+    bundle: &ClaimBundle,
+    shard_data: &DistributionCellData,
+) -> Result<(), BundleError> {
+    if bundle.campaign_id().as_slice() != shard_data.campaign_id().as_slice() {
+        return Err(BundleError::CampaignMismatch);
+    }
+    if bundle.merkle_root().as_slice() != shard_data.merkle_root().as_slice() {
+        return Err(BundleError::MerkleRootMismatch);
+    }
+    if !verify_merkle_proof(
+        shard_data.merkle_root().as_slice(),
+        bundle_leaf(bundle),
+        &bundle.merkle_proof(),
+    ) {
+        return Err(BundleError::MerkleProofInvalid);
+    }
+
+    Ok(())
+}
+
+/// The JSON mirror of `OutPoint`, in the layout of the CKB RPC.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct OutPointJson {
+    pub tx_hash: String,
+    pub index: String,
+}
+
+/// The JSON mirror of `Referral`. The bonus is a hex quantity, as in the CKB
+/// RPC.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ReferralJson {
+    pub referrer_lock_hash: String,
+    pub bonus_amount: String,
+}
+
+/// The JSON mirror of `ClaimBundle`. Hashes are `0x`-prefixed hex strings.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ClaimBundleJson {
+    pub campaign_id: String,
+    pub shard_out_point: OutPointJson,
+    pub merkle_root: String,
+    pub proof_out_point: OutPointJson,
+    pub proof_type_id: String,
+    pub subscriber_lock_hash: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub referral: Option<ReferralJson>,
+    pub merkle_proof: Vec<String>,
+}
+
+impl From<&ClaimBundle> for ClaimBundleJson {
+    fn from(bundle: &ClaimBundle) -> Self {
+        ClaimBundleJson {
+            campaign_id: to_hex(bundle.campaign_id().as_slice()),
+            shard_out_point: out_point_json(&bundle.shard_out_point()),
+            merkle_root: to_hex(bundle.merkle_root().as_slice()),
+            proof_out_point: out_point_json(&bundle.proof_out_point()),
+            proof_type_id: to_hex(bundle.proof_type_id().as_slice()),
+            subscriber_lock_hash: to_hex(bundle.subscriber_lock_hash().as_slice()),
+            referral: bundle.referral().to_opt().map(|referral| ReferralJson {
+                referrer_lock_hash: to_hex(referral.referrer_lock_hash().as_slice()),
+                bonus_amount: to_quantity(referral.bonus_amount().unpack()),
+            }),
+            merkle_proof: bundle
+                .merkle_proof()
+                .into_iter()
+                .map(|sibling| to_hex(sibling.as_slice()))
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<&ClaimBundleJson> for ClaimBundle {
+    type Error = BundleError;
+
+    fn try_from(json: &ClaimBundleJson) -> Result<Self, Self::Error> {
+        let referral = match &json.referral {
+            Some(referral) => Some(
+                Referral::new_builder()
+                    .referrer_lock_hash(
+                        from_hex::<32>(&referral.referrer_lock_hash, "referrer_lock_hash")?.into(),
+                    )
+                    .bonus_amount(from_quantity(&referral.bonus_amount, "bonus_amount")?.pack())
+                    .build(),
+            ),
+            None => None,
+        };
+        let merkle_proof = json
+            .merkle_proof
+            .iter()
+            .map(|sibling| from_hex::<32>(sibling, "merkle_proof").map(Into::into))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(ClaimBundle::new_builder()
+            .campaign_id(from_hex::<32>(&json.campaign_id, "campaign_id")?.into())
+            .shard_out_point(out_point(&json.shard_out_point, "shard_out_point")?)
+            .merkle_root(from_hex::<32>(&json.merkle_root, "merkle_root")?.into())
+            .proof_out_point(out_point(&json.proof_out_point, "proof_out_point")?)
+            .proof_type_id(from_hex::<32>(&json.proof_type_id, "proof_type_id")?.into())
+            .subscriber_lock_hash(
+                from_hex::<32>(&json.subscriber_lock_hash, "subscriber_lock_hash")?.into(),
+            )
+            .referral(ReferralOpt::new_builder().set(referral).build())
+            .merkle_proof(Byte32Vec::new_builder().set(merkle_proof).build())
+            .build())
+    }
+}
+
+fn out_point_json(out_point: &OutPoint) -> OutPointJson {
+    OutPointJson {
+        tx_hash: to_hex(out_point.tx_hash().as_slice()),
+        index: to_quantity(Unpack::<u32>::unpack(&out_point.index()).into()),
+    }
+}
+
+fn out_point(json: &OutPointJson, field: &'static str) -> Result<OutPoint, BundleError> {
+    let index = u32::try_from(from_quantity(&json.index, field)?)
+        .map_err(|_| BundleError::FieldInvalid(field))?;
+    Ok(OutPoint::new_builder()
+        .tx_hash(from_hex::<32>(&json.tx_hash, field)?.into())
+        .index(index.pack())
+        .build())
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    let mut hex = vec![0u8; bytes.len() * 2];
+    hex_encode(bytes, &mut hex).expect("the buffer fits the encoding");
+    let mut value = String::from("0x");
+    value.push_str(core::str::from_utf8(&hex).expect("hex is ascii"));
+    value
+}
+
+fn from_hex<const N: usize>(value: &str, field: &'static str) -> Result<[u8; N], BundleError> {
+    let hex = value
+        .strip_prefix("0x")
+        .ok_or(BundleError::FieldInvalid(field))?;
+    if hex.len() != N * 2 {
+        return Err(BundleError::FieldInvalid(field));
+    }
+    let mut bytes = [0u8; N];
+    hex_decode(hex.as_bytes(), &mut bytes).map_err(|_| BundleError::FieldInvalid(field))?;
+    Ok(bytes)
+}
+
+fn to_quantity(value: u64) -> String {
+    alloc::format!("{:#x}", value)
+}
+
+fn from_quantity(value: &str, field: &'static str) -> Result<u64, BundleError> {
+    let hex = value
+        .strip_prefix("0x")
+        .ok_or(BundleError::FieldInvalid(field))?;
+    u64::from_str_radix(hex, 16).map_err(|_| BundleError::FieldInvalid(field))
+}
@@ -0,0 +1,328 @@
+//! Scaffolding shared by the contracts: their entry point, their error type and
+//! the loops over cells they all need.
+//!
+//! The helpers load cells with the low-level syscalls instead of `QueryIter`,
+//! which panics on any error other than `IndexOutOfBound`, so a failure in
+//! them reaches the contract as a `SysError` with a registered exit code. The
+//! loops that only one contract needs still iterate with `QueryIter`, where a
+//! failing syscall ends the script through ckb-std's panic handler, with exit
+//! code -1, rather than with a registered code.
+//!
+//! Nothing here may panic. Clippy does not lint the expansions of these macros
+//! in the contracts, as they come from another crate, so the lint is denied
+//! here and the test module expands them locally.
+
+#![deny(clippy::panic)]
+
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::packed::CellOutput,
+    error::SysError,
+    high_level::{load_cell, load_cell_capacity, load_cell_data, load_cell_lock_hash},
+};
+use molecule::prelude::Entity;
+
+/// Declares `program_entry`, which runs `$entry` and turns its error into the
+/// exit code, along with the VM entry point and allocator of the contract.
+///
+/// The entry point and allocator are left out of `library` and test builds,
+/// as the contracts' own `main.rs` did.
+#[macro_export]
+macro_rules! contract_entry {
+    ($entry:ident) => {
+        #[cfg(not(any(feature = "library", test)))]
+        ckb_std::entry!(program_entry);
+        // By default, the following heap configuration is used:
+        // * 16KB fixed heap
+        // * 1.2MB(rounded up to be 16-byte aligned) dynamic heap
+        // * Minimal memory block in dynamic heap is 64 bytes
+        // For more details, please refer to ckb-std's default_alloc macro
+        // and the buddy-alloc alloc implementation.
+        #[cfg(not(any(feature = "library", test)))]
+        ckb_std::default_alloc!(16384, 1258306, 64);
+
+        pub fn program_entry() -> i8 {
+            match $entry() {
+                Ok(()) => 0,
+                Err(err) => err.into(),
+            }
+        }
+    };
+}
+
+/// Declares the `Error` of a contract, wrapping syscall errors, the errors of
+/// the shared helpers and `$biz`, the contract's enum in `common::error`,
+/// which is re-exported as `BizError`.
+///
+/// `$label` names the contract in the debug output of a failure.
+#[macro_export]
+macro_rules! contract_error {
+    ($biz:path, $label:literal) => {
+        pub use $biz as BizError;
+
+        #[derive(Debug)]
+        pub enum Error {
+            Sys(ckb_std::error::SysError),
+            Biz(BizError),
+            Common($crate::error::Error),
+        }
+
+        impl From<ckb_std::error::SysError> for Error {
+            fn from(err: ckb_std::error::SysError) -> Self {
+                Error::Sys(err)
+            }
+        }
+
+        impl From<BizError> for Error {
+            fn from(err: BizError) -> Self {
+                Error::Biz(err)
+            }
+        }
+
+        impl From<$crate::error::Error> for Error {
+            fn from(err: $crate::error::Error) -> Self {
+                Self::Common(err)
+            }
+        }
+
+        impl From<Error> for i8 {
+            fn from(err: Error) -> i8 {
+                ckb_std::debug!("{} error {:?}", $label, err);
+                match err {
+                    Error::Sys(v) => $crate::error::Error::from(v) as i8,
+                    Error::Biz(v) => v as i8,
+                    Error::Common(v) => v as i8,
+                }
+            }
+        }
+    };
+}
+
+/// Sums the capacity of the cells of `source` locked with `lock_hash`.
+pub fn sum_capacity_by_lock_hash(lock_hash: &[u8], source: Source) -> Result<u64, SysError> {
+    sum_capacity_with(
+        lock_hash,
+        |index| load_cell_lock_hash(index, source),
+        |index| load_cell_capacity(index, source),
+    )
+}
+
+/// Finds the only output for which `matches`, given the output and its lock
+/// hash, holds.
+///
+/// Returns `Ok(None)` when no output matches, and `duplicate` when more than
+/// one does.
+pub fn find_unique_output<E, F>(duplicate: E, matches: F) -> Result<Option<usize>, E>
+where
+    E: From<SysError>,
+    F: FnMut(&CellOutput, &[u8; 32]) -> bool,
+{
+    find_unique_with(
+        duplicate,
+        |index| {
+            let cell = load_cell(index, Source::Output)?;
+            let lock_hash = load_cell_lock_hash(index, Source::Output)?;
+            Ok((cell, lock_hash))
+        },
+        matches,
+    )
+}
+
+/// The loop of `sum_capacity_by_lock_hash`, over the cells `load_lock_hash`
+/// and `load_capacity` read until `IndexOutOfBound`.
+fn sum_capacity_with<L, C>(
+    lock_hash: &[u8],
+    mut load_lock_hash: L,
+    mut load_capacity: C,
+) -> Result<u64, SysError>
+where
+    L: FnMut(usize) -> Result<[u8; 32], SysError>,
+    C: FnMut(usize) -> Result<u64, SysError>,
+{
+    let mut capacity = 0u64;
+    let mut index = 0;
+    loop {
+        match load_lock_hash(index) {
+            Ok(cell_lock_hash) => {
+                if cell_lock_hash == lock_hash {
+                    capacity = capacity.saturating_add(load_capacity(index)?);
+                }
+            }
+            Err(SysError::IndexOutOfBound) => return Ok(capacity),
+            Err(err) => return Err(err),
+        }
+        index += 1;
+    }
+}
+
+/// The loop of `find_unique_output`, over the cells and lock hashes `load`
+/// reads until `IndexOutOfBound`.
+fn find_unique_with<E, L, F>(duplicate: E, mut load: L, mut matches: F) -> Result<Option<usize>, E>
+where
+    E: From<SysError>,
+    L: FnMut(usize) -> Result<(CellOutput, [u8; 32]), SysError>,
+    F: FnMut(&CellOutput, &[u8; 32]) -> bool,
+{
+    let mut found = None;
+    let mut index = 0;
+    loop {
+        let (cell, lock_hash) = match load(index) {
+            Ok(loaded) => loaded,
+            Err(SysError::IndexOutOfBound) => return Ok(found),
+            Err(err) => return Err(err.into()),
+        };
+        if matches(&cell, &lock_hash) {
+            if found.is_some() {
+                return Err(duplicate);
+            }
+            found = Some(index);
+        }
+        index += 1;
+    }
+}
+
+/// Loads the data of the first cell of `source`, a group source, as a `T`.
+///
+/// Returns `Ok(None)` when the data is not a valid `T`.
+pub fn load_group_data<T: Entity>(source: Source) -> Result<Option<T>, SysError> {
+    let data = load_cell_data(0, source)?;
+    Ok(T::from_slice(&data).ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+    use ckb_std::{ckb_types::packed::CellOutput, error::SysError};
+    use molecule::prelude::{Builder, Entity};
+
+    use super::{find_unique_with, sum_capacity_with};
+
+    /// `contract_error!` expanded in this crate, under this module's lint
+    /// denial. `make check-panics` adds a probe panic with `--cfg panic_probe`
+    /// and expects clippy to reject it.
+    mod expansion {
+        use ckb_std::error::SysError;
+
+        crate::contract_error!(crate::error::ProofLockError, "probe");
+
+        #[cfg(panic_probe)]
+        #[allow(dead_code)]
+        pub fn probe() {
+            panic!("probe");
+        }
+
+        #[test]
+        fn every_error_converts_to_its_code() {
+            assert_eq!(
+                i8::from(Error::from(BizError::ArgumentInvalid)),
+                BizError::ArgumentInvalid as i8
+            );
+            assert_eq!(
+                i8::from(Error::from(SysError::Unknown(7))),
+                crate::error::Error::from(SysError::Unknown(7)) as i8
+            );
+        }
+    }
+
+    const ALICE: [u8; 32] = [1; 32];
+    const BOB: [u8; 32] = [2; 32];
+
+    /// Serves `cells` by index, then `IndexOutOfBound`.
+    fn loader<T: Clone>(cells: &[T]) -> impl FnMut(usize) -> Result<T, SysError> + '_ {
+        |index| cells.get(index).cloned().ok_or(SysError::IndexOutOfBound)
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum TestError {
+        Sys(SysError),
+        Duplicate,
+    }
+
+    impl From<SysError> for TestError {
+        fn from(err: SysError) -> Self {
+            TestError::Sys(err)
+        }
+    }
+
+    fn cells(lock_hashes: &[[u8; 32]]) -> Vec<(CellOutput, [u8; 32])> {
+        lock_hashes
+            .iter()
+            .map(|lock_hash| (CellOutput::new_builder().build(), *lock_hash))
+            .collect()
+    }
+
+    #[test]
+    fn sum_capacity_adds_up_the_matching_cells() {
+        let lock_hashes = [ALICE, BOB, ALICE];
+        let capacities = [10, 20, 30];
+        let sum = sum_capacity_with(&ALICE, loader(&lock_hashes), loader(&capacities));
+        assert_eq!(sum, Ok(40));
+    }
+
+    #[test]
+    fn sum_capacity_of_no_cells_is_zero() {
+        let sum = sum_capacity_with(&ALICE, loader(&[]), loader(&[]));
+        assert_eq!(sum, Ok(0));
+    }
+
+    #[test]
+    fn sum_capacity_saturates() {
+        let lock_hashes = [ALICE, ALICE];
+        let capacities = [u64::MAX, 1];
+        let sum = sum_capacity_with(&ALICE, loader(&lock_hashes), loader(&capacities));
+        assert_eq!(sum, Ok(u64::MAX));
+    }
+
+    #[test]
+    fn sum_capacity_only_loads_the_capacity_of_matching_cells() {
+        let lock_hashes = [BOB, ALICE];
+        let sum = sum_capacity_with(&ALICE, loader(&lock_hashes), |index| match index {
+            1 => Ok(5),
+            _ => Err(SysError::ItemMissing),
+        });
+        assert_eq!(sum, Ok(5));
+    }
+
+    #[test]
+    fn sum_capacity_propagates_other_errors() {
+        let sum = sum_capacity_with(&ALICE, |_| Err(SysError::Encoding), loader(&[]));
+        assert_eq!(sum, Err(SysError::Encoding));
+    }
+
+    #[test]
+    fn find_unique_returns_the_only_match() {
+        let cells = cells(&[BOB, ALICE, BOB]);
+        let found = find_unique_with(TestError::Duplicate, loader(&cells), |_, lock_hash| {
+            *lock_hash == ALICE
+        });
+        assert_eq!(found, Ok(Some(1)));
+    }
+
+    #[test]
+    fn find_unique_returns_none_without_a_match() {
+        let cells = cells(&[BOB, BOB]);
+        let found = find_unique_with(TestError::Duplicate, loader(&cells), |_, lock_hash| {
+            *lock_hash == ALICE
+        });
+        assert_eq!(found, Ok(None));
+    }
+
+    #[test]
+    fn find_unique_rejects_a_second_match() {
+        let cells = cells(&[ALICE, BOB, ALICE]);
+        let found = find_unique_with(TestError::Duplicate, loader(&cells), |_, lock_hash| {
+            *lock_hash == ALICE
+        });
+        assert_eq!(found, Err(TestError::Duplicate));
+    }
+
+    #[test]
+    fn find_unique_propagates_other_errors() {
+        let found = find_unique_with(
+            TestError::Duplicate,
+            |_| Err(SysError::LengthNotEnough(8)),
+            |_, _| true,
+        );
+        assert_eq!(found, Err(TestError::Sys(SysError::LengthNotEnough(8))));
+    }
+}
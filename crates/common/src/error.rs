@@ -1,22 +1,341 @@
+//! The registry of the exit codes of every contract.
+//!
+//! Each contract owns a disjoint range of codes, so a code identifies the
+//! error regardless of which script returned it:
+//!
+//! | Codes      | Owner                      |
+//! |------------|----------------------------|
+//! | 1..=19     | `SysError`, as `Error`     |
+//! | 20..=24    | `vault-lock`               |
+//! | 25..=29    | `vesting-lock`             |
+//! | 30..=34    | `distribution-lock`        |
+//! | 35..=44    | `entity-type`              |
+//! | 45..=64    | `vault-type`               |
+//! | 65..=84    | `proof-type`               |
+//! | 85..=119   | `distribution-type`        |
+//! | 120..=124  | `proof-lock`               |
+//!
+//! New errors are appended to the end of their contract's enum, so existing
+//! codes never change.
+
 use ckb_std::error::SysError;
 
-#[derive(Debug)]
-#[repr(i8)]
-pub enum Error {
-    IndexOutOfBound = 1,
-    ItemMissing,
-    LengthNotEnough,
-    Encoding,
+/// Declares the error enum of one owner of the registry. Variants are numbered
+/// from `base`, and the enum can be decoded back from its code.
+macro_rules! error_codes {
+    (
+        $(#[$meta:meta])*
+        $name:ident: $base:literal..=$last:literal {
+            $first:ident
+            $(, $variant:ident)* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #[repr(i8)]
+        pub enum $name {
+            $first = $base,
+            $($variant,)*
+        }
+
+        impl $name {
+            pub const FIRST_CODE: i8 = $base;
+            pub const LAST_CODE: i8 = $last;
+
+            const VARIANTS: &'static [$name] = &[$name::$first, $($name::$variant,)*];
+
+            /// Returns the error with the given exit code.
+            pub fn from_code(code: i8) -> Option<Self> {
+                if code < Self::FIRST_CODE {
+                    return None;
+                }
+                Self::VARIANTS.get((code - Self::FIRST_CODE) as usize).copied()
+            }
+
+            /// Returns the name of the error.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $name::$first => stringify!($first),
+                    $($name::$variant => stringify!($variant),)*
+                }
+            }
+        }
+
+        // Fails the build when a contract outgrows its range.
+        const _: () = assert!(
+            $name::FIRST_CODE as usize + $name::VARIANTS.len() - 1 <= $name::LAST_CODE as usize
+        );
+    };
+}
+
+error_codes! {
+    /// The syscall errors of `SysError`, numbered as ckb-std numbers them. A
+    /// contract exits with the same code whether the error reached it directly
+    /// or through a shared helper.
+    Error: 1..=19 {
+        IndexOutOfBound,
+        ItemMissing,
+        LengthNotEnough,
+        Encoding,
+        WaitFailure,
+        InvalidFd,
+        OtherEndClosed,
+        MaxVmsSpawned,
+        MaxFdsCreated,
+        TypeIdError,
+        Unknown,
+    }
 }
 
 impl From<SysError> for Error {
     fn from(err: SysError) -> Self {
+        // The match is exhaustive on purpose: a variant added by a ckb-std
+        // upgrade fails the build until it is given a code.
         match err {
             SysError::IndexOutOfBound => Self::IndexOutOfBound,
             SysError::ItemMissing => Self::ItemMissing,
             SysError::LengthNotEnough(_) => Self::LengthNotEnough,
             SysError::Encoding => Self::Encoding,
-            _ => panic!("unexpected sys error"),
+            SysError::WaitFailure => Self::WaitFailure,
+            SysError::InvalidFd => Self::InvalidFd,
+            SysError::OtherEndClosed => Self::OtherEndClosed,
+            SysError::MaxVmsSpawned => Self::MaxVmsSpawned,
+            SysError::MaxFdsCreated => Self::MaxFdsCreated,
+            SysError::TypeIDError => Self::TypeIdError,
+            SysError::Unknown(_) => Self::Unknown,
         }
     }
 }
+
+error_codes! {
+    VaultLockError: 20..=24 {
+        ArgumentLengthInvalid,
+        UnauthorizedAction,
+    }
+}
+
+error_codes! {
+    VestingLockError: 25..=29 {
+        // General
+        ArgumentInvalid,
+        UnauthorizedAction,
+
+        // Withdrawal
+        SinceInvalid,
+        VestedAmountExceeded,
+        RelockInvalid,
+    }
+}
+
+error_codes! {
+    DistributionLockError: 30..=34 {
+        // General
+        WitnessDataInvalid,
+
+        // Claim
+        DistributionDataInvalid,
+        MerkleProofInvalid,
+    }
+}
+
+error_codes! {
+    EntityTypeError: 35..=44 {
+        // General
+        EntityTransactionInvalid,
+        EntityDataInvalid,
+
+        // Creation
+        EntityIdMismatch,
+        CreatorLockHashInvalid,
+        CreatorLockHashMismatch,
+        MetadataHashInvalid,
+
+        // Update
+        EntityDataImmutable,
+    }
+}
+
+error_codes! {
+    VaultTypeError: 45..=64 {
+        // General
+        ArgumentLengthInvalid,
+        VaultDataInvalid,
+        VaultTransactionInvalid,
+
+        // Creation
+        FeePercentageOutOfRange,
+
+        // Distribution
+        CapacityMismatch, // Used for all capacity checks
+        DistributionDataInvalid,
+        ShardRewardInconsistent,
+        FeeCapacityMismatch,
+
+        // Refund / Capacity Adjustment
+        CapacityAdjustmentInvalid,
+        VaultDataImmutable,
+        VaultLockScriptImmutable,
+
+        // Funders
+        FunderLedgerInvalid,
+
+        // Fee Schedule
+        FeeScheduleInvalid,
+        FeeModelInvalid,
+
+        // Entity Targeting
+        EntityTargetingInvalid,
+    }
+}
+
+error_codes! {
+    ProofTypeError: 65..=84 {
+        // General
+        ProofTransactionInvalid,
+        ProofCellUpdateInvalid,
+
+        // Creation
+        ProofDataInvalid,
+        ProofEntityIdInvalid,
+        ProofCampaignIdInvalid,
+        ProofHashInvalid,
+        SubscriberLockHashInvalid,
+        SubscriberLockHashMismatch,
+
+        // Campaign Bitmap
+        ClaimedCampaignsInvalid,
+
+        // Expiry
+        ProofRefundInvalid,
+
+        // Consumption
+        ProofConsumptionInvalid,
+
+        // Entity Registry
+        ProofScriptArgsInvalid,
+        EntityNotRegistered,
+    }
+}
+
+error_codes! {
+    DistributionTypeError: 85..=119 {
+        // General
+        DistributionTransactionInvalid,
+        WitnessDataInvalid,
+
+        // Creation
+        ShardCreationDataInvalid,
+        ShardCreationDataInconsistent,
+
+        // Claim
+        ClaimTransactionInvalid,
+        FinalClaimCapacityInvalid,
+        ProofCellCountInvalid,
+        ProofDataInvalid,
+        ProofCampaignIdMismatch,
+        ProofSubscriberLockHashMismatch,
+        ProofLockHashMismatch,
+        ProofTypeIdMismatch,
+        RewardCapacityInvalid,
+        RewardLockHashMismatch,
+        ShardCapacityUpdateInvalid,
+        ShardDataImmutable,
+        ShardTypeScriptImmutable,
+
+        // Reclamation
+        ReclamationSinceInvalid,
+        ReclamationLockHashMismatch,
+        ReclamationCapacityMismatch,
+
+        // Claim Counter
+        RemainingClaimsInvalid,
+
+        // Referral
+        ReferralInvalid,
+        ReferralBudgetExceeded,
+        ReferralPayoutMissing,
+
+        // Commit-Reveal
+        ProofRevealMismatch,
+
+        // Proof Reuse
+        ProofCampaignAlreadyClaimed,
+        ProofCampaignNotMarked,
+
+        // Entity Targeting
+        EntityNotTargeted,
+
+        // Entity Registry
+        ProofScriptArgsInvalid,
+        EntityRegistryMismatch,
+        EntityCreatorMismatch,
+
+        // Proof Script
+        ProofDistributionTypeMismatch,
+    }
+}
+
+error_codes! {
+    ProofLockError: 120..=124 {
+        // General
+        ArgumentInvalid,
+        UnauthorizedAction,
+
+        // Cleanup
+        ProofDataInvalid,
+        RefundInvalid,
+    }
+}
+
+// Fails the build when two ranges overlap.
+const _: () = assert!(
+    Error::LAST_CODE < VaultLockError::FIRST_CODE
+        && VaultLockError::LAST_CODE < VestingLockError::FIRST_CODE
+        && VestingLockError::LAST_CODE < DistributionLockError::FIRST_CODE
+        && DistributionLockError::LAST_CODE < EntityTypeError::FIRST_CODE
+        && EntityTypeError::LAST_CODE < VaultTypeError::FIRST_CODE
+        && VaultTypeError::LAST_CODE < ProofTypeError::FIRST_CODE
+        && ProofTypeError::LAST_CODE < DistributionTypeError::FIRST_CODE
+        && DistributionTypeError::LAST_CODE < ProofLockError::FIRST_CODE
+);
+
+/// The contracts of the platform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContractScript {
+    VaultLock,
+    VaultType,
+    DistributionLock,
+    DistributionType,
+    ProofType,
+    EntityType,
+    VestingLock,
+    ProofLock,
+}
+
+/// Returns the name of the error `script` exited with, or `"Unknown"` for a
+/// code outside the shared ranges and the script's own range.
+pub fn decode_error(script: ContractScript, code: i8) -> &'static str {
+    match code {
+        0 => Some("Success"),
+        1..=19 => Error::from_code(code).map(Error::name),
+        _ => match script {
+            ContractScript::VaultLock => VaultLockError::from_code(code).map(VaultLockError::name),
+            ContractScript::VaultType => VaultTypeError::from_code(code).map(VaultTypeError::name),
+            ContractScript::DistributionLock => {
+                DistributionLockError::from_code(code).map(DistributionLockError::name)
+            }
+            ContractScript::DistributionType => {
+                DistributionTypeError::from_code(code).map(DistributionTypeError::name)
+            }
+            ContractScript::ProofType => ProofTypeError::from_code(code).map(ProofTypeError::name),
+            ContractScript::EntityType => {
+                EntityTypeError::from_code(code).map(EntityTypeError::name)
+            }
+            ContractScript::VestingLock => {
+                VestingLockError::from_code(code).map(VestingLockError::name)
+            }
+            ContractScript::ProofLock => ProofLockError::from_code(code).map(ProofLockError::name),
+        },
+    }
+    .unwrap_or("Unknown")
+}
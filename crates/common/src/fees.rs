@@ -0,0 +1,213 @@
+//! The platform fee rules of a vault.
+//!
+//! `vault-type` enforces them and the SDK builds transactions with the same
+//! routines, so a fee the SDK computes is one the chain accepts.
+
+use alloc::vec::Vec;
+use ckb_std::ckb_types::prelude::{Pack, Unpack};
+use molecule::prelude::{Builder, Byte, Entity};
+
+use crate::{
+    error::VaultTypeError,
+    schema::vault::{self, VaultCellData},
+    NULL_HASH,
+};
+
+/// The basis points a fee schedule must add up to.
+pub const FEE_SCHEDULE_TOTAL_BASIS_POINTS: u32 = 10000;
+
+/// The fee is `fee_percentage` of the vault capacity, bounded by the caps.
+pub const FEE_KIND_PERCENTAGE: u8 = 0;
+/// The fee is a fixed amount.
+pub const FEE_KIND_FLAT: u8 = 1;
+/// No fee is charged and no fee cell is created.
+pub const FEE_KIND_NONE: u8 = 2;
+
+/// A single fee payout: the beneficiary's lock hash and the capacity it is owed.
+pub type FeeShare = ([u8; 32], u64);
+
+/// How the platform fee of a vault is computed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeModel {
+    /// `fee_percentage` of the vault capacity, bounded by `min_fee` and
+    /// `max_fee`. A `max_fee` of 0 means uncapped.
+    Percentage { min_fee: u64, max_fee: u64 },
+    /// A fixed fee, regardless of the vault capacity.
+    Flat(u64),
+    /// No fee at all.
+    None,
+}
+
+impl Default for FeeModel {
+    fn default() -> Self {
+        FeeModel::Percentage {
+            min_fee: 0,
+            max_fee: 0,
+        }
+    }
+}
+
+impl FeeModel {
+    /// Reads the fee model stored in vault data.
+    pub fn from_vault_data(vault_data: &VaultCellData) -> Result<Self, VaultTypeError> {
+        let fee_model = vault_data.fee_model();
+        let kind: u8 = fee_model.kind().into();
+        let flat_fee: u64 = fee_model.flat_fee().unpack();
+        let min_fee: u64 = fee_model.min_fee().unpack();
+        let max_fee: u64 = fee_model.max_fee().unpack();
+
+        match kind {
+            FEE_KIND_PERCENTAGE if flat_fee == 0 => Ok(FeeModel::Percentage { min_fee, max_fee }),
+            FEE_KIND_FLAT if min_fee == 0 && max_fee == 0 => Ok(FeeModel::Flat(flat_fee)),
+            FEE_KIND_NONE if flat_fee == 0 && min_fee == 0 && max_fee == 0 => Ok(FeeModel::None),
+            _ => Err(VaultTypeError::FeeModelInvalid),
+        }
+    }
+
+    /// Returns the molecule encoding stored in vault data.
+    pub fn to_molecule(&self) -> vault::FeeModel {
+        let (kind, flat_fee, min_fee, max_fee) = match *self {
+            FeeModel::Percentage { min_fee, max_fee } => (FEE_KIND_PERCENTAGE, 0, min_fee, max_fee),
+            FeeModel::Flat(flat_fee) => (FEE_KIND_FLAT, flat_fee, 0, 0),
+            FeeModel::None => (FEE_KIND_NONE, 0, 0, 0),
+        };
+
+        vault::FeeModel::new_builder()
+            .kind(Byte::new(kind))
+            .flat_fee(flat_fee.pack())
+            .min_fee(min_fee.pack())
+            .max_fee(max_fee.pack())
+            .build()
+    }
+}
+
+/// Checks that the fee model of the vault data is well formed.
+pub fn verify_fee_model(vault_data: &VaultCellData) -> Result<FeeModel, VaultTypeError> {
+    let fee_percentage: u16 = vault_data.fee_percentage().unpack();
+    if fee_percentage > 10000 {
+        return Err(VaultTypeError::FeePercentageOutOfRange);
+    }
+
+    let fee_model = FeeModel::from_vault_data(vault_data)?;
+    let valid = match fee_model {
+        FeeModel::Percentage { min_fee, max_fee } => max_fee == 0 || min_fee <= max_fee,
+        FeeModel::Flat(flat_fee) => flat_fee > 0,
+        FeeModel::None => fee_percentage == 0 && vault_data.fee_schedule().is_empty(),
+    };
+    if !valid {
+        return Err(VaultTypeError::FeeModelInvalid);
+    }
+
+    Ok(fee_model)
+}
+
+/// Checks that a vault of `vault_capacity` can pay the smallest fee `fee_model`
+/// charges: the flat fee, or the minimum fee of a percentage. A vault that
+/// cannot would never be distributed.
+pub fn verify_fee_capacity(
+    fee_model: &FeeModel,
+    vault_capacity: u64,
+) -> Result<(), VaultTypeError> {
+    let smallest_fee = match *fee_model {
+        FeeModel::Percentage { min_fee, .. } => min_fee,
+        FeeModel::Flat(flat_fee) => flat_fee,
+        FeeModel::None => 0,
+    };
+    if smallest_fee > vault_capacity {
+        return Err(VaultTypeError::FeeModelInvalid);
+    }
+
+    Ok(())
+}
+
+/// Computes the platform fee owed on a vault of `vault_capacity`.
+pub fn expected_fee(
+    vault_data: &VaultCellData,
+    vault_capacity: u64,
+) -> Result<u64, VaultTypeError> {
+    let fee = match verify_fee_model(vault_data)? {
+        FeeModel::Flat(flat_fee) => flat_fee,
+        FeeModel::None => 0,
+        FeeModel::Percentage { min_fee, max_fee } => {
+            let fee_percentage: u128 = vault_data.fee_percentage().unpack().into();
+
+            let mut fee = (vault_capacity as u128 * fee_percentage / 10000) as u64;
+            if fee < min_fee {
+                fee = min_fee;
+            }
+            if max_fee != 0 && fee > max_fee {
+                fee = max_fee;
+            }
+            fee
+        }
+    };
+
+    if fee > vault_capacity {
+        return Err(VaultTypeError::VaultTransactionInvalid);
+    }
+
+    Ok(fee)
+}
+
+/// Parses the fee schedule from the vault data.
+///
+/// An empty schedule sends the whole fee to the admin, so it is normalized into
+/// a single admin entry. Entries of a non-empty schedule must be unique,
+/// non-null, non-zero and add up to `FEE_SCHEDULE_TOTAL_BASIS_POINTS`.
+pub fn parse_fee_schedule(
+    vault_data: &VaultCellData,
+    admin_lock_hash: &[u8; 32],
+) -> Result<Vec<([u8; 32], u16)>, VaultTypeError> {
+    let schedule = vault_data.fee_schedule();
+    if schedule.is_empty() {
+        return Ok(alloc::vec![(
+            *admin_lock_hash,
+            FEE_SCHEDULE_TOTAL_BASIS_POINTS as u16
+        )]);
+    }
+
+    let mut beneficiaries: Vec<([u8; 32], u16)> = Vec::with_capacity(schedule.len());
+    let mut total: u32 = 0;
+    for beneficiary in schedule.into_iter() {
+        let lock_hash: [u8; 32] = beneficiary.lock_hash().into();
+        let basis_points: u16 = beneficiary.basis_points().unpack();
+
+        if lock_hash == NULL_HASH
+            || basis_points == 0
+            || beneficiaries.iter().any(|(hash, _)| *hash == lock_hash)
+        {
+            return Err(VaultTypeError::FeeScheduleInvalid);
+        }
+
+        total += basis_points as u32;
+        beneficiaries.push((lock_hash, basis_points));
+    }
+
+    if total != FEE_SCHEDULE_TOTAL_BASIS_POINTS {
+        return Err(VaultTypeError::FeeScheduleInvalid);
+    }
+
+    Ok(beneficiaries)
+}
+
+/// Splits `total_fee` across the schedule's beneficiaries.
+///
+/// Shares are rounded down and the remainder goes to the first beneficiary, so
+/// the split is deterministic and always adds up to `total_fee`.
+pub fn fee_shares(beneficiaries: &[([u8; 32], u16)], total_fee: u64) -> Vec<FeeShare> {
+    let mut shares: Vec<FeeShare> = beneficiaries
+        .iter()
+        .map(|(lock_hash, basis_points)| {
+            let share = (total_fee as u128 * *basis_points as u128
+                / FEE_SCHEDULE_TOTAL_BASIS_POINTS as u128) as u64;
+            (*lock_hash, share)
+        })
+        .collect();
+
+    let distributed: u64 = shares.iter().map(|(_, share)| *share).sum();
+    if let Some(first) = shares.first_mut() {
+        first.1 += total_fee - distributed;
+    }
+
+    shares
+}
@@ -0,0 +1,86 @@
+//! The funders ledger of a vault.
+//!
+//! `vault-type` enforces these rules and the SDK builds refunds with the same
+//! routines.
+
+use alloc::vec::Vec;
+use ckb_std::ckb_types::prelude::Unpack;
+
+use crate::{error::VaultTypeError, schema::vault::VaultCellData};
+
+/// A single ledger entry: the funder's lock hash and the amount it is owed.
+pub type FunderShare = ([u8; 32], u64);
+
+/// Parses the funders ledger from the vault data.
+///
+/// An empty ledger means the creator funded the whole vault, so it is
+/// normalized into a single creator entry owning `vault_capacity`. Entries of
+/// a non-empty ledger must be unique and non-zero.
+pub fn parse_funders(
+    vault_data: &VaultCellData,
+    creator_lock_hash: &[u8; 32],
+    vault_capacity: u64,
+) -> Result<Vec<FunderShare>, VaultTypeError> {
+    let funders = vault_data.funders();
+    if funders.is_empty() {
+        return Ok(alloc::vec![(*creator_lock_hash, vault_capacity)]);
+    }
+
+    let mut shares: Vec<FunderShare> = Vec::with_capacity(funders.len());
+    for funder in funders.into_iter() {
+        let lock_hash: [u8; 32] = funder.lock_hash().into();
+        let amount: u64 = funder.amount().unpack();
+
+        if amount == 0 || shares.iter().any(|(hash, _)| *hash == lock_hash) {
+            return Err(VaultTypeError::FunderLedgerInvalid);
+        }
+        shares.push((lock_hash, amount));
+    }
+
+    Ok(shares)
+}
+
+/// Parses the funders ledger and checks that it accounts for exactly
+/// `vault_capacity`.
+pub fn load_funders(
+    vault_data: &VaultCellData,
+    creator_lock_hash: &[u8; 32],
+    vault_capacity: u64,
+) -> Result<Vec<FunderShare>, VaultTypeError> {
+    let shares = parse_funders(vault_data, creator_lock_hash, vault_capacity)?;
+
+    let mut total: u64 = 0;
+    for (_, amount) in shares.iter() {
+        total = total
+            .checked_add(*amount)
+            .ok_or(VaultTypeError::FunderLedgerInvalid)?;
+    }
+    if total != vault_capacity {
+        return Err(VaultTypeError::FunderLedgerInvalid);
+    }
+
+    Ok(shares)
+}
+
+/// Splits `capacity` across the funders in proportion to their ledger amounts.
+///
+/// Shares are rounded down and the remainder goes to the first funder, so the
+/// split is deterministic and always adds up to `capacity`.
+pub fn pro_rata_shares(funders: &[FunderShare], capacity: u64) -> Vec<FunderShare> {
+    let total: u128 = funders.iter().map(|(_, amount)| *amount as u128).sum();
+
+    let mut shares: Vec<FunderShare> = funders
+        .iter()
+        .map(|(lock_hash, amount)| {
+            let share = (capacity as u128 * *amount as u128 / total) as u64;
+            (*lock_hash, share)
+        })
+        .collect();
+
+    let distributed: u64 = shares.iter().map(|(_, share)| *share).sum();
+    if let Some(first) = shares.first_mut() {
+        first.1 += capacity - distributed;
+    }
+
+    shares
+}
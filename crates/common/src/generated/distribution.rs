@@ -30,6 +30,12 @@ impl ::core::fmt::Display for DistributionCellData {
             "proof_script_code_hash",
             self.proof_script_code_hash()
         )?;
+        write!(
+            f,
+            ", {}: {}",
+            "proof_script_hash_type",
+            self.proof_script_hash_type()
+        )?;
         write!(
             f,
             ", {}: {}",
@@ -37,6 +43,17 @@ impl ::core::fmt::Display for DistributionCellData {
             self.uniform_reward_amount()
         )?;
         write!(f, ", {}: {}", "deadline", self.deadline())?;
+        write!(f, ", {}: {}", "remaining_claims", self.remaining_claims())?;
+        write!(f, ", {}: {}", "vesting", self.vesting())?;
+        write!(f, ", {}: {}", "referral_budget", self.referral_budget())?;
+        write!(f, ", {}: {}", "entity_root", self.entity_root())?;
+        write!(f, ", {}: {}", "entity_registry", self.entity_registry())?;
+        write!(
+            f,
+            ", {}: {}",
+            "entity_creator_lock_hash",
+            self.entity_creator_lock_hash()
+        )?;
         write!(f, " }}")
     }
 }
@@ -47,43 +64,1033 @@ impl ::core::default::Default for DistributionCellData {
     }
 }
 impl DistributionCellData {
-    const DEFAULT_VALUE: [u8; 144] = [
+    const DEFAULT_VALUE: [u8; 303] = [
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0,
     ];
-    pub const TOTAL_SIZE: usize = 144;
-    pub const FIELD_SIZES: [usize; 6] = [32, 32, 32, 32, 8, 8];
-    pub const FIELD_COUNT: usize = 6;
+    pub const TOTAL_SIZE: usize = 303;
+    pub const FIELD_SIZES: [usize; 13] = [32, 32, 32, 32, 1, 8, 8, 4, 49, 8, 32, 33, 32];
+    pub const FIELD_COUNT: usize = 13;
     pub fn campaign_id(&self) -> Byte32 {
         Byte32::new_unchecked(self.0.slice(0..32))
     }
-    pub fn admin_lock_hash(&self) -> Byte32 {
+    pub fn admin_lock_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(32..64))
+    }
+    pub fn merkle_root(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(64..96))
+    }
+    pub fn proof_script_code_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(96..128))
+    }
+    pub fn proof_script_hash_type(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(128..129))
+    }
+    pub fn uniform_reward_amount(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(129..137))
+    }
+    pub fn deadline(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(137..145))
+    }
+    pub fn remaining_claims(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(145..149))
+    }
+    pub fn vesting(&self) -> VestingConfig {
+        VestingConfig::new_unchecked(self.0.slice(149..198))
+    }
+    pub fn referral_budget(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(198..206))
+    }
+    pub fn entity_root(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(206..238))
+    }
+    pub fn entity_registry(&self) -> ScriptRef {
+        ScriptRef::new_unchecked(self.0.slice(238..271))
+    }
+    pub fn entity_creator_lock_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(271..303))
+    }
+    pub fn as_reader<'r>(&'r self) -> DistributionCellDataReader<'r> {
+        DistributionCellDataReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for DistributionCellData {
+    type Builder = DistributionCellDataBuilder;
+    const NAME: &'static str = "DistributionCellData";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        DistributionCellData(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        DistributionCellDataReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        DistributionCellDataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .campaign_id(self.campaign_id())
+            .admin_lock_hash(self.admin_lock_hash())
+            .merkle_root(self.merkle_root())
+            .proof_script_code_hash(self.proof_script_code_hash())
+            .proof_script_hash_type(self.proof_script_hash_type())
+            .uniform_reward_amount(self.uniform_reward_amount())
+            .deadline(self.deadline())
+            .remaining_claims(self.remaining_claims())
+            .vesting(self.vesting())
+            .referral_budget(self.referral_budget())
+            .entity_root(self.entity_root())
+            .entity_registry(self.entity_registry())
+            .entity_creator_lock_hash(self.entity_creator_lock_hash())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct DistributionCellDataReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for DistributionCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for DistributionCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for DistributionCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "campaign_id", self.campaign_id())?;
+        write!(f, ", {}: {}", "admin_lock_hash", self.admin_lock_hash())?;
+        write!(f, ", {}: {}", "merkle_root", self.merkle_root())?;
+        write!(
+            f,
+            ", {}: {}",
+            "proof_script_code_hash",
+            self.proof_script_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "proof_script_hash_type",
+            self.proof_script_hash_type()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "uniform_reward_amount",
+            self.uniform_reward_amount()
+        )?;
+        write!(f, ", {}: {}", "deadline", self.deadline())?;
+        write!(f, ", {}: {}", "remaining_claims", self.remaining_claims())?;
+        write!(f, ", {}: {}", "vesting", self.vesting())?;
+        write!(f, ", {}: {}", "referral_budget", self.referral_budget())?;
+        write!(f, ", {}: {}", "entity_root", self.entity_root())?;
+        write!(f, ", {}: {}", "entity_registry", self.entity_registry())?;
+        write!(
+            f,
+            ", {}: {}",
+            "entity_creator_lock_hash",
+            self.entity_creator_lock_hash()
+        )?;
+        write!(f, " }}")
+    }
+}
+impl<'r> DistributionCellDataReader<'r> {
+    pub const TOTAL_SIZE: usize = 303;
+    pub const FIELD_SIZES: [usize; 13] = [32, 32, 32, 32, 1, 8, 8, 4, 49, 8, 32, 33, 32];
+    pub const FIELD_COUNT: usize = 13;
+    pub fn campaign_id(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn admin_lock_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[32..64])
+    }
+    pub fn merkle_root(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[64..96])
+    }
+    pub fn proof_script_code_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[96..128])
+    }
+    pub fn proof_script_hash_type(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[128..129])
+    }
+    pub fn uniform_reward_amount(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[129..137])
+    }
+    pub fn deadline(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[137..145])
+    }
+    pub fn remaining_claims(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[145..149])
+    }
+    pub fn vesting(&self) -> VestingConfigReader<'r> {
+        VestingConfigReader::new_unchecked(&self.as_slice()[149..198])
+    }
+    pub fn referral_budget(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[198..206])
+    }
+    pub fn entity_root(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[206..238])
+    }
+    pub fn entity_registry(&self) -> ScriptRefReader<'r> {
+        ScriptRefReader::new_unchecked(&self.as_slice()[238..271])
+    }
+    pub fn entity_creator_lock_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[271..303])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for DistributionCellDataReader<'r> {
+    type Entity = DistributionCellData;
+    const NAME: &'static str = "DistributionCellDataReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        DistributionCellDataReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct DistributionCellDataBuilder {
+    pub(crate) campaign_id: Byte32,
+    pub(crate) admin_lock_hash: Byte32,
+    pub(crate) merkle_root: Byte32,
+    pub(crate) proof_script_code_hash: Byte32,
+    pub(crate) proof_script_hash_type: Byte,
+    pub(crate) uniform_reward_amount: Uint64,
+    pub(crate) deadline: Uint64,
+    pub(crate) remaining_claims: Uint32,
+    pub(crate) vesting: VestingConfig,
+    pub(crate) referral_budget: Uint64,
+    pub(crate) entity_root: Byte32,
+    pub(crate) entity_registry: ScriptRef,
+    pub(crate) entity_creator_lock_hash: Byte32,
+}
+impl DistributionCellDataBuilder {
+    pub const TOTAL_SIZE: usize = 303;
+    pub const FIELD_SIZES: [usize; 13] = [32, 32, 32, 32, 1, 8, 8, 4, 49, 8, 32, 33, 32];
+    pub const FIELD_COUNT: usize = 13;
+    pub fn campaign_id(mut self, v: Byte32) -> Self {
+        self.campaign_id = v;
+        self
+    }
+    pub fn admin_lock_hash(mut self, v: Byte32) -> Self {
+        self.admin_lock_hash = v;
+        self
+    }
+    pub fn merkle_root(mut self, v: Byte32) -> Self {
+        self.merkle_root = v;
+        self
+    }
+    pub fn proof_script_code_hash(mut self, v: Byte32) -> Self {
+        self.proof_script_code_hash = v;
+        self
+    }
+    pub fn proof_script_hash_type(mut self, v: Byte) -> Self {
+        self.proof_script_hash_type = v;
+        self
+    }
+    pub fn uniform_reward_amount(mut self, v: Uint64) -> Self {
+        self.uniform_reward_amount = v;
+        self
+    }
+    pub fn deadline(mut self, v: Uint64) -> Self {
+        self.deadline = v;
+        self
+    }
+    pub fn remaining_claims(mut self, v: Uint32) -> Self {
+        self.remaining_claims = v;
+        self
+    }
+    pub fn vesting(mut self, v: VestingConfig) -> Self {
+        self.vesting = v;
+        self
+    }
+    pub fn referral_budget(mut self, v: Uint64) -> Self {
+        self.referral_budget = v;
+        self
+    }
+    pub fn entity_root(mut self, v: Byte32) -> Self {
+        self.entity_root = v;
+        self
+    }
+    pub fn entity_registry(mut self, v: ScriptRef) -> Self {
+        self.entity_registry = v;
+        self
+    }
+    pub fn entity_creator_lock_hash(mut self, v: Byte32) -> Self {
+        self.entity_creator_lock_hash = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for DistributionCellDataBuilder {
+    type Entity = DistributionCellData;
+    const NAME: &'static str = "DistributionCellDataBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.campaign_id.as_slice())?;
+        writer.write_all(self.admin_lock_hash.as_slice())?;
+        writer.write_all(self.merkle_root.as_slice())?;
+        writer.write_all(self.proof_script_code_hash.as_slice())?;
+        writer.write_all(self.proof_script_hash_type.as_slice())?;
+        writer.write_all(self.uniform_reward_amount.as_slice())?;
+        writer.write_all(self.deadline.as_slice())?;
+        writer.write_all(self.remaining_claims.as_slice())?;
+        writer.write_all(self.vesting.as_slice())?;
+        writer.write_all(self.referral_budget.as_slice())?;
+        writer.write_all(self.entity_root.as_slice())?;
+        writer.write_all(self.entity_registry.as_slice())?;
+        writer.write_all(self.entity_creator_lock_hash.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        DistributionCellData::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct DistributionCellDataV1(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for DistributionCellDataV1 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for DistributionCellDataV1 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for DistributionCellDataV1 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "campaign_id", self.campaign_id())?;
+        write!(f, ", {}: {}", "admin_lock_hash", self.admin_lock_hash())?;
+        write!(f, ", {}: {}", "merkle_root", self.merkle_root())?;
+        write!(
+            f,
+            ", {}: {}",
+            "proof_script_code_hash",
+            self.proof_script_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "uniform_reward_amount",
+            self.uniform_reward_amount()
+        )?;
+        write!(f, ", {}: {}", "deadline", self.deadline())?;
+        write!(f, ", {}: {}", "remaining_claims", self.remaining_claims())?;
+        write!(f, ", {}: {}", "vesting", self.vesting())?;
+        write!(f, ", {}: {}", "referral_budget", self.referral_budget())?;
+        write!(f, ", {}: {}", "entity_root", self.entity_root())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for DistributionCellDataV1 {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        DistributionCellDataV1::new_unchecked(v)
+    }
+}
+impl DistributionCellDataV1 {
+    const DEFAULT_VALUE: [u8; 236] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 236;
+    pub const FIELD_SIZES: [usize; 10] = [32, 32, 32, 32, 8, 8, 4, 48, 8, 32];
+    pub const FIELD_COUNT: usize = 10;
+    pub fn campaign_id(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn admin_lock_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(32..64))
+    }
+    pub fn merkle_root(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(64..96))
+    }
+    pub fn proof_script_code_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(96..128))
+    }
+    pub fn uniform_reward_amount(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(128..136))
+    }
+    pub fn deadline(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(136..144))
+    }
+    pub fn remaining_claims(&self) -> Uint32 {
+        Uint32::new_unchecked(self.0.slice(144..148))
+    }
+    pub fn vesting(&self) -> VestingConfigV1 {
+        VestingConfigV1::new_unchecked(self.0.slice(148..196))
+    }
+    pub fn referral_budget(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(196..204))
+    }
+    pub fn entity_root(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(204..236))
+    }
+    pub fn as_reader<'r>(&'r self) -> DistributionCellDataV1Reader<'r> {
+        DistributionCellDataV1Reader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for DistributionCellDataV1 {
+    type Builder = DistributionCellDataV1Builder;
+    const NAME: &'static str = "DistributionCellDataV1";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        DistributionCellDataV1(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        DistributionCellDataV1Reader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        DistributionCellDataV1Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .campaign_id(self.campaign_id())
+            .admin_lock_hash(self.admin_lock_hash())
+            .merkle_root(self.merkle_root())
+            .proof_script_code_hash(self.proof_script_code_hash())
+            .uniform_reward_amount(self.uniform_reward_amount())
+            .deadline(self.deadline())
+            .remaining_claims(self.remaining_claims())
+            .vesting(self.vesting())
+            .referral_budget(self.referral_budget())
+            .entity_root(self.entity_root())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct DistributionCellDataV1Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for DistributionCellDataV1Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for DistributionCellDataV1Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for DistributionCellDataV1Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "campaign_id", self.campaign_id())?;
+        write!(f, ", {}: {}", "admin_lock_hash", self.admin_lock_hash())?;
+        write!(f, ", {}: {}", "merkle_root", self.merkle_root())?;
+        write!(
+            f,
+            ", {}: {}",
+            "proof_script_code_hash",
+            self.proof_script_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "uniform_reward_amount",
+            self.uniform_reward_amount()
+        )?;
+        write!(f, ", {}: {}", "deadline", self.deadline())?;
+        write!(f, ", {}: {}", "remaining_claims", self.remaining_claims())?;
+        write!(f, ", {}: {}", "vesting", self.vesting())?;
+        write!(f, ", {}: {}", "referral_budget", self.referral_budget())?;
+        write!(f, ", {}: {}", "entity_root", self.entity_root())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> DistributionCellDataV1Reader<'r> {
+    pub const TOTAL_SIZE: usize = 236;
+    pub const FIELD_SIZES: [usize; 10] = [32, 32, 32, 32, 8, 8, 4, 48, 8, 32];
+    pub const FIELD_COUNT: usize = 10;
+    pub fn campaign_id(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn admin_lock_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[32..64])
+    }
+    pub fn merkle_root(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[64..96])
+    }
+    pub fn proof_script_code_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[96..128])
+    }
+    pub fn uniform_reward_amount(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[128..136])
+    }
+    pub fn deadline(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[136..144])
+    }
+    pub fn remaining_claims(&self) -> Uint32Reader<'r> {
+        Uint32Reader::new_unchecked(&self.as_slice()[144..148])
+    }
+    pub fn vesting(&self) -> VestingConfigV1Reader<'r> {
+        VestingConfigV1Reader::new_unchecked(&self.as_slice()[148..196])
+    }
+    pub fn referral_budget(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[196..204])
+    }
+    pub fn entity_root(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[204..236])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for DistributionCellDataV1Reader<'r> {
+    type Entity = DistributionCellDataV1;
+    const NAME: &'static str = "DistributionCellDataV1Reader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        DistributionCellDataV1Reader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct DistributionCellDataV1Builder {
+    pub(crate) campaign_id: Byte32,
+    pub(crate) admin_lock_hash: Byte32,
+    pub(crate) merkle_root: Byte32,
+    pub(crate) proof_script_code_hash: Byte32,
+    pub(crate) uniform_reward_amount: Uint64,
+    pub(crate) deadline: Uint64,
+    pub(crate) remaining_claims: Uint32,
+    pub(crate) vesting: VestingConfigV1,
+    pub(crate) referral_budget: Uint64,
+    pub(crate) entity_root: Byte32,
+}
+impl DistributionCellDataV1Builder {
+    pub const TOTAL_SIZE: usize = 236;
+    pub const FIELD_SIZES: [usize; 10] = [32, 32, 32, 32, 8, 8, 4, 48, 8, 32];
+    pub const FIELD_COUNT: usize = 10;
+    pub fn campaign_id(mut self, v: Byte32) -> Self {
+        self.campaign_id = v;
+        self
+    }
+    pub fn admin_lock_hash(mut self, v: Byte32) -> Self {
+        self.admin_lock_hash = v;
+        self
+    }
+    pub fn merkle_root(mut self, v: Byte32) -> Self {
+        self.merkle_root = v;
+        self
+    }
+    pub fn proof_script_code_hash(mut self, v: Byte32) -> Self {
+        self.proof_script_code_hash = v;
+        self
+    }
+    pub fn uniform_reward_amount(mut self, v: Uint64) -> Self {
+        self.uniform_reward_amount = v;
+        self
+    }
+    pub fn deadline(mut self, v: Uint64) -> Self {
+        self.deadline = v;
+        self
+    }
+    pub fn remaining_claims(mut self, v: Uint32) -> Self {
+        self.remaining_claims = v;
+        self
+    }
+    pub fn vesting(mut self, v: VestingConfigV1) -> Self {
+        self.vesting = v;
+        self
+    }
+    pub fn referral_budget(mut self, v: Uint64) -> Self {
+        self.referral_budget = v;
+        self
+    }
+    pub fn entity_root(mut self, v: Byte32) -> Self {
+        self.entity_root = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for DistributionCellDataV1Builder {
+    type Entity = DistributionCellDataV1;
+    const NAME: &'static str = "DistributionCellDataV1Builder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.campaign_id.as_slice())?;
+        writer.write_all(self.admin_lock_hash.as_slice())?;
+        writer.write_all(self.merkle_root.as_slice())?;
+        writer.write_all(self.proof_script_code_hash.as_slice())?;
+        writer.write_all(self.uniform_reward_amount.as_slice())?;
+        writer.write_all(self.deadline.as_slice())?;
+        writer.write_all(self.remaining_claims.as_slice())?;
+        writer.write_all(self.vesting.as_slice())?;
+        writer.write_all(self.referral_budget.as_slice())?;
+        writer.write_all(self.entity_root.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        DistributionCellDataV1::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Referral(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Referral {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Referral {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Referral {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "referrer_lock_hash", self.referrer_lock_hash())?;
+        write!(f, ", {}: {}", "bonus_amount", self.bonus_amount())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for Referral {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        Referral::new_unchecked(v)
+    }
+}
+impl Referral {
+    const DEFAULT_VALUE: [u8; 40] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 40;
+    pub const FIELD_SIZES: [usize; 2] = [32, 8];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn referrer_lock_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn bonus_amount(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(32..40))
+    }
+    pub fn as_reader<'r>(&'r self) -> ReferralReader<'r> {
+        ReferralReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Referral {
+    type Builder = ReferralBuilder;
+    const NAME: &'static str = "Referral";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Referral(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ReferralReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ReferralReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .referrer_lock_hash(self.referrer_lock_hash())
+            .bonus_amount(self.bonus_amount())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ReferralReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ReferralReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ReferralReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ReferralReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "referrer_lock_hash", self.referrer_lock_hash())?;
+        write!(f, ", {}: {}", "bonus_amount", self.bonus_amount())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> ReferralReader<'r> {
+    pub const TOTAL_SIZE: usize = 40;
+    pub const FIELD_SIZES: [usize; 2] = [32, 8];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn referrer_lock_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn bonus_amount(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[32..40])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ReferralReader<'r> {
+    type Entity = Referral;
+    const NAME: &'static str = "ReferralReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ReferralReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct ReferralBuilder {
+    pub(crate) referrer_lock_hash: Byte32,
+    pub(crate) bonus_amount: Uint64,
+}
+impl ReferralBuilder {
+    pub const TOTAL_SIZE: usize = 40;
+    pub const FIELD_SIZES: [usize; 2] = [32, 8];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn referrer_lock_hash(mut self, v: Byte32) -> Self {
+        self.referrer_lock_hash = v;
+        self
+    }
+    pub fn bonus_amount(mut self, v: Uint64) -> Self {
+        self.bonus_amount = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ReferralBuilder {
+    type Entity = Referral;
+    const NAME: &'static str = "ReferralBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.referrer_lock_hash.as_slice())?;
+        writer.write_all(self.bonus_amount.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Referral::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ReferralOpt(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ReferralOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ReferralOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ReferralOpt {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl ::core::default::Default for ReferralOpt {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        ReferralOpt::new_unchecked(v)
+    }
+}
+impl ReferralOpt {
+    const DEFAULT_VALUE: [u8; 0] = [];
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<Referral> {
+        if self.is_none() {
+            None
+        } else {
+            Some(Referral::new_unchecked(self.0.clone()))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ReferralOptReader<'r> {
+        ReferralOptReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ReferralOpt {
+    type Builder = ReferralOptBuilder;
+    const NAME: &'static str = "ReferralOpt";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ReferralOpt(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ReferralOptReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ReferralOptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().set(self.to_opt())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ReferralOptReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ReferralOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ReferralOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ReferralOptReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
+    }
+}
+impl<'r> ReferralOptReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<ReferralReader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(ReferralReader::new_unchecked(self.as_slice()))
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ReferralOptReader<'r> {
+    type Entity = ReferralOpt;
+    const NAME: &'static str = "ReferralOptReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ReferralOptReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            ReferralReader::verify(&slice[..], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct ReferralOptBuilder(pub(crate) Option<Referral>);
+impl ReferralOptBuilder {
+    pub fn set(mut self, v: Option<Referral>) -> Self {
+        self.0 = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ReferralOptBuilder {
+    type Entity = ReferralOpt;
+    const NAME: &'static str = "ReferralOptBuilder";
+    fn expected_length(&self) -> usize {
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ReferralOpt::new_unchecked(inner.into())
+    }
+}
+impl From<Referral> for ReferralOpt {
+    fn from(value: Referral) -> Self {
+        Self::new_builder().set(Some(value)).build()
+    }
+}
+#[derive(Clone)]
+pub struct PrivateClaim(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for PrivateClaim {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for PrivateClaim {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for PrivateClaim {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "secret", self.secret())?;
+        write!(
+            f,
+            ", {}: {}",
+            "destination_lock_hash",
+            self.destination_lock_hash()
+        )?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for PrivateClaim {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        PrivateClaim::new_unchecked(v)
+    }
+}
+impl PrivateClaim {
+    const DEFAULT_VALUE: [u8; 64] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 64;
+    pub const FIELD_SIZES: [usize; 2] = [32, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn secret(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn destination_lock_hash(&self) -> Byte32 {
         Byte32::new_unchecked(self.0.slice(32..64))
     }
-    pub fn merkle_root(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(64..96))
-    }
-    pub fn proof_script_code_hash(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(96..128))
-    }
-    pub fn uniform_reward_amount(&self) -> Uint64 {
-        Uint64::new_unchecked(self.0.slice(128..136))
-    }
-    pub fn deadline(&self) -> Uint64 {
-        Uint64::new_unchecked(self.0.slice(136..144))
-    }
-    pub fn as_reader<'r>(&'r self) -> DistributionCellDataReader<'r> {
-        DistributionCellDataReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> PrivateClaimReader<'r> {
+        PrivateClaimReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for DistributionCellData {
-    type Builder = DistributionCellDataBuilder;
-    const NAME: &'static str = "DistributionCellData";
+impl molecule::prelude::Entity for PrivateClaim {
+    type Builder = PrivateClaimBuilder;
+    const NAME: &'static str = "PrivateClaim";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        DistributionCellData(data)
+        PrivateClaim(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -92,27 +1099,23 @@ impl molecule::prelude::Entity for DistributionCellData {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        DistributionCellDataReader::from_slice(slice).map(|reader| reader.to_entity())
+        PrivateClaimReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        DistributionCellDataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        PrivateClaimReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .campaign_id(self.campaign_id())
-            .admin_lock_hash(self.admin_lock_hash())
-            .merkle_root(self.merkle_root())
-            .proof_script_code_hash(self.proof_script_code_hash())
-            .uniform_reward_amount(self.uniform_reward_amount())
-            .deadline(self.deadline())
+            .secret(self.secret())
+            .destination_lock_hash(self.destination_lock_hash())
     }
 }
 #[derive(Clone, Copy)]
-pub struct DistributionCellDataReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for DistributionCellDataReader<'r> {
+pub struct PrivateClaimReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for PrivateClaimReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -121,64 +1124,43 @@ impl<'r> ::core::fmt::LowerHex for DistributionCellDataReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for DistributionCellDataReader<'r> {
+impl<'r> ::core::fmt::Debug for PrivateClaimReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for DistributionCellDataReader<'r> {
+impl<'r> ::core::fmt::Display for PrivateClaimReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "campaign_id", self.campaign_id())?;
-        write!(f, ", {}: {}", "admin_lock_hash", self.admin_lock_hash())?;
-        write!(f, ", {}: {}", "merkle_root", self.merkle_root())?;
-        write!(
-            f,
-            ", {}: {}",
-            "proof_script_code_hash",
-            self.proof_script_code_hash()
-        )?;
+        write!(f, "{}: {}", "secret", self.secret())?;
         write!(
             f,
             ", {}: {}",
-            "uniform_reward_amount",
-            self.uniform_reward_amount()
+            "destination_lock_hash",
+            self.destination_lock_hash()
         )?;
-        write!(f, ", {}: {}", "deadline", self.deadline())?;
         write!(f, " }}")
     }
 }
-impl<'r> DistributionCellDataReader<'r> {
-    pub const TOTAL_SIZE: usize = 144;
-    pub const FIELD_SIZES: [usize; 6] = [32, 32, 32, 32, 8, 8];
-    pub const FIELD_COUNT: usize = 6;
-    pub fn campaign_id(&self) -> Byte32Reader<'r> {
+impl<'r> PrivateClaimReader<'r> {
+    pub const TOTAL_SIZE: usize = 64;
+    pub const FIELD_SIZES: [usize; 2] = [32, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn secret(&self) -> Byte32Reader<'r> {
         Byte32Reader::new_unchecked(&self.as_slice()[0..32])
     }
-    pub fn admin_lock_hash(&self) -> Byte32Reader<'r> {
+    pub fn destination_lock_hash(&self) -> Byte32Reader<'r> {
         Byte32Reader::new_unchecked(&self.as_slice()[32..64])
     }
-    pub fn merkle_root(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[64..96])
-    }
-    pub fn proof_script_code_hash(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[96..128])
-    }
-    pub fn uniform_reward_amount(&self) -> Uint64Reader<'r> {
-        Uint64Reader::new_unchecked(&self.as_slice()[128..136])
-    }
-    pub fn deadline(&self) -> Uint64Reader<'r> {
-        Uint64Reader::new_unchecked(&self.as_slice()[136..144])
-    }
 }
-impl<'r> molecule::prelude::Reader<'r> for DistributionCellDataReader<'r> {
-    type Entity = DistributionCellData;
-    const NAME: &'static str = "DistributionCellDataReader";
+impl<'r> molecule::prelude::Reader<'r> for PrivateClaimReader<'r> {
+    type Entity = PrivateClaim;
+    const NAME: &'static str = "PrivateClaimReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        DistributionCellDataReader(slice)
+        PrivateClaimReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -193,68 +1175,44 @@ impl<'r> molecule::prelude::Reader<'r> for DistributionCellDataReader<'r> {
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct DistributionCellDataBuilder {
-    pub(crate) campaign_id: Byte32,
-    pub(crate) admin_lock_hash: Byte32,
-    pub(crate) merkle_root: Byte32,
-    pub(crate) proof_script_code_hash: Byte32,
-    pub(crate) uniform_reward_amount: Uint64,
-    pub(crate) deadline: Uint64,
+pub struct PrivateClaimBuilder {
+    pub(crate) secret: Byte32,
+    pub(crate) destination_lock_hash: Byte32,
 }
-impl DistributionCellDataBuilder {
-    pub const TOTAL_SIZE: usize = 144;
-    pub const FIELD_SIZES: [usize; 6] = [32, 32, 32, 32, 8, 8];
-    pub const FIELD_COUNT: usize = 6;
-    pub fn campaign_id(mut self, v: Byte32) -> Self {
-        self.campaign_id = v;
-        self
-    }
-    pub fn admin_lock_hash(mut self, v: Byte32) -> Self {
-        self.admin_lock_hash = v;
-        self
-    }
-    pub fn merkle_root(mut self, v: Byte32) -> Self {
-        self.merkle_root = v;
-        self
-    }
-    pub fn proof_script_code_hash(mut self, v: Byte32) -> Self {
-        self.proof_script_code_hash = v;
-        self
-    }
-    pub fn uniform_reward_amount(mut self, v: Uint64) -> Self {
-        self.uniform_reward_amount = v;
+impl PrivateClaimBuilder {
+    pub const TOTAL_SIZE: usize = 64;
+    pub const FIELD_SIZES: [usize; 2] = [32, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn secret(mut self, v: Byte32) -> Self {
+        self.secret = v;
         self
     }
-    pub fn deadline(mut self, v: Uint64) -> Self {
-        self.deadline = v;
+    pub fn destination_lock_hash(mut self, v: Byte32) -> Self {
+        self.destination_lock_hash = v;
         self
     }
 }
-impl molecule::prelude::Builder for DistributionCellDataBuilder {
-    type Entity = DistributionCellData;
-    const NAME: &'static str = "DistributionCellDataBuilder";
+impl molecule::prelude::Builder for PrivateClaimBuilder {
+    type Entity = PrivateClaim;
+    const NAME: &'static str = "PrivateClaimBuilder";
     fn expected_length(&self) -> usize {
         Self::TOTAL_SIZE
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.campaign_id.as_slice())?;
-        writer.write_all(self.admin_lock_hash.as_slice())?;
-        writer.write_all(self.merkle_root.as_slice())?;
-        writer.write_all(self.proof_script_code_hash.as_slice())?;
-        writer.write_all(self.uniform_reward_amount.as_slice())?;
-        writer.write_all(self.deadline.as_slice())?;
+        writer.write_all(self.secret.as_slice())?;
+        writer.write_all(self.destination_lock_hash.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        DistributionCellData::new_unchecked(inner.into())
+        PrivateClaim::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
-pub struct OutPoint(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for OutPoint {
+pub struct PrivateClaimOpt(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for PrivateClaimOpt {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -263,48 +1221,50 @@ impl ::core::fmt::LowerHex for OutPoint {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for OutPoint {
+impl ::core::fmt::Debug for PrivateClaimOpt {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for OutPoint {
+impl ::core::fmt::Display for PrivateClaimOpt {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "tx_hash", self.tx_hash())?;
-        write!(f, ", {}: {}", "index", self.index())?;
-        write!(f, " }}")
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
     }
 }
-impl ::core::default::Default for OutPoint {
+impl ::core::default::Default for PrivateClaimOpt {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        OutPoint::new_unchecked(v)
+        PrivateClaimOpt::new_unchecked(v)
     }
 }
-impl OutPoint {
-    const DEFAULT_VALUE: [u8; 36] = [
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0,
-    ];
-    pub const TOTAL_SIZE: usize = 36;
-    pub const FIELD_SIZES: [usize; 2] = [32, 4];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn tx_hash(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(0..32))
+impl PrivateClaimOpt {
+    const DEFAULT_VALUE: [u8; 0] = [];
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
+    }
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
     }
-    pub fn index(&self) -> Uint32 {
-        Uint32::new_unchecked(self.0.slice(32..36))
+    pub fn to_opt(&self) -> Option<PrivateClaim> {
+        if self.is_none() {
+            None
+        } else {
+            Some(PrivateClaim::new_unchecked(self.0.clone()))
+        }
     }
-    pub fn as_reader<'r>(&'r self) -> OutPointReader<'r> {
-        OutPointReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> PrivateClaimOptReader<'r> {
+        PrivateClaimOptReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for OutPoint {
-    type Builder = OutPointBuilder;
-    const NAME: &'static str = "OutPoint";
+impl molecule::prelude::Entity for PrivateClaimOpt {
+    type Builder = PrivateClaimOptBuilder;
+    const NAME: &'static str = "PrivateClaimOpt";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        OutPoint(data)
+        PrivateClaimOpt(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -313,23 +1273,21 @@ impl molecule::prelude::Entity for OutPoint {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        OutPointReader::from_slice(slice).map(|reader| reader.to_entity())
+        PrivateClaimOptReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        OutPointReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        PrivateClaimOptReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder()
-            .tx_hash(self.tx_hash())
-            .index(self.index())
+        Self::new_builder().set(self.to_opt())
     }
 }
 #[derive(Clone, Copy)]
-pub struct OutPointReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for OutPointReader<'r> {
+pub struct PrivateClaimOptReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for PrivateClaimOptReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -338,90 +1296,92 @@ impl<'r> ::core::fmt::LowerHex for OutPointReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for OutPointReader<'r> {
+impl<'r> ::core::fmt::Debug for PrivateClaimOptReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for OutPointReader<'r> {
+impl<'r> ::core::fmt::Display for PrivateClaimOptReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "tx_hash", self.tx_hash())?;
-        write!(f, ", {}: {}", "index", self.index())?;
-        write!(f, " }}")
+        if let Some(v) = self.to_opt() {
+            write!(f, "{}(Some({}))", Self::NAME, v)
+        } else {
+            write!(f, "{}(None)", Self::NAME)
+        }
     }
 }
-impl<'r> OutPointReader<'r> {
-    pub const TOTAL_SIZE: usize = 36;
-    pub const FIELD_SIZES: [usize; 2] = [32, 4];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn tx_hash(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+impl<'r> PrivateClaimOptReader<'r> {
+    pub fn is_none(&self) -> bool {
+        self.0.is_empty()
     }
-    pub fn index(&self) -> Uint32Reader<'r> {
-        Uint32Reader::new_unchecked(&self.as_slice()[32..36])
+    pub fn is_some(&self) -> bool {
+        !self.0.is_empty()
+    }
+    pub fn to_opt(&self) -> Option<PrivateClaimReader<'r>> {
+        if self.is_none() {
+            None
+        } else {
+            Some(PrivateClaimReader::new_unchecked(self.as_slice()))
+        }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for OutPointReader<'r> {
-    type Entity = OutPoint;
-    const NAME: &'static str = "OutPointReader";
+impl<'r> molecule::prelude::Reader<'r> for PrivateClaimOptReader<'r> {
+    type Entity = PrivateClaimOpt;
+    const NAME: &'static str = "PrivateClaimOptReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        OutPointReader(slice)
+        PrivateClaimOptReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
-        use molecule::verification_error as ve;
-        let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        if !slice.is_empty() {
+            PrivateClaimReader::verify(&slice[..], compatible)?;
         }
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct OutPointBuilder {
-    pub(crate) tx_hash: Byte32,
-    pub(crate) index: Uint32,
-}
-impl OutPointBuilder {
-    pub const TOTAL_SIZE: usize = 36;
-    pub const FIELD_SIZES: [usize; 2] = [32, 4];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn tx_hash(mut self, v: Byte32) -> Self {
-        self.tx_hash = v;
-        self
-    }
-    pub fn index(mut self, v: Uint32) -> Self {
-        self.index = v;
+pub struct PrivateClaimOptBuilder(pub(crate) Option<PrivateClaim>);
+impl PrivateClaimOptBuilder {
+    pub fn set(mut self, v: Option<PrivateClaim>) -> Self {
+        self.0 = v;
         self
     }
 }
-impl molecule::prelude::Builder for OutPointBuilder {
-    type Entity = OutPoint;
-    const NAME: &'static str = "OutPointBuilder";
+impl molecule::prelude::Builder for PrivateClaimOptBuilder {
+    type Entity = PrivateClaimOpt;
+    const NAME: &'static str = "PrivateClaimOptBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        self.0
+            .as_ref()
+            .map(|ref inner| inner.as_slice().len())
+            .unwrap_or(0)
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.tx_hash.as_slice())?;
-        writer.write_all(self.index.as_slice())?;
-        Ok(())
+        self.0
+            .as_ref()
+            .map(|ref inner| writer.write_all(inner.as_slice()))
+            .unwrap_or(Ok(()))
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        OutPoint::new_unchecked(inner.into())
+        PrivateClaimOpt::new_unchecked(inner.into())
+    }
+}
+impl From<PrivateClaim> for PrivateClaimOpt {
+    fn from(value: PrivateClaim) -> Self {
+        Self::new_builder().set(Some(value)).build()
     }
 }
 #[derive(Clone)]
-pub struct Byte32Vec(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for Byte32Vec {
+pub struct ProofReveal(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ProofReveal {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -430,66 +1390,49 @@ impl ::core::fmt::LowerHex for Byte32Vec {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for Byte32Vec {
+impl ::core::fmt::Debug for ProofReveal {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for Byte32Vec {
+impl ::core::fmt::Display for ProofReveal {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
-        }
-        write!(f, "]")
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "proof", self.proof())?;
+        write!(f, ", {}: {}", "salt", self.salt())?;
+        write!(f, " }}")
     }
 }
-impl ::core::default::Default for Byte32Vec {
+impl ::core::default::Default for ProofReveal {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        Byte32Vec::new_unchecked(v)
+        ProofReveal::new_unchecked(v)
     }
 }
-impl Byte32Vec {
-    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
-    pub const ITEM_SIZE: usize = 32;
-    pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
-    }
-    pub fn item_count(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn len(&self) -> usize {
-        self.item_count()
-    }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
-    }
-    pub fn get(&self, idx: usize) -> Option<Byte32> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+impl ProofReveal {
+    const DEFAULT_VALUE: [u8; 64] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 64;
+    pub const FIELD_SIZES: [usize; 2] = [32, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn proof(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
     }
-    pub fn get_unchecked(&self, idx: usize) -> Byte32 {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        Byte32::new_unchecked(self.0.slice(start..end))
+    pub fn salt(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(32..64))
     }
-    pub fn as_reader<'r>(&'r self) -> Byte32VecReader<'r> {
-        Byte32VecReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> ProofRevealReader<'r> {
+        ProofRevealReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for Byte32Vec {
-    type Builder = Byte32VecBuilder;
-    const NAME: &'static str = "Byte32Vec";
+impl molecule::prelude::Entity for ProofReveal {
+    type Builder = ProofRevealBuilder;
+    const NAME: &'static str = "ProofReveal";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        Byte32Vec(data)
+        ProofReveal(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -498,21 +1441,21 @@ impl molecule::prelude::Entity for Byte32Vec {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        Byte32VecReader::from_slice(slice).map(|reader| reader.to_entity())
+        ProofRevealReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        Byte32VecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        ProofRevealReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().extend(self.into_iter())
+        Self::new_builder().proof(self.proof()).salt(self.salt())
     }
 }
 #[derive(Clone, Copy)]
-pub struct Byte32VecReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for Byte32VecReader<'r> {
+pub struct ProofRevealReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ProofRevealReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -521,59 +1464,38 @@ impl<'r> ::core::fmt::LowerHex for Byte32VecReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for Byte32VecReader<'r> {
+impl<'r> ::core::fmt::Debug for ProofRevealReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for Byte32VecReader<'r> {
+impl<'r> ::core::fmt::Display for ProofRevealReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-        write!(f, "{} [", Self::NAME)?;
-        for i in 0..self.len() {
-            if i == 0 {
-                write!(f, "{}", self.get_unchecked(i))?;
-            } else {
-                write!(f, ", {}", self.get_unchecked(i))?;
-            }
-        }
-        write!(f, "]")
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "proof", self.proof())?;
+        write!(f, ", {}: {}", "salt", self.salt())?;
+        write!(f, " }}")
     }
 }
-impl<'r> Byte32VecReader<'r> {
-    pub const ITEM_SIZE: usize = 32;
-    pub fn total_size(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
-    }
-    pub fn item_count(&self) -> usize {
-        molecule::unpack_number(self.as_slice()) as usize
-    }
-    pub fn len(&self) -> usize {
-        self.item_count()
-    }
-    pub fn is_empty(&self) -> bool {
-        self.len() == 0
-    }
-    pub fn get(&self, idx: usize) -> Option<Byte32Reader<'r>> {
-        if idx >= self.len() {
-            None
-        } else {
-            Some(self.get_unchecked(idx))
-        }
+impl<'r> ProofRevealReader<'r> {
+    pub const TOTAL_SIZE: usize = 64;
+    pub const FIELD_SIZES: [usize; 2] = [32, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn proof(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
     }
-    pub fn get_unchecked(&self, idx: usize) -> Byte32Reader<'r> {
-        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
-        let end = start + Self::ITEM_SIZE;
-        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    pub fn salt(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[32..64])
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for Byte32VecReader<'r> {
-    type Entity = Byte32Vec;
-    const NAME: &'static str = "Byte32VecReader";
+impl<'r> molecule::prelude::Reader<'r> for ProofRevealReader<'r> {
+    type Entity = ProofReveal;
+    const NAME: &'static str = "ProofRevealReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        Byte32VecReader(slice)
+        ProofRevealReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -581,119 +1503,46 @@ impl<'r> molecule::prelude::Reader<'r> for Byte32VecReader<'r> {
     fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len < molecule::NUMBER_SIZE {
-            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
-        }
-        let item_count = molecule::unpack_number(slice) as usize;
-        if item_count == 0 {
-            if slice_len != molecule::NUMBER_SIZE {
-                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
-            }
-            return Ok(());
-        }
-        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
-        if slice_len != total_size {
-            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
         }
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct Byte32VecBuilder(pub(crate) Vec<Byte32>);
-impl Byte32VecBuilder {
-    pub const ITEM_SIZE: usize = 32;
-    pub fn set(mut self, v: Vec<Byte32>) -> Self {
-        self.0 = v;
-        self
-    }
-    pub fn push(mut self, v: Byte32) -> Self {
-        self.0.push(v);
+pub struct ProofRevealBuilder {
+    pub(crate) proof: Byte32,
+    pub(crate) salt: Byte32,
+}
+impl ProofRevealBuilder {
+    pub const TOTAL_SIZE: usize = 64;
+    pub const FIELD_SIZES: [usize; 2] = [32, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn proof(mut self, v: Byte32) -> Self {
+        self.proof = v;
         self
     }
-    pub fn extend<T: ::core::iter::IntoIterator<Item = Byte32>>(mut self, iter: T) -> Self {
-        for elem in iter {
-            self.0.push(elem);
-        }
+    pub fn salt(mut self, v: Byte32) -> Self {
+        self.salt = v;
         self
     }
-    pub fn replace(&mut self, index: usize, v: Byte32) -> Option<Byte32> {
-        self.0
-            .get_mut(index)
-            .map(|item| ::core::mem::replace(item, v))
-    }
 }
-impl molecule::prelude::Builder for Byte32VecBuilder {
-    type Entity = Byte32Vec;
-    const NAME: &'static str = "Byte32VecBuilder";
+impl molecule::prelude::Builder for ProofRevealBuilder {
+    type Entity = ProofReveal;
+    const NAME: &'static str = "ProofRevealBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+        Self::TOTAL_SIZE
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
-        for inner in &self.0[..] {
-            writer.write_all(inner.as_slice())?;
-        }
+        writer.write_all(self.proof.as_slice())?;
+        writer.write_all(self.salt.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        Byte32Vec::new_unchecked(inner.into())
-    }
-}
-pub struct Byte32VecIterator(Byte32Vec, usize, usize);
-impl ::core::iter::Iterator for Byte32VecIterator {
-    type Item = Byte32;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl ::core::iter::ExactSizeIterator for Byte32VecIterator {
-    fn len(&self) -> usize {
-        self.2 - self.1
-    }
-}
-impl ::core::iter::IntoIterator for Byte32Vec {
-    type Item = Byte32;
-    type IntoIter = Byte32VecIterator;
-    fn into_iter(self) -> Self::IntoIter {
-        let len = self.len();
-        Byte32VecIterator(self, 0, len)
-    }
-}
-impl<'r> Byte32VecReader<'r> {
-    pub fn iter<'t>(&'t self) -> Byte32VecReaderIterator<'t, 'r> {
-        Byte32VecReaderIterator(&self, 0, self.len())
-    }
-}
-pub struct Byte32VecReaderIterator<'t, 'r>(&'t Byte32VecReader<'r>, usize, usize);
-impl<'t: 'r, 'r> ::core::iter::Iterator for Byte32VecReaderIterator<'t, 'r> {
-    type Item = Byte32Reader<'t>;
-    fn next(&mut self) -> Option<Self::Item> {
-        if self.1 >= self.2 {
-            None
-        } else {
-            let ret = self.0.get_unchecked(self.1);
-            self.1 += 1;
-            Some(ret)
-        }
-    }
-}
-impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for Byte32VecReaderIterator<'t, 'r> {
-    fn len(&self) -> usize {
-        self.2 - self.1
-    }
-}
-impl ::core::iter::FromIterator<Byte32> for Byte32Vec {
-    fn from_iter<T: IntoIterator<Item = Byte32>>(iter: T) -> Self {
-        Self::new_builder().extend(iter).build()
+        ProofReveal::new_unchecked(inner.into())
     }
 }
 #[derive(Clone)]
@@ -722,12 +1571,11 @@ impl ::core::fmt::Display for ClaimWitness {
             "subscriber_lock_hash",
             self.subscriber_lock_hash()
         )?;
-        write!(
-            f,
-            ", {}: {}",
-            "proof_cell_out_point",
-            self.proof_cell_out_point()
-        )?;
+        write!(f, ", {}: {}", "proof_type_id", self.proof_type_id())?;
+        write!(f, ", {}: {}", "referral", self.referral())?;
+        write!(f, ", {}: {}", "private_claim", self.private_claim())?;
+        write!(f, ", {}: {}", "proof_reveal", self.proof_reveal())?;
+        write!(f, ", {}: {}", "entity_proof", self.entity_proof())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -742,12 +1590,15 @@ impl ::core::default::Default for ClaimWitness {
     }
 }
 impl ClaimWitness {
-    const DEFAULT_VALUE: [u8; 88] = [
-        88, 0, 0, 0, 16, 0, 0, 0, 20, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    const DEFAULT_VALUE: [u8; 168] = [
+        168, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 68, 0, 0, 0, 100, 0, 0, 0, 100, 0, 0, 0, 100, 0, 0,
+        0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 7;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -776,14 +1627,38 @@ impl ClaimWitness {
         let end = molecule::unpack_number(&slice[12..]) as usize;
         Byte32::new_unchecked(self.0.slice(start..end))
     }
-    pub fn proof_cell_out_point(&self) -> OutPoint {
+    pub fn proof_type_id(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn referral(&self) -> ReferralOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        ReferralOpt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn private_claim(&self) -> PrivateClaimOpt {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        PrivateClaimOpt::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn proof_reveal(&self) -> ProofReveal {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        ProofReveal::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn entity_proof(&self) -> Byte32Vec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            OutPoint::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[32..]) as usize;
+            Byte32Vec::new_unchecked(self.0.slice(start..end))
         } else {
-            OutPoint::new_unchecked(self.0.slice(start..))
+            Byte32Vec::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> ClaimWitnessReader<'r> {
@@ -815,7 +1690,11 @@ impl molecule::prelude::Entity for ClaimWitness {
         Self::new_builder()
             .merkle_proof(self.merkle_proof())
             .subscriber_lock_hash(self.subscriber_lock_hash())
-            .proof_cell_out_point(self.proof_cell_out_point())
+            .proof_type_id(self.proof_type_id())
+            .referral(self.referral())
+            .private_claim(self.private_claim())
+            .proof_reveal(self.proof_reveal())
+            .entity_proof(self.entity_proof())
     }
 }
 #[derive(Clone, Copy)]
@@ -844,12 +1723,11 @@ impl<'r> ::core::fmt::Display for ClaimWitnessReader<'r> {
             "subscriber_lock_hash",
             self.subscriber_lock_hash()
         )?;
-        write!(
-            f,
-            ", {}: {}",
-            "proof_cell_out_point",
-            self.proof_cell_out_point()
-        )?;
+        write!(f, ", {}: {}", "proof_type_id", self.proof_type_id())?;
+        write!(f, ", {}: {}", "referral", self.referral())?;
+        write!(f, ", {}: {}", "private_claim", self.private_claim())?;
+        write!(f, ", {}: {}", "proof_reveal", self.proof_reveal())?;
+        write!(f, ", {}: {}", "entity_proof", self.entity_proof())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -858,7 +1736,7 @@ impl<'r> ::core::fmt::Display for ClaimWitnessReader<'r> {
     }
 }
 impl<'r> ClaimWitnessReader<'r> {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 7;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -887,14 +1765,38 @@ impl<'r> ClaimWitnessReader<'r> {
         let end = molecule::unpack_number(&slice[12..]) as usize;
         Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
-    pub fn proof_cell_out_point(&self) -> OutPointReader<'r> {
+    pub fn proof_type_id(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn referral(&self) -> ReferralOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        ReferralOptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn private_claim(&self) -> PrivateClaimOptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        PrivateClaimOptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn proof_reveal(&self) -> ProofRevealReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        ProofRevealReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn entity_proof(&self) -> Byte32VecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[16..]) as usize;
-            OutPointReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[32..]) as usize;
+            Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            OutPointReader::new_unchecked(&self.as_slice()[start..])
+            Byte32VecReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -946,7 +1848,11 @@ impl<'r> molecule::prelude::Reader<'r> for ClaimWitnessReader<'r> {
         }
         Byte32VecReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
         Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
-        OutPointReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        ReferralOptReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        PrivateClaimOptReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        ProofRevealReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        Byte32VecReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
         Ok(())
     }
 }
@@ -954,10 +1860,14 @@ impl<'r> molecule::prelude::Reader<'r> for ClaimWitnessReader<'r> {
 pub struct ClaimWitnessBuilder {
     pub(crate) merkle_proof: Byte32Vec,
     pub(crate) subscriber_lock_hash: Byte32,
-    pub(crate) proof_cell_out_point: OutPoint,
+    pub(crate) proof_type_id: Byte32,
+    pub(crate) referral: ReferralOpt,
+    pub(crate) private_claim: PrivateClaimOpt,
+    pub(crate) proof_reveal: ProofReveal,
+    pub(crate) entity_proof: Byte32Vec,
 }
 impl ClaimWitnessBuilder {
-    pub const FIELD_COUNT: usize = 3;
+    pub const FIELD_COUNT: usize = 7;
     pub fn merkle_proof(mut self, v: Byte32Vec) -> Self {
         self.merkle_proof = v;
         self
@@ -966,8 +1876,24 @@ impl ClaimWitnessBuilder {
         self.subscriber_lock_hash = v;
         self
     }
-    pub fn proof_cell_out_point(mut self, v: OutPoint) -> Self {
-        self.proof_cell_out_point = v;
+    pub fn proof_type_id(mut self, v: Byte32) -> Self {
+        self.proof_type_id = v;
+        self
+    }
+    pub fn referral(mut self, v: ReferralOpt) -> Self {
+        self.referral = v;
+        self
+    }
+    pub fn private_claim(mut self, v: PrivateClaimOpt) -> Self {
+        self.private_claim = v;
+        self
+    }
+    pub fn proof_reveal(mut self, v: ProofReveal) -> Self {
+        self.proof_reveal = v;
+        self
+    }
+    pub fn entity_proof(mut self, v: Byte32Vec) -> Self {
+        self.entity_proof = v;
         self
     }
 }
@@ -978,7 +1904,11 @@ impl molecule::prelude::Builder for ClaimWitnessBuilder {
         molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
             + self.merkle_proof.as_slice().len()
             + self.subscriber_lock_hash.as_slice().len()
-            + self.proof_cell_out_point.as_slice().len()
+            + self.proof_type_id.as_slice().len()
+            + self.referral.as_slice().len()
+            + self.private_claim.as_slice().len()
+            + self.proof_reveal.as_slice().len()
+            + self.entity_proof.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -988,14 +1918,26 @@ impl molecule::prelude::Builder for ClaimWitnessBuilder {
         offsets.push(total_size);
         total_size += self.subscriber_lock_hash.as_slice().len();
         offsets.push(total_size);
-        total_size += self.proof_cell_out_point.as_slice().len();
+        total_size += self.proof_type_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.referral.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.private_claim.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.proof_reveal.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.entity_proof.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
         writer.write_all(self.merkle_proof.as_slice())?;
         writer.write_all(self.subscriber_lock_hash.as_slice())?;
-        writer.write_all(self.proof_cell_out_point.as_slice())?;
+        writer.write_all(self.proof_type_id.as_slice())?;
+        writer.write_all(self.referral.as_slice())?;
+        writer.write_all(self.private_claim.as_slice())?;
+        writer.write_all(self.proof_reveal.as_slice())?;
+        writer.write_all(self.entity_proof.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -0,0 +1,188 @@
+// Generated by Molecule 0.8.0
+
+use super::base::*;
+use molecule::prelude::*;
+#[derive(Clone)]
+pub struct EntityCellData(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for EntityCellData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for EntityCellData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for EntityCellData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "creator_lock_hash", self.creator_lock_hash())?;
+        write!(f, ", {}: {}", "entity_id", self.entity_id())?;
+        write!(f, ", {}: {}", "metadata_hash", self.metadata_hash())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for EntityCellData {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        EntityCellData::new_unchecked(v)
+    }
+}
+impl EntityCellData {
+    const DEFAULT_VALUE: [u8; 96] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 96;
+    pub const FIELD_SIZES: [usize; 3] = [32, 32, 32];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn creator_lock_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn entity_id(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(32..64))
+    }
+    pub fn metadata_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(64..96))
+    }
+    pub fn as_reader<'r>(&'r self) -> EntityCellDataReader<'r> {
+        EntityCellDataReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for EntityCellData {
+    type Builder = EntityCellDataBuilder;
+    const NAME: &'static str = "EntityCellData";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        EntityCellData(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        EntityCellDataReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        EntityCellDataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .creator_lock_hash(self.creator_lock_hash())
+            .entity_id(self.entity_id())
+            .metadata_hash(self.metadata_hash())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct EntityCellDataReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for EntityCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for EntityCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for EntityCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "creator_lock_hash", self.creator_lock_hash())?;
+        write!(f, ", {}: {}", "entity_id", self.entity_id())?;
+        write!(f, ", {}: {}", "metadata_hash", self.metadata_hash())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> EntityCellDataReader<'r> {
+    pub const TOTAL_SIZE: usize = 96;
+    pub const FIELD_SIZES: [usize; 3] = [32, 32, 32];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn creator_lock_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn entity_id(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[32..64])
+    }
+    pub fn metadata_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[64..96])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for EntityCellDataReader<'r> {
+    type Entity = EntityCellData;
+    const NAME: &'static str = "EntityCellDataReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        EntityCellDataReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct EntityCellDataBuilder {
+    pub(crate) creator_lock_hash: Byte32,
+    pub(crate) entity_id: Byte32,
+    pub(crate) metadata_hash: Byte32,
+}
+impl EntityCellDataBuilder {
+    pub const TOTAL_SIZE: usize = 96;
+    pub const FIELD_SIZES: [usize; 3] = [32, 32, 32];
+    pub const FIELD_COUNT: usize = 3;
+    pub fn creator_lock_hash(mut self, v: Byte32) -> Self {
+        self.creator_lock_hash = v;
+        self
+    }
+    pub fn entity_id(mut self, v: Byte32) -> Self {
+        self.entity_id = v;
+        self
+    }
+    pub fn metadata_hash(mut self, v: Byte32) -> Self {
+        self.metadata_hash = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for EntityCellDataBuilder {
+    type Entity = EntityCellData;
+    const NAME: &'static str = "EntityCellDataBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.creator_lock_hash.as_slice())?;
+        writer.write_all(self.entity_id.as_slice())?;
+        writer.write_all(self.metadata_hash.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        EntityCellData::new_unchecked(inner.into())
+    }
+}
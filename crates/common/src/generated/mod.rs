@@ -3,6 +3,9 @@
 #![allow(warnings)]
 
 pub mod base;
+pub mod bundle;
 pub mod distribution;
+pub mod entity;
 pub mod proof;
 pub mod vault;
+pub mod vesting;
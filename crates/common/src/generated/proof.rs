@@ -22,14 +22,20 @@ impl ::core::fmt::Display for ProofCellData {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "entity_id", self.entity_id())?;
-        write!(f, ", {}: {}", "campaign_id", self.campaign_id())?;
-        write!(f, ", {}: {}", "proof", self.proof())?;
+        write!(f, ", {}: {}", "campaign_ids", self.campaign_ids())?;
+        write!(f, ", {}: {}", "proof_commit", self.proof_commit())?;
         write!(
             f,
             ", {}: {}",
             "subscriber_lock_hash",
             self.subscriber_lock_hash()
         )?;
+        write!(f, ", {}: {}", "claimed_campaigns", self.claimed_campaigns())?;
+        write!(f, ", {}: {}", "expiry", self.expiry())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
         write!(f, " }}")
     }
 }
@@ -40,27 +46,69 @@ impl ::core::default::Default for ProofCellData {
     }
 }
 impl ProofCellData {
-    const DEFAULT_VALUE: [u8; 128] = [
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    const DEFAULT_VALUE: [u8; 140] = [
+        140, 0, 0, 0, 28, 0, 0, 0, 60, 0, 0, 0, 64, 0, 0, 0, 96, 0, 0, 0, 128, 0, 0, 0, 132, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
-    pub const TOTAL_SIZE: usize = 128;
-    pub const FIELD_SIZES: [usize; 4] = [32, 32, 32, 32];
-    pub const FIELD_COUNT: usize = 4;
-    pub fn entity_id(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(0..32))
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn campaign_id(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(32..64))
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
     }
-    pub fn proof(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(64..96))
+    pub fn entity_id(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn campaign_ids(&self) -> Byte32Vec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32Vec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn proof_commit(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
     }
     pub fn subscriber_lock_hash(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(96..128))
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn claimed_campaigns(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn expiry(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            Uint64::new_unchecked(self.0.slice(start..end))
+        } else {
+            Uint64::new_unchecked(self.0.slice(start..))
+        }
     }
     pub fn as_reader<'r>(&'r self) -> ProofCellDataReader<'r> {
         ProofCellDataReader::new_unchecked(self.as_slice())
@@ -90,9 +138,11 @@ impl molecule::prelude::Entity for ProofCellData {
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
             .entity_id(self.entity_id())
-            .campaign_id(self.campaign_id())
-            .proof(self.proof())
+            .campaign_ids(self.campaign_ids())
+            .proof_commit(self.proof_commit())
             .subscriber_lock_hash(self.subscriber_lock_hash())
+            .claimed_campaigns(self.claimed_campaigns())
+            .expiry(self.expiry())
     }
 }
 #[derive(Clone, Copy)]
@@ -115,32 +165,80 @@ impl<'r> ::core::fmt::Display for ProofCellDataReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
         write!(f, "{}: {}", "entity_id", self.entity_id())?;
-        write!(f, ", {}: {}", "campaign_id", self.campaign_id())?;
-        write!(f, ", {}: {}", "proof", self.proof())?;
+        write!(f, ", {}: {}", "campaign_ids", self.campaign_ids())?;
+        write!(f, ", {}: {}", "proof_commit", self.proof_commit())?;
         write!(
             f,
             ", {}: {}",
             "subscriber_lock_hash",
             self.subscriber_lock_hash()
         )?;
+        write!(f, ", {}: {}", "claimed_campaigns", self.claimed_campaigns())?;
+        write!(f, ", {}: {}", "expiry", self.expiry())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
         write!(f, " }}")
     }
 }
 impl<'r> ProofCellDataReader<'r> {
-    pub const TOTAL_SIZE: usize = 128;
-    pub const FIELD_SIZES: [usize; 4] = [32, 32, 32, 32];
-    pub const FIELD_COUNT: usize = 4;
-    pub fn entity_id(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    pub const FIELD_COUNT: usize = 6;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn campaign_id(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[32..64])
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
     }
-    pub fn proof(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[64..96])
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn entity_id(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn campaign_ids(&self) -> Byte32VecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn proof_commit(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
     }
     pub fn subscriber_lock_hash(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[96..128])
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn claimed_campaigns(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn expiry(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[28..]) as usize;
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
+        }
     }
 }
 impl<'r> molecule::prelude::Reader<'r> for ProofCellDataReader<'r> {
@@ -155,54 +253,122 @@ impl<'r> molecule::prelude::Reader<'r> for ProofCellDataReader<'r> {
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
         }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32VecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        BytesReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
         Ok(())
     }
 }
 #[derive(Clone, Debug, Default)]
 pub struct ProofCellDataBuilder {
     pub(crate) entity_id: Byte32,
-    pub(crate) campaign_id: Byte32,
-    pub(crate) proof: Byte32,
+    pub(crate) campaign_ids: Byte32Vec,
+    pub(crate) proof_commit: Byte32,
     pub(crate) subscriber_lock_hash: Byte32,
+    pub(crate) claimed_campaigns: Bytes,
+    pub(crate) expiry: Uint64,
 }
 impl ProofCellDataBuilder {
-    pub const TOTAL_SIZE: usize = 128;
-    pub const FIELD_SIZES: [usize; 4] = [32, 32, 32, 32];
-    pub const FIELD_COUNT: usize = 4;
+    pub const FIELD_COUNT: usize = 6;
     pub fn entity_id(mut self, v: Byte32) -> Self {
         self.entity_id = v;
         self
     }
-    pub fn campaign_id(mut self, v: Byte32) -> Self {
-        self.campaign_id = v;
+    pub fn campaign_ids(mut self, v: Byte32Vec) -> Self {
+        self.campaign_ids = v;
         self
     }
-    pub fn proof(mut self, v: Byte32) -> Self {
-        self.proof = v;
+    pub fn proof_commit(mut self, v: Byte32) -> Self {
+        self.proof_commit = v;
         self
     }
     pub fn subscriber_lock_hash(mut self, v: Byte32) -> Self {
         self.subscriber_lock_hash = v;
         self
     }
+    pub fn claimed_campaigns(mut self, v: Bytes) -> Self {
+        self.claimed_campaigns = v;
+        self
+    }
+    pub fn expiry(mut self, v: Uint64) -> Self {
+        self.expiry = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for ProofCellDataBuilder {
     type Entity = ProofCellData;
     const NAME: &'static str = "ProofCellDataBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.entity_id.as_slice().len()
+            + self.campaign_ids.as_slice().len()
+            + self.proof_commit.as_slice().len()
+            + self.subscriber_lock_hash.as_slice().len()
+            + self.claimed_campaigns.as_slice().len()
+            + self.expiry.as_slice().len()
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.entity_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.campaign_ids.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.proof_commit.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.subscriber_lock_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.claimed_campaigns.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.expiry.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
         writer.write_all(self.entity_id.as_slice())?;
-        writer.write_all(self.campaign_id.as_slice())?;
-        writer.write_all(self.proof.as_slice())?;
+        writer.write_all(self.campaign_ids.as_slice())?;
+        writer.write_all(self.proof_commit.as_slice())?;
         writer.write_all(self.subscriber_lock_hash.as_slice())?;
+        writer.write_all(self.claimed_campaigns.as_slice())?;
+        writer.write_all(self.expiry.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -212,3 +378,203 @@ impl molecule::prelude::Builder for ProofCellDataBuilder {
         ProofCellData::new_unchecked(inner.into())
     }
 }
+#[derive(Clone)]
+pub struct ProofTypeArgs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ProofTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ProofTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ProofTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "type_id", self.type_id())?;
+        write!(f, ", {}: {}", "entity_registry", self.entity_registry())?;
+        write!(f, ", {}: {}", "distribution_type", self.distribution_type())?;
+        write!(f, ", {}: {}", "proof_lock", self.proof_lock())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ProofTypeArgs {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        ProofTypeArgs::new_unchecked(v)
+    }
+}
+impl ProofTypeArgs {
+    const DEFAULT_VALUE: [u8; 131] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 131;
+    pub const FIELD_SIZES: [usize; 4] = [32, 33, 33, 33];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn type_id(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn entity_registry(&self) -> ScriptRef {
+        ScriptRef::new_unchecked(self.0.slice(32..65))
+    }
+    pub fn distribution_type(&self) -> ScriptRef {
+        ScriptRef::new_unchecked(self.0.slice(65..98))
+    }
+    pub fn proof_lock(&self) -> ScriptRef {
+        ScriptRef::new_unchecked(self.0.slice(98..131))
+    }
+    pub fn as_reader<'r>(&'r self) -> ProofTypeArgsReader<'r> {
+        ProofTypeArgsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ProofTypeArgs {
+    type Builder = ProofTypeArgsBuilder;
+    const NAME: &'static str = "ProofTypeArgs";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ProofTypeArgs(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ProofTypeArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ProofTypeArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .type_id(self.type_id())
+            .entity_registry(self.entity_registry())
+            .distribution_type(self.distribution_type())
+            .proof_lock(self.proof_lock())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ProofTypeArgsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ProofTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ProofTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ProofTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "type_id", self.type_id())?;
+        write!(f, ", {}: {}", "entity_registry", self.entity_registry())?;
+        write!(f, ", {}: {}", "distribution_type", self.distribution_type())?;
+        write!(f, ", {}: {}", "proof_lock", self.proof_lock())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> ProofTypeArgsReader<'r> {
+    pub const TOTAL_SIZE: usize = 131;
+    pub const FIELD_SIZES: [usize; 4] = [32, 33, 33, 33];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn type_id(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn entity_registry(&self) -> ScriptRefReader<'r> {
+        ScriptRefReader::new_unchecked(&self.as_slice()[32..65])
+    }
+    pub fn distribution_type(&self) -> ScriptRefReader<'r> {
+        ScriptRefReader::new_unchecked(&self.as_slice()[65..98])
+    }
+    pub fn proof_lock(&self) -> ScriptRefReader<'r> {
+        ScriptRefReader::new_unchecked(&self.as_slice()[98..131])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ProofTypeArgsReader<'r> {
+    type Entity = ProofTypeArgs;
+    const NAME: &'static str = "ProofTypeArgsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ProofTypeArgsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct ProofTypeArgsBuilder {
+    pub(crate) type_id: Byte32,
+    pub(crate) entity_registry: ScriptRef,
+    pub(crate) distribution_type: ScriptRef,
+    pub(crate) proof_lock: ScriptRef,
+}
+impl ProofTypeArgsBuilder {
+    pub const TOTAL_SIZE: usize = 131;
+    pub const FIELD_SIZES: [usize; 4] = [32, 33, 33, 33];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn type_id(mut self, v: Byte32) -> Self {
+        self.type_id = v;
+        self
+    }
+    pub fn entity_registry(mut self, v: ScriptRef) -> Self {
+        self.entity_registry = v;
+        self
+    }
+    pub fn distribution_type(mut self, v: ScriptRef) -> Self {
+        self.distribution_type = v;
+        self
+    }
+    pub fn proof_lock(mut self, v: ScriptRef) -> Self {
+        self.proof_lock = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ProofTypeArgsBuilder {
+    type Entity = ProofTypeArgs;
+    const NAME: &'static str = "ProofTypeArgsBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.type_id.as_slice())?;
+        writer.write_all(self.entity_registry.as_slice())?;
+        writer.write_all(self.distribution_type.as_slice())?;
+        writer.write_all(self.proof_lock.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ProofTypeArgs::new_unchecked(inner.into())
+    }
+}
@@ -3,6 +3,1090 @@
 use super::base::*;
 use molecule::prelude::*;
 #[derive(Clone)]
+pub struct Funder(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Funder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Funder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Funder {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "lock_hash", self.lock_hash())?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for Funder {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        Funder::new_unchecked(v)
+    }
+}
+impl Funder {
+    const DEFAULT_VALUE: [u8; 40] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 40;
+    pub const FIELD_SIZES: [usize; 2] = [32, 8];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn lock_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn amount(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(32..40))
+    }
+    pub fn as_reader<'r>(&'r self) -> FunderReader<'r> {
+        FunderReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Funder {
+    type Builder = FunderBuilder;
+    const NAME: &'static str = "Funder";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Funder(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FunderReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FunderReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .lock_hash(self.lock_hash())
+            .amount(self.amount())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct FunderReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for FunderReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for FunderReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for FunderReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "lock_hash", self.lock_hash())?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> FunderReader<'r> {
+    pub const TOTAL_SIZE: usize = 40;
+    pub const FIELD_SIZES: [usize; 2] = [32, 8];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn lock_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn amount(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[32..40])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for FunderReader<'r> {
+    type Entity = Funder;
+    const NAME: &'static str = "FunderReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        FunderReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct FunderBuilder {
+    pub(crate) lock_hash: Byte32,
+    pub(crate) amount: Uint64,
+}
+impl FunderBuilder {
+    pub const TOTAL_SIZE: usize = 40;
+    pub const FIELD_SIZES: [usize; 2] = [32, 8];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn lock_hash(mut self, v: Byte32) -> Self {
+        self.lock_hash = v;
+        self
+    }
+    pub fn amount(mut self, v: Uint64) -> Self {
+        self.amount = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for FunderBuilder {
+    type Entity = Funder;
+    const NAME: &'static str = "FunderBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.lock_hash.as_slice())?;
+        writer.write_all(self.amount.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Funder::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct FunderVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for FunderVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for FunderVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for FunderVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for FunderVec {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        FunderVec::new_unchecked(v)
+    }
+}
+impl FunderVec {
+    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
+    pub const ITEM_SIZE: usize = 40;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<Funder> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> Funder {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        Funder::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn as_reader<'r>(&'r self) -> FunderVecReader<'r> {
+        FunderVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for FunderVec {
+    type Builder = FunderVecBuilder;
+    const NAME: &'static str = "FunderVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        FunderVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FunderVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FunderVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct FunderVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for FunderVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for FunderVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for FunderVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> FunderVecReader<'r> {
+    pub const ITEM_SIZE: usize = 40;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<FunderReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> FunderReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        FunderReader::new_unchecked(&self.as_slice()[start..end])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for FunderVecReader<'r> {
+    type Entity = FunderVec;
+    const NAME: &'static str = "FunderVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        FunderVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct FunderVecBuilder(pub(crate) Vec<Funder>);
+impl FunderVecBuilder {
+    pub const ITEM_SIZE: usize = 40;
+    pub fn set(mut self, v: Vec<Funder>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: Funder) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = Funder>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: Funder) -> Option<Funder> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for FunderVecBuilder {
+    type Entity = FunderVec;
+    const NAME: &'static str = "FunderVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        FunderVec::new_unchecked(inner.into())
+    }
+}
+pub struct FunderVecIterator(FunderVec, usize, usize);
+impl ::core::iter::Iterator for FunderVecIterator {
+    type Item = Funder;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for FunderVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for FunderVec {
+    type Item = Funder;
+    type IntoIter = FunderVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        FunderVecIterator(self, 0, len)
+    }
+}
+impl<'r> FunderVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> FunderVecReaderIterator<'t, 'r> {
+        FunderVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct FunderVecReaderIterator<'t, 'r>(&'t FunderVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for FunderVecReaderIterator<'t, 'r> {
+    type Item = FunderReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for FunderVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::FromIterator<Funder> for FunderVec {
+    fn from_iter<T: IntoIterator<Item = Funder>>(iter: T) -> Self {
+        Self::new_builder().extend(iter).build()
+    }
+}
+#[derive(Clone)]
+pub struct FeeBeneficiary(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for FeeBeneficiary {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for FeeBeneficiary {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for FeeBeneficiary {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "lock_hash", self.lock_hash())?;
+        write!(f, ", {}: {}", "basis_points", self.basis_points())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for FeeBeneficiary {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        FeeBeneficiary::new_unchecked(v)
+    }
+}
+impl FeeBeneficiary {
+    const DEFAULT_VALUE: [u8; 34] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 34;
+    pub const FIELD_SIZES: [usize; 2] = [32, 2];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn lock_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn basis_points(&self) -> Uint16 {
+        Uint16::new_unchecked(self.0.slice(32..34))
+    }
+    pub fn as_reader<'r>(&'r self) -> FeeBeneficiaryReader<'r> {
+        FeeBeneficiaryReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for FeeBeneficiary {
+    type Builder = FeeBeneficiaryBuilder;
+    const NAME: &'static str = "FeeBeneficiary";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        FeeBeneficiary(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FeeBeneficiaryReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FeeBeneficiaryReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .lock_hash(self.lock_hash())
+            .basis_points(self.basis_points())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct FeeBeneficiaryReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for FeeBeneficiaryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for FeeBeneficiaryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for FeeBeneficiaryReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "lock_hash", self.lock_hash())?;
+        write!(f, ", {}: {}", "basis_points", self.basis_points())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> FeeBeneficiaryReader<'r> {
+    pub const TOTAL_SIZE: usize = 34;
+    pub const FIELD_SIZES: [usize; 2] = [32, 2];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn lock_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn basis_points(&self) -> Uint16Reader<'r> {
+        Uint16Reader::new_unchecked(&self.as_slice()[32..34])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for FeeBeneficiaryReader<'r> {
+    type Entity = FeeBeneficiary;
+    const NAME: &'static str = "FeeBeneficiaryReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        FeeBeneficiaryReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct FeeBeneficiaryBuilder {
+    pub(crate) lock_hash: Byte32,
+    pub(crate) basis_points: Uint16,
+}
+impl FeeBeneficiaryBuilder {
+    pub const TOTAL_SIZE: usize = 34;
+    pub const FIELD_SIZES: [usize; 2] = [32, 2];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn lock_hash(mut self, v: Byte32) -> Self {
+        self.lock_hash = v;
+        self
+    }
+    pub fn basis_points(mut self, v: Uint16) -> Self {
+        self.basis_points = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for FeeBeneficiaryBuilder {
+    type Entity = FeeBeneficiary;
+    const NAME: &'static str = "FeeBeneficiaryBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.lock_hash.as_slice())?;
+        writer.write_all(self.basis_points.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        FeeBeneficiary::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct FeeBeneficiaryVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for FeeBeneficiaryVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for FeeBeneficiaryVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for FeeBeneficiaryVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for FeeBeneficiaryVec {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        FeeBeneficiaryVec::new_unchecked(v)
+    }
+}
+impl FeeBeneficiaryVec {
+    const DEFAULT_VALUE: [u8; 4] = [0, 0, 0, 0];
+    pub const ITEM_SIZE: usize = 34;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<FeeBeneficiary> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> FeeBeneficiary {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        FeeBeneficiary::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn as_reader<'r>(&'r self) -> FeeBeneficiaryVecReader<'r> {
+        FeeBeneficiaryVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for FeeBeneficiaryVec {
+    type Builder = FeeBeneficiaryVecBuilder;
+    const NAME: &'static str = "FeeBeneficiaryVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        FeeBeneficiaryVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FeeBeneficiaryVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FeeBeneficiaryVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct FeeBeneficiaryVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for FeeBeneficiaryVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for FeeBeneficiaryVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for FeeBeneficiaryVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> FeeBeneficiaryVecReader<'r> {
+    pub const ITEM_SIZE: usize = 34;
+    pub fn total_size(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.item_count()
+    }
+    pub fn item_count(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<FeeBeneficiaryReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> FeeBeneficiaryReader<'r> {
+        let start = molecule::NUMBER_SIZE + Self::ITEM_SIZE * idx;
+        let end = start + Self::ITEM_SIZE;
+        FeeBeneficiaryReader::new_unchecked(&self.as_slice()[start..end])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for FeeBeneficiaryVecReader<'r> {
+    type Entity = FeeBeneficiaryVec;
+    const NAME: &'static str = "FeeBeneficiaryVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        FeeBeneficiaryVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let item_count = molecule::unpack_number(slice) as usize;
+        if item_count == 0 {
+            if slice_len != molecule::NUMBER_SIZE {
+                return ve!(Self, TotalSizeNotMatch, molecule::NUMBER_SIZE, slice_len);
+            }
+            return Ok(());
+        }
+        let total_size = molecule::NUMBER_SIZE + Self::ITEM_SIZE * item_count;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct FeeBeneficiaryVecBuilder(pub(crate) Vec<FeeBeneficiary>);
+impl FeeBeneficiaryVecBuilder {
+    pub const ITEM_SIZE: usize = 34;
+    pub fn set(mut self, v: Vec<FeeBeneficiary>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: FeeBeneficiary) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = FeeBeneficiary>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+    pub fn replace(&mut self, index: usize, v: FeeBeneficiary) -> Option<FeeBeneficiary> {
+        self.0
+            .get_mut(index)
+            .map(|item| ::core::mem::replace(item, v))
+    }
+}
+impl molecule::prelude::Builder for FeeBeneficiaryVecBuilder {
+    type Entity = FeeBeneficiaryVec;
+    const NAME: &'static str = "FeeBeneficiaryVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE + Self::ITEM_SIZE * self.0.len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(&molecule::pack_number(self.0.len() as molecule::Number))?;
+        for inner in &self.0[..] {
+            writer.write_all(inner.as_slice())?;
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        FeeBeneficiaryVec::new_unchecked(inner.into())
+    }
+}
+pub struct FeeBeneficiaryVecIterator(FeeBeneficiaryVec, usize, usize);
+impl ::core::iter::Iterator for FeeBeneficiaryVecIterator {
+    type Item = FeeBeneficiary;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for FeeBeneficiaryVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for FeeBeneficiaryVec {
+    type Item = FeeBeneficiary;
+    type IntoIter = FeeBeneficiaryVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        FeeBeneficiaryVecIterator(self, 0, len)
+    }
+}
+impl<'r> FeeBeneficiaryVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> FeeBeneficiaryVecReaderIterator<'t, 'r> {
+        FeeBeneficiaryVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct FeeBeneficiaryVecReaderIterator<'t, 'r>(&'t FeeBeneficiaryVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for FeeBeneficiaryVecReaderIterator<'t, 'r> {
+    type Item = FeeBeneficiaryReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for FeeBeneficiaryVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::FromIterator<FeeBeneficiary> for FeeBeneficiaryVec {
+    fn from_iter<T: IntoIterator<Item = FeeBeneficiary>>(iter: T) -> Self {
+        Self::new_builder().extend(iter).build()
+    }
+}
+#[derive(Clone)]
+pub struct FeeModel(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for FeeModel {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for FeeModel {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for FeeModel {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "kind", self.kind())?;
+        write!(f, ", {}: {}", "flat_fee", self.flat_fee())?;
+        write!(f, ", {}: {}", "min_fee", self.min_fee())?;
+        write!(f, ", {}: {}", "max_fee", self.max_fee())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for FeeModel {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        FeeModel::new_unchecked(v)
+    }
+}
+impl FeeModel {
+    const DEFAULT_VALUE: [u8; 25] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 25;
+    pub const FIELD_SIZES: [usize; 4] = [1, 8, 8, 8];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn kind(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+    pub fn flat_fee(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(1..9))
+    }
+    pub fn min_fee(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(9..17))
+    }
+    pub fn max_fee(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(17..25))
+    }
+    pub fn as_reader<'r>(&'r self) -> FeeModelReader<'r> {
+        FeeModelReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for FeeModel {
+    type Builder = FeeModelBuilder;
+    const NAME: &'static str = "FeeModel";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        FeeModel(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FeeModelReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        FeeModelReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .kind(self.kind())
+            .flat_fee(self.flat_fee())
+            .min_fee(self.min_fee())
+            .max_fee(self.max_fee())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct FeeModelReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for FeeModelReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for FeeModelReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for FeeModelReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "kind", self.kind())?;
+        write!(f, ", {}: {}", "flat_fee", self.flat_fee())?;
+        write!(f, ", {}: {}", "min_fee", self.min_fee())?;
+        write!(f, ", {}: {}", "max_fee", self.max_fee())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> FeeModelReader<'r> {
+    pub const TOTAL_SIZE: usize = 25;
+    pub const FIELD_SIZES: [usize; 4] = [1, 8, 8, 8];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn kind(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn flat_fee(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[1..9])
+    }
+    pub fn min_fee(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[9..17])
+    }
+    pub fn max_fee(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[17..25])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for FeeModelReader<'r> {
+    type Entity = FeeModel;
+    const NAME: &'static str = "FeeModelReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        FeeModelReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct FeeModelBuilder {
+    pub(crate) kind: Byte,
+    pub(crate) flat_fee: Uint64,
+    pub(crate) min_fee: Uint64,
+    pub(crate) max_fee: Uint64,
+}
+impl FeeModelBuilder {
+    pub const TOTAL_SIZE: usize = 25;
+    pub const FIELD_SIZES: [usize; 4] = [1, 8, 8, 8];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn kind(mut self, v: Byte) -> Self {
+        self.kind = v;
+        self
+    }
+    pub fn flat_fee(mut self, v: Uint64) -> Self {
+        self.flat_fee = v;
+        self
+    }
+    pub fn min_fee(mut self, v: Uint64) -> Self {
+        self.min_fee = v;
+        self
+    }
+    pub fn max_fee(mut self, v: Uint64) -> Self {
+        self.max_fee = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for FeeModelBuilder {
+    type Entity = FeeModel;
+    const NAME: &'static str = "FeeModelBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.kind.as_slice())?;
+        writer.write_all(self.flat_fee.as_slice())?;
+        writer.write_all(self.min_fee.as_slice())?;
+        writer.write_all(self.max_fee.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        FeeModel::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
 pub struct VaultCellData(molecule::bytes::Bytes);
 impl ::core::fmt::LowerHex for VaultCellData {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
@@ -13,58 +1097,1223 @@ impl ::core::fmt::LowerHex for VaultCellData {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for VaultCellData {
+impl ::core::fmt::Debug for VaultCellData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for VaultCellData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "campaign_id", self.campaign_id())?;
+        write!(f, ", {}: {}", "fee_percentage", self.fee_percentage())?;
+        write!(
+            f,
+            ", {}: {}",
+            "proof_script_code_hash",
+            self.proof_script_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "proof_script_hash_type",
+            self.proof_script_hash_type()
+        )?;
+        write!(f, ", {}: {}", "funders", self.funders())?;
+        write!(f, ", {}: {}", "fee_schedule", self.fee_schedule())?;
+        write!(f, ", {}: {}", "fee_model", self.fee_model())?;
+        write!(f, ", {}: {}", "vesting", self.vesting())?;
+        write!(f, ", {}: {}", "entity_root", self.entity_root())?;
+        write!(f, ", {}: {}", "entity_ids", self.entity_ids())?;
+        write!(f, ", {}: {}", "entity_registry", self.entity_registry())?;
+        write!(
+            f,
+            ", {}: {}",
+            "entity_creator_lock_hash",
+            self.entity_creator_lock_hash()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for VaultCellData {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        VaultCellData::new_unchecked(v)
+    }
+}
+impl VaultCellData {
+    const DEFAULT_VALUE: [u8; 302] = [
+        46, 1, 0, 0, 52, 0, 0, 0, 84, 0, 0, 0, 86, 0, 0, 0, 118, 0, 0, 0, 119, 0, 0, 0, 123, 0, 0,
+        0, 127, 0, 0, 0, 152, 0, 0, 0, 201, 0, 0, 0, 233, 0, 0, 0, 237, 0, 0, 0, 14, 1, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 12;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn campaign_id(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn fee_percentage(&self) -> Uint16 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint16::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn proof_script_code_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn proof_script_hash_type(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn funders(&self) -> FunderVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        FunderVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn fee_schedule(&self) -> FeeBeneficiaryVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        FeeBeneficiaryVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn fee_model(&self) -> FeeModel {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        FeeModel::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn vesting(&self) -> VestingConfig {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        VestingConfig::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn entity_root(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn entity_ids(&self) -> Byte32Vec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        Byte32Vec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn entity_registry(&self) -> ScriptRef {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        ScriptRef::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn entity_creator_lock_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[52..]) as usize;
+            Byte32::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte32::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> VaultCellDataReader<'r> {
+        VaultCellDataReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for VaultCellData {
+    type Builder = VaultCellDataBuilder;
+    const NAME: &'static str = "VaultCellData";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        VaultCellData(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        VaultCellDataReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        VaultCellDataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .campaign_id(self.campaign_id())
+            .fee_percentage(self.fee_percentage())
+            .proof_script_code_hash(self.proof_script_code_hash())
+            .proof_script_hash_type(self.proof_script_hash_type())
+            .funders(self.funders())
+            .fee_schedule(self.fee_schedule())
+            .fee_model(self.fee_model())
+            .vesting(self.vesting())
+            .entity_root(self.entity_root())
+            .entity_ids(self.entity_ids())
+            .entity_registry(self.entity_registry())
+            .entity_creator_lock_hash(self.entity_creator_lock_hash())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct VaultCellDataReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for VaultCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for VaultCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for VaultCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "campaign_id", self.campaign_id())?;
+        write!(f, ", {}: {}", "fee_percentage", self.fee_percentage())?;
+        write!(
+            f,
+            ", {}: {}",
+            "proof_script_code_hash",
+            self.proof_script_code_hash()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "proof_script_hash_type",
+            self.proof_script_hash_type()
+        )?;
+        write!(f, ", {}: {}", "funders", self.funders())?;
+        write!(f, ", {}: {}", "fee_schedule", self.fee_schedule())?;
+        write!(f, ", {}: {}", "fee_model", self.fee_model())?;
+        write!(f, ", {}: {}", "vesting", self.vesting())?;
+        write!(f, ", {}: {}", "entity_root", self.entity_root())?;
+        write!(f, ", {}: {}", "entity_ids", self.entity_ids())?;
+        write!(f, ", {}: {}", "entity_registry", self.entity_registry())?;
+        write!(
+            f,
+            ", {}: {}",
+            "entity_creator_lock_hash",
+            self.entity_creator_lock_hash()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> VaultCellDataReader<'r> {
+    pub const FIELD_COUNT: usize = 12;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn campaign_id(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn fee_percentage(&self) -> Uint16Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint16Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn proof_script_code_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn proof_script_hash_type(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn funders(&self) -> FunderVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        FunderVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn fee_schedule(&self) -> FeeBeneficiaryVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        FeeBeneficiaryVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn fee_model(&self) -> FeeModelReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        FeeModelReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn vesting(&self) -> VestingConfigReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        VestingConfigReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn entity_root(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn entity_ids(&self) -> Byte32VecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn entity_registry(&self) -> ScriptRefReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        ScriptRefReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn entity_creator_lock_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[52..]) as usize;
+            Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Byte32Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for VaultCellDataReader<'r> {
+    type Entity = VaultCellData;
+    const NAME: &'static str = "VaultCellDataReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        VaultCellDataReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint16Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        ByteReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        FunderVecReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        FeeBeneficiaryVecReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        FeeModelReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        VestingConfigReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        Byte32VecReader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        ScriptRefReader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct VaultCellDataBuilder {
+    pub(crate) campaign_id: Byte32,
+    pub(crate) fee_percentage: Uint16,
+    pub(crate) proof_script_code_hash: Byte32,
+    pub(crate) proof_script_hash_type: Byte,
+    pub(crate) funders: FunderVec,
+    pub(crate) fee_schedule: FeeBeneficiaryVec,
+    pub(crate) fee_model: FeeModel,
+    pub(crate) vesting: VestingConfig,
+    pub(crate) entity_root: Byte32,
+    pub(crate) entity_ids: Byte32Vec,
+    pub(crate) entity_registry: ScriptRef,
+    pub(crate) entity_creator_lock_hash: Byte32,
+}
+impl VaultCellDataBuilder {
+    pub const FIELD_COUNT: usize = 12;
+    pub fn campaign_id(mut self, v: Byte32) -> Self {
+        self.campaign_id = v;
+        self
+    }
+    pub fn fee_percentage(mut self, v: Uint16) -> Self {
+        self.fee_percentage = v;
+        self
+    }
+    pub fn proof_script_code_hash(mut self, v: Byte32) -> Self {
+        self.proof_script_code_hash = v;
+        self
+    }
+    pub fn proof_script_hash_type(mut self, v: Byte) -> Self {
+        self.proof_script_hash_type = v;
+        self
+    }
+    pub fn funders(mut self, v: FunderVec) -> Self {
+        self.funders = v;
+        self
+    }
+    pub fn fee_schedule(mut self, v: FeeBeneficiaryVec) -> Self {
+        self.fee_schedule = v;
+        self
+    }
+    pub fn fee_model(mut self, v: FeeModel) -> Self {
+        self.fee_model = v;
+        self
+    }
+    pub fn vesting(mut self, v: VestingConfig) -> Self {
+        self.vesting = v;
+        self
+    }
+    pub fn entity_root(mut self, v: Byte32) -> Self {
+        self.entity_root = v;
+        self
+    }
+    pub fn entity_ids(mut self, v: Byte32Vec) -> Self {
+        self.entity_ids = v;
+        self
+    }
+    pub fn entity_registry(mut self, v: ScriptRef) -> Self {
+        self.entity_registry = v;
+        self
+    }
+    pub fn entity_creator_lock_hash(mut self, v: Byte32) -> Self {
+        self.entity_creator_lock_hash = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for VaultCellDataBuilder {
+    type Entity = VaultCellData;
+    const NAME: &'static str = "VaultCellDataBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.campaign_id.as_slice().len()
+            + self.fee_percentage.as_slice().len()
+            + self.proof_script_code_hash.as_slice().len()
+            + self.proof_script_hash_type.as_slice().len()
+            + self.funders.as_slice().len()
+            + self.fee_schedule.as_slice().len()
+            + self.fee_model.as_slice().len()
+            + self.vesting.as_slice().len()
+            + self.entity_root.as_slice().len()
+            + self.entity_ids.as_slice().len()
+            + self.entity_registry.as_slice().len()
+            + self.entity_creator_lock_hash.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.campaign_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.fee_percentage.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.proof_script_code_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.proof_script_hash_type.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.funders.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.fee_schedule.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.fee_model.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.vesting.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.entity_root.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.entity_ids.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.entity_registry.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.entity_creator_lock_hash.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.campaign_id.as_slice())?;
+        writer.write_all(self.fee_percentage.as_slice())?;
+        writer.write_all(self.proof_script_code_hash.as_slice())?;
+        writer.write_all(self.proof_script_hash_type.as_slice())?;
+        writer.write_all(self.funders.as_slice())?;
+        writer.write_all(self.fee_schedule.as_slice())?;
+        writer.write_all(self.fee_model.as_slice())?;
+        writer.write_all(self.vesting.as_slice())?;
+        writer.write_all(self.entity_root.as_slice())?;
+        writer.write_all(self.entity_ids.as_slice())?;
+        writer.write_all(self.entity_registry.as_slice())?;
+        writer.write_all(self.entity_creator_lock_hash.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        VaultCellData::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct VaultCellDataV1(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for VaultCellDataV1 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for VaultCellDataV1 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for VaultCellDataV1 {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "campaign_id", self.campaign_id())?;
+        write!(f, ", {}: {}", "fee_percentage", self.fee_percentage())?;
+        write!(
+            f,
+            ", {}: {}",
+            "proof_script_code_hash",
+            self.proof_script_code_hash()
+        )?;
+        write!(f, ", {}: {}", "funders", self.funders())?;
+        write!(f, ", {}: {}", "fee_schedule", self.fee_schedule())?;
+        write!(f, ", {}: {}", "fee_model", self.fee_model())?;
+        write!(f, ", {}: {}", "vesting", self.vesting())?;
+        write!(f, ", {}: {}", "entity_root", self.entity_root())?;
+        write!(f, ", {}: {}", "entity_ids", self.entity_ids())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for VaultCellDataV1 {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        VaultCellDataV1::new_unchecked(v)
+    }
+}
+impl VaultCellDataV1 {
+    const DEFAULT_VALUE: [u8; 223] = [
+        223, 0, 0, 0, 40, 0, 0, 0, 72, 0, 0, 0, 74, 0, 0, 0, 106, 0, 0, 0, 110, 0, 0, 0, 114, 0, 0,
+        0, 139, 0, 0, 0, 187, 0, 0, 0, 219, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const FIELD_COUNT: usize = 9;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn campaign_id(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn fee_percentage(&self) -> Uint16 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint16::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn proof_script_code_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn funders(&self) -> FunderVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        FunderVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn fee_schedule(&self) -> FeeBeneficiaryVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        FeeBeneficiaryVec::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn fee_model(&self) -> FeeModel {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        FeeModel::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn vesting(&self) -> VestingConfigV1 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        VestingConfigV1::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn entity_root(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn entity_ids(&self) -> Byte32Vec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[40..]) as usize;
+            Byte32Vec::new_unchecked(self.0.slice(start..end))
+        } else {
+            Byte32Vec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> VaultCellDataV1Reader<'r> {
+        VaultCellDataV1Reader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for VaultCellDataV1 {
+    type Builder = VaultCellDataV1Builder;
+    const NAME: &'static str = "VaultCellDataV1";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        VaultCellDataV1(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        VaultCellDataV1Reader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        VaultCellDataV1Reader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .campaign_id(self.campaign_id())
+            .fee_percentage(self.fee_percentage())
+            .proof_script_code_hash(self.proof_script_code_hash())
+            .funders(self.funders())
+            .fee_schedule(self.fee_schedule())
+            .fee_model(self.fee_model())
+            .vesting(self.vesting())
+            .entity_root(self.entity_root())
+            .entity_ids(self.entity_ids())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct VaultCellDataV1Reader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for VaultCellDataV1Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for VaultCellDataV1Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for VaultCellDataV1Reader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "campaign_id", self.campaign_id())?;
+        write!(f, ", {}: {}", "fee_percentage", self.fee_percentage())?;
+        write!(
+            f,
+            ", {}: {}",
+            "proof_script_code_hash",
+            self.proof_script_code_hash()
+        )?;
+        write!(f, ", {}: {}", "funders", self.funders())?;
+        write!(f, ", {}: {}", "fee_schedule", self.fee_schedule())?;
+        write!(f, ", {}: {}", "fee_model", self.fee_model())?;
+        write!(f, ", {}: {}", "vesting", self.vesting())?;
+        write!(f, ", {}: {}", "entity_root", self.entity_root())?;
+        write!(f, ", {}: {}", "entity_ids", self.entity_ids())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> VaultCellDataV1Reader<'r> {
+    pub const FIELD_COUNT: usize = 9;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn campaign_id(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn fee_percentage(&self) -> Uint16Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint16Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn proof_script_code_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn funders(&self) -> FunderVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        FunderVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn fee_schedule(&self) -> FeeBeneficiaryVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        FeeBeneficiaryVecReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn fee_model(&self) -> FeeModelReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        FeeModelReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn vesting(&self) -> VestingConfigV1Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        VestingConfigV1Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn entity_root(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn entity_ids(&self) -> Byte32VecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[40..]) as usize;
+            Byte32VecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Byte32VecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for VaultCellDataV1Reader<'r> {
+    type Entity = VaultCellDataV1;
+    const NAME: &'static str = "VaultCellDataV1Reader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        VaultCellDataV1Reader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % molecule::NUMBER_SIZE != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        if slice_len < offset_first {
+            return ve!(Self, HeaderIsBroken, offset_first, slice_len);
+        }
+        let field_count = offset_first / molecule::NUMBER_SIZE - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..offset_first]
+            .chunks_exact(molecule::NUMBER_SIZE)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint16Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        FunderVecReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        FeeBeneficiaryVecReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        FeeModelReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        VestingConfigV1Reader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        Byte32VecReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct VaultCellDataV1Builder {
+    pub(crate) campaign_id: Byte32,
+    pub(crate) fee_percentage: Uint16,
+    pub(crate) proof_script_code_hash: Byte32,
+    pub(crate) funders: FunderVec,
+    pub(crate) fee_schedule: FeeBeneficiaryVec,
+    pub(crate) fee_model: FeeModel,
+    pub(crate) vesting: VestingConfigV1,
+    pub(crate) entity_root: Byte32,
+    pub(crate) entity_ids: Byte32Vec,
+}
+impl VaultCellDataV1Builder {
+    pub const FIELD_COUNT: usize = 9;
+    pub fn campaign_id(mut self, v: Byte32) -> Self {
+        self.campaign_id = v;
+        self
+    }
+    pub fn fee_percentage(mut self, v: Uint16) -> Self {
+        self.fee_percentage = v;
+        self
+    }
+    pub fn proof_script_code_hash(mut self, v: Byte32) -> Self {
+        self.proof_script_code_hash = v;
+        self
+    }
+    pub fn funders(mut self, v: FunderVec) -> Self {
+        self.funders = v;
+        self
+    }
+    pub fn fee_schedule(mut self, v: FeeBeneficiaryVec) -> Self {
+        self.fee_schedule = v;
+        self
+    }
+    pub fn fee_model(mut self, v: FeeModel) -> Self {
+        self.fee_model = v;
+        self
+    }
+    pub fn vesting(mut self, v: VestingConfigV1) -> Self {
+        self.vesting = v;
+        self
+    }
+    pub fn entity_root(mut self, v: Byte32) -> Self {
+        self.entity_root = v;
+        self
+    }
+    pub fn entity_ids(mut self, v: Byte32Vec) -> Self {
+        self.entity_ids = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for VaultCellDataV1Builder {
+    type Entity = VaultCellDataV1;
+    const NAME: &'static str = "VaultCellDataV1Builder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.campaign_id.as_slice().len()
+            + self.fee_percentage.as_slice().len()
+            + self.proof_script_code_hash.as_slice().len()
+            + self.funders.as_slice().len()
+            + self.fee_schedule.as_slice().len()
+            + self.fee_model.as_slice().len()
+            + self.vesting.as_slice().len()
+            + self.entity_root.as_slice().len()
+            + self.entity_ids.as_slice().len()
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.campaign_id.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.fee_percentage.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.proof_script_code_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.funders.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.fee_schedule.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.fee_model.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.vesting.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.entity_root.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.entity_ids.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.campaign_id.as_slice())?;
+        writer.write_all(self.fee_percentage.as_slice())?;
+        writer.write_all(self.proof_script_code_hash.as_slice())?;
+        writer.write_all(self.funders.as_slice())?;
+        writer.write_all(self.fee_schedule.as_slice())?;
+        writer.write_all(self.fee_model.as_slice())?;
+        writer.write_all(self.vesting.as_slice())?;
+        writer.write_all(self.entity_root.as_slice())?;
+        writer.write_all(self.entity_ids.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        VaultCellDataV1::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct VaultLockArgs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for VaultLockArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for VaultLockArgs {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for VaultCellData {
+impl ::core::fmt::Display for VaultLockArgs {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "campaign_id", self.campaign_id())?;
-        write!(f, ", {}: {}", "fee_percentage", self.fee_percentage())?;
-        write!(
-            f,
-            ", {}: {}",
-            "proof_script_code_hash",
-            self.proof_script_code_hash()
-        )?;
+        write!(f, "{}: {}", "creator_lock_hash", self.creator_lock_hash())?;
+        write!(f, ", {}: {}", "admin_lock_hash", self.admin_lock_hash())?;
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for VaultCellData {
+impl ::core::default::Default for VaultLockArgs {
     fn default() -> Self {
         let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
-        VaultCellData::new_unchecked(v)
+        VaultLockArgs::new_unchecked(v)
     }
 }
-impl VaultCellData {
+impl VaultLockArgs {
+    const DEFAULT_VALUE: [u8; 64] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 64;
+    pub const FIELD_SIZES: [usize; 2] = [32, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn creator_lock_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn admin_lock_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(32..64))
+    }
+    pub fn as_reader<'r>(&'r self) -> VaultLockArgsReader<'r> {
+        VaultLockArgsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for VaultLockArgs {
+    type Builder = VaultLockArgsBuilder;
+    const NAME: &'static str = "VaultLockArgs";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        VaultLockArgs(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        VaultLockArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        VaultLockArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .creator_lock_hash(self.creator_lock_hash())
+            .admin_lock_hash(self.admin_lock_hash())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct VaultLockArgsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for VaultLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for VaultLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for VaultLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "creator_lock_hash", self.creator_lock_hash())?;
+        write!(f, ", {}: {}", "admin_lock_hash", self.admin_lock_hash())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> VaultLockArgsReader<'r> {
+    pub const TOTAL_SIZE: usize = 64;
+    pub const FIELD_SIZES: [usize; 2] = [32, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn creator_lock_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn admin_lock_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[32..64])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for VaultLockArgsReader<'r> {
+    type Entity = VaultLockArgs;
+    const NAME: &'static str = "VaultLockArgsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        VaultLockArgsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct VaultLockArgsBuilder {
+    pub(crate) creator_lock_hash: Byte32,
+    pub(crate) admin_lock_hash: Byte32,
+}
+impl VaultLockArgsBuilder {
+    pub const TOTAL_SIZE: usize = 64;
+    pub const FIELD_SIZES: [usize; 2] = [32, 32];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn creator_lock_hash(mut self, v: Byte32) -> Self {
+        self.creator_lock_hash = v;
+        self
+    }
+    pub fn admin_lock_hash(mut self, v: Byte32) -> Self {
+        self.admin_lock_hash = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for VaultLockArgsBuilder {
+    type Entity = VaultLockArgs;
+    const NAME: &'static str = "VaultLockArgsBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.creator_lock_hash.as_slice())?;
+        writer.write_all(self.admin_lock_hash.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        VaultLockArgs::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct VaultTypeArgs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for VaultTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for VaultTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for VaultTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "distribution_lock", self.distribution_lock())?;
+        write!(f, ", {}: {}", "distribution_type", self.distribution_type())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for VaultTypeArgs {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        VaultTypeArgs::new_unchecked(v)
+    }
+}
+impl VaultTypeArgs {
     const DEFAULT_VALUE: [u8; 66] = [
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         0, 0, 0, 0, 0, 0,
     ];
     pub const TOTAL_SIZE: usize = 66;
-    pub const FIELD_SIZES: [usize; 3] = [32, 2, 32];
-    pub const FIELD_COUNT: usize = 3;
-    pub fn campaign_id(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(0..32))
-    }
-    pub fn fee_percentage(&self) -> Uint16 {
-        Uint16::new_unchecked(self.0.slice(32..34))
+    pub const FIELD_SIZES: [usize; 2] = [33, 33];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn distribution_lock(&self) -> ScriptRef {
+        ScriptRef::new_unchecked(self.0.slice(0..33))
     }
-    pub fn proof_script_code_hash(&self) -> Byte32 {
-        Byte32::new_unchecked(self.0.slice(34..66))
+    pub fn distribution_type(&self) -> ScriptRef {
+        ScriptRef::new_unchecked(self.0.slice(33..66))
     }
-    pub fn as_reader<'r>(&'r self) -> VaultCellDataReader<'r> {
-        VaultCellDataReader::new_unchecked(self.as_slice())
+    pub fn as_reader<'r>(&'r self) -> VaultTypeArgsReader<'r> {
+        VaultTypeArgsReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for VaultCellData {
-    type Builder = VaultCellDataBuilder;
-    const NAME: &'static str = "VaultCellData";
+impl molecule::prelude::Entity for VaultTypeArgs {
+    type Builder = VaultTypeArgsBuilder;
+    const NAME: &'static str = "VaultTypeArgs";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        VaultCellData(data)
+        VaultTypeArgs(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -73,24 +2322,23 @@ impl molecule::prelude::Entity for VaultCellData {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        VaultCellDataReader::from_slice(slice).map(|reader| reader.to_entity())
+        VaultTypeArgsReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        VaultCellDataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        VaultTypeArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .campaign_id(self.campaign_id())
-            .fee_percentage(self.fee_percentage())
-            .proof_script_code_hash(self.proof_script_code_hash())
+            .distribution_lock(self.distribution_lock())
+            .distribution_type(self.distribution_type())
     }
 }
 #[derive(Clone, Copy)]
-pub struct VaultCellDataReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for VaultCellDataReader<'r> {
+pub struct VaultTypeArgsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for VaultTypeArgsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -99,47 +2347,38 @@ impl<'r> ::core::fmt::LowerHex for VaultCellDataReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for VaultCellDataReader<'r> {
+impl<'r> ::core::fmt::Debug for VaultTypeArgsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for VaultCellDataReader<'r> {
+impl<'r> ::core::fmt::Display for VaultTypeArgsReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "campaign_id", self.campaign_id())?;
-        write!(f, ", {}: {}", "fee_percentage", self.fee_percentage())?;
-        write!(
-            f,
-            ", {}: {}",
-            "proof_script_code_hash",
-            self.proof_script_code_hash()
-        )?;
+        write!(f, "{}: {}", "distribution_lock", self.distribution_lock())?;
+        write!(f, ", {}: {}", "distribution_type", self.distribution_type())?;
         write!(f, " }}")
     }
 }
-impl<'r> VaultCellDataReader<'r> {
+impl<'r> VaultTypeArgsReader<'r> {
     pub const TOTAL_SIZE: usize = 66;
-    pub const FIELD_SIZES: [usize; 3] = [32, 2, 32];
-    pub const FIELD_COUNT: usize = 3;
-    pub fn campaign_id(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    pub const FIELD_SIZES: [usize; 2] = [33, 33];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn distribution_lock(&self) -> ScriptRefReader<'r> {
+        ScriptRefReader::new_unchecked(&self.as_slice()[0..33])
     }
-    pub fn fee_percentage(&self) -> Uint16Reader<'r> {
-        Uint16Reader::new_unchecked(&self.as_slice()[32..34])
-    }
-    pub fn proof_script_code_hash(&self) -> Byte32Reader<'r> {
-        Byte32Reader::new_unchecked(&self.as_slice()[34..66])
+    pub fn distribution_type(&self) -> ScriptRefReader<'r> {
+        ScriptRefReader::new_unchecked(&self.as_slice()[33..66])
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for VaultCellDataReader<'r> {
-    type Entity = VaultCellData;
-    const NAME: &'static str = "VaultCellDataReader";
+impl<'r> molecule::prelude::Reader<'r> for VaultTypeArgsReader<'r> {
+    type Entity = VaultTypeArgs;
+    const NAME: &'static str = "VaultTypeArgsReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        VaultCellDataReader(slice)
+        VaultTypeArgsReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
@@ -154,44 +2393,38 @@ impl<'r> molecule::prelude::Reader<'r> for VaultCellDataReader<'r> {
     }
 }
 #[derive(Clone, Debug, Default)]
-pub struct VaultCellDataBuilder {
-    pub(crate) campaign_id: Byte32,
-    pub(crate) fee_percentage: Uint16,
-    pub(crate) proof_script_code_hash: Byte32,
+pub struct VaultTypeArgsBuilder {
+    pub(crate) distribution_lock: ScriptRef,
+    pub(crate) distribution_type: ScriptRef,
 }
-impl VaultCellDataBuilder {
+impl VaultTypeArgsBuilder {
     pub const TOTAL_SIZE: usize = 66;
-    pub const FIELD_SIZES: [usize; 3] = [32, 2, 32];
-    pub const FIELD_COUNT: usize = 3;
-    pub fn campaign_id(mut self, v: Byte32) -> Self {
-        self.campaign_id = v;
-        self
-    }
-    pub fn fee_percentage(mut self, v: Uint16) -> Self {
-        self.fee_percentage = v;
+    pub const FIELD_SIZES: [usize; 2] = [33, 33];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn distribution_lock(mut self, v: ScriptRef) -> Self {
+        self.distribution_lock = v;
         self
     }
-    pub fn proof_script_code_hash(mut self, v: Byte32) -> Self {
-        self.proof_script_code_hash = v;
+    pub fn distribution_type(mut self, v: ScriptRef) -> Self {
+        self.distribution_type = v;
         self
     }
 }
-impl molecule::prelude::Builder for VaultCellDataBuilder {
-    type Entity = VaultCellData;
-    const NAME: &'static str = "VaultCellDataBuilder";
+impl molecule::prelude::Builder for VaultTypeArgsBuilder {
+    type Entity = VaultTypeArgs;
+    const NAME: &'static str = "VaultTypeArgsBuilder";
     fn expected_length(&self) -> usize {
         Self::TOTAL_SIZE
     }
     fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
-        writer.write_all(self.campaign_id.as_slice())?;
-        writer.write_all(self.fee_percentage.as_slice())?;
-        writer.write_all(self.proof_script_code_hash.as_slice())?;
+        writer.write_all(self.distribution_lock.as_slice())?;
+        writer.write_all(self.distribution_type.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        VaultCellData::new_unchecked(inner.into())
+        VaultTypeArgs::new_unchecked(inner.into())
     }
 }
@@ -0,0 +1,211 @@
+// Generated by Molecule 0.8.0
+
+use super::base::*;
+use molecule::prelude::*;
+#[derive(Clone)]
+pub struct VestingLockArgs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for VestingLockArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for VestingLockArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for VestingLockArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "beneficiary_lock_hash",
+            self.beneficiary_lock_hash()
+        )?;
+        write!(f, ", {}: {}", "locked_amount", self.locked_amount())?;
+        write!(f, ", {}: {}", "start_epoch", self.start_epoch())?;
+        write!(f, ", {}: {}", "end_epoch", self.end_epoch())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for VestingLockArgs {
+    fn default() -> Self {
+        let v = molecule::bytes::Bytes::from_static(&Self::DEFAULT_VALUE);
+        VestingLockArgs::new_unchecked(v)
+    }
+}
+impl VestingLockArgs {
+    const DEFAULT_VALUE: [u8; 56] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    pub const TOTAL_SIZE: usize = 56;
+    pub const FIELD_SIZES: [usize; 4] = [32, 8, 8, 8];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn beneficiary_lock_hash(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(0..32))
+    }
+    pub fn locked_amount(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(32..40))
+    }
+    pub fn start_epoch(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(40..48))
+    }
+    pub fn end_epoch(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(48..56))
+    }
+    pub fn as_reader<'r>(&'r self) -> VestingLockArgsReader<'r> {
+        VestingLockArgsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for VestingLockArgs {
+    type Builder = VestingLockArgsBuilder;
+    const NAME: &'static str = "VestingLockArgs";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        VestingLockArgs(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        VestingLockArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        VestingLockArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .beneficiary_lock_hash(self.beneficiary_lock_hash())
+            .locked_amount(self.locked_amount())
+            .start_epoch(self.start_epoch())
+            .end_epoch(self.end_epoch())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct VestingLockArgsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for VestingLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for VestingLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for VestingLockArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "beneficiary_lock_hash",
+            self.beneficiary_lock_hash()
+        )?;
+        write!(f, ", {}: {}", "locked_amount", self.locked_amount())?;
+        write!(f, ", {}: {}", "start_epoch", self.start_epoch())?;
+        write!(f, ", {}: {}", "end_epoch", self.end_epoch())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> VestingLockArgsReader<'r> {
+    pub const TOTAL_SIZE: usize = 56;
+    pub const FIELD_SIZES: [usize; 4] = [32, 8, 8, 8];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn beneficiary_lock_hash(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[0..32])
+    }
+    pub fn locked_amount(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[32..40])
+    }
+    pub fn start_epoch(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[40..48])
+    }
+    pub fn end_epoch(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[48..56])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for VestingLockArgsReader<'r> {
+    type Entity = VestingLockArgs;
+    const NAME: &'static str = "VestingLockArgsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        VestingLockArgsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Clone, Debug, Default)]
+pub struct VestingLockArgsBuilder {
+    pub(crate) beneficiary_lock_hash: Byte32,
+    pub(crate) locked_amount: Uint64,
+    pub(crate) start_epoch: Uint64,
+    pub(crate) end_epoch: Uint64,
+}
+impl VestingLockArgsBuilder {
+    pub const TOTAL_SIZE: usize = 56;
+    pub const FIELD_SIZES: [usize; 4] = [32, 8, 8, 8];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn beneficiary_lock_hash(mut self, v: Byte32) -> Self {
+        self.beneficiary_lock_hash = v;
+        self
+    }
+    pub fn locked_amount(mut self, v: Uint64) -> Self {
+        self.locked_amount = v;
+        self
+    }
+    pub fn start_epoch(mut self, v: Uint64) -> Self {
+        self.start_epoch = v;
+        self
+    }
+    pub fn end_epoch(mut self, v: Uint64) -> Self {
+        self.end_epoch = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for VestingLockArgsBuilder {
+    type Entity = VestingLockArgs;
+    const NAME: &'static str = "VestingLockArgsBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: molecule::io::Write>(&self, writer: &mut W) -> molecule::io::Result<()> {
+        writer.write_all(self.beneficiary_lock_hash.as_slice())?;
+        writer.write_all(self.locked_amount.as_slice())?;
+        writer.write_all(self.start_epoch.as_slice())?;
+        writer.write_all(self.end_epoch.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        VestingLockArgs::new_unchecked(inner.into())
+    }
+}
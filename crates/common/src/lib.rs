@@ -4,9 +4,25 @@ extern crate alloc;
 
 pub const NULL_HASH: [u8; 32] = [0u8; 32];
 
+pub mod bitmap;
+#[cfg(feature = "bundle")]
+pub mod bundle;
+pub mod contract;
 pub mod conversion;
+#[deny(clippy::panic)]
 pub mod error;
+#[cfg(feature = "vault")]
+pub mod fees;
+#[cfg(feature = "vault")]
+pub mod funders;
+pub mod merkle;
+#[cfg(feature = "proof")]
+pub mod proof;
+pub mod script;
 pub mod utils;
+pub mod version;
+#[cfg(feature = "vesting")]
+pub mod vesting;
 
 mod generated;
 
@@ -17,12 +33,21 @@ pub mod schema {
     #![allow(unknown_lints)]
     #![allow(warnings)]
 
+    #[cfg(feature = "bundle")]
+    pub use crate::generated::bundle;
+
     #[cfg(feature = "distribution")]
     pub use crate::generated::distribution;
 
+    #[cfg(feature = "entity")]
+    pub use crate::generated::entity;
+
     #[cfg(feature = "proof")]
     pub use crate::generated::proof;
 
     #[cfg(feature = "vault")]
     pub use crate::generated::vault;
+
+    #[cfg(feature = "vesting")]
+    pub use crate::generated::vesting;
 }
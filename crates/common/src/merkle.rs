@@ -0,0 +1,151 @@
+//! Claimant Merkle leaves and proofs.
+//!
+//! `distribution-lock` verifies claims with these routines, and off-chain
+//! tooling uses the same ones so a proof it accepts is one the chain accepts.
+//! Parents hash their two children in ascending order.
+
+use alloc::vec::Vec;
+use ckb_hash::new_blake2b;
+use molecule::prelude::Entity;
+
+#[cfg(feature = "distribution")]
+use crate::schema::distribution::ClaimWitness;
+use crate::{base::Byte32Vec, NULL_HASH};
+
+/// Merkle proofs longer than this are rejected, which bounds the cycles a
+/// claim can spend on verification.
+pub const MAX_MERKLE_PROOF_SIBLINGS: usize = 64;
+
+/// Hashes `data` with the CKB blake2b personalization.
+pub fn blake2b_256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = new_blake2b();
+    hasher.update(data);
+    let mut hash = [0u8; 32];
+    hasher.finalize(&mut hash);
+    hash
+}
+
+/// Computes a claimant's leaf from the proof cell Type ID and the subscriber
+/// lock hash, or the hash of the secret for a private claim, followed by the
+/// serialized `Referral` when the claim has one.
+pub fn claimant_leaf(
+    proof_type_id: &[u8],
+    subscriber_key: &[u8],
+    referral: Option<&[u8]>,
+) -> [u8; 32] {
+    let mut hasher = new_blake2b();
+    hasher.update(proof_type_id);
+    hasher.update(subscriber_key);
+    if let Some(referral) = referral {
+        hasher.update(referral);
+    }
+    let mut leaf = [0u8; 32];
+    hasher.finalize(&mut leaf);
+    leaf
+}
+
+/// Computes the leaf a claim witness reveals: the private claim's secret is
+/// hashed in place of the subscriber lock hash.
+#[cfg(feature = "distribution")]
+pub fn witness_leaf(witness: &ClaimWitness) -> [u8; 32] {
+    let subscriber_key = match witness.private_claim().to_opt() {
+        Some(private_claim) => blake2b_256(private_claim.secret().as_slice()),
+        None => witness.subscriber_lock_hash().into(),
+    };
+    let referral = witness.referral().to_opt();
+    claimant_leaf(
+        witness.proof_type_id().as_slice(),
+        &subscriber_key,
+        referral.as_ref().map(|referral| referral.as_slice()),
+    )
+}
+
+/// Hashes two sibling nodes into their parent, in ascending order.
+pub fn merkle_parent(node: &[u8], sibling: &[u8]) -> [u8; 32] {
+    let mut hasher = new_blake2b();
+    if node < sibling {
+        hasher.update(node);
+        hasher.update(sibling);
+    } else {
+        hasher.update(sibling);
+        hasher.update(node);
+    }
+    let mut parent = [0u8; 32];
+    hasher.finalize(&mut parent);
+    parent
+}
+
+/// Computes the root of the tree over `leaves`, pairing the last node of an
+/// odd level with itself. A single leaf is its own root, and no leaves give
+/// the null hash.
+pub fn merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    let mut level: Vec<[u8; 32]> = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level(&level);
+    }
+    level.first().copied().unwrap_or(NULL_HASH)
+}
+
+/// Returns the sibling path of the leaf at `leaf_index`, from the leaf up to
+/// the root, as `verify_merkle_proof` expects it.
+pub fn merkle_proof(leaves: &[[u8; 32]], leaf_index: usize) -> Vec<[u8; 32]> {
+    let mut proof = Vec::new();
+    let mut level = leaves.to_vec();
+    let mut index = leaf_index;
+
+    while level.len() > 1 {
+        let sibling_index = index ^ 1;
+        proof.push(*level.get(sibling_index).unwrap_or(&level[index]));
+
+        level = next_level(&level);
+        index /= 2;
+    }
+    proof
+}
+
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| {
+            let left = &pair[0];
+            let right = pair.get(1).unwrap_or(left);
+            merkle_parent(left, right)
+        })
+        .collect()
+}
+
+/// Checks that `merkle_proof` leads from `leaf` to `merkle_root`.
+pub fn verify_merkle_proof(merkle_root: &[u8], leaf: [u8; 32], merkle_proof: &Byte32Vec) -> bool {
+    if merkle_proof.len() > MAX_MERKLE_PROOF_SIBLINGS {
+        return false;
+    }
+
+    let mut computed_hash = leaf;
+    for sibling_hash in merkle_proof.clone().into_iter() {
+        computed_hash = merkle_parent(&computed_hash, sibling_hash.as_slice());
+    }
+
+    computed_hash == merkle_root
+}
+
+#[cfg(test)]
+mod tests {
+    use molecule::prelude::Builder;
+
+    use super::*;
+    use crate::base::Byte32;
+
+    #[test]
+    fn merkle_proofs_lead_to_the_root() {
+        for count in 1..=5u8 {
+            let leaves: Vec<[u8; 32]> = (0..count).map(|i| [i; 32]).collect();
+            let root = merkle_root(&leaves);
+            for (index, leaf) in leaves.iter().enumerate() {
+                let proof = Byte32Vec::new_builder()
+                    .extend(merkle_proof(&leaves, index).into_iter().map(Byte32::from))
+                    .build();
+                assert!(verify_merkle_proof(&root, *leaf, &proof));
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! The rules of proof cells that `proof-type`, `proof-lock` and
+//! `distribution-type` share.
+
+use ckb_std::{
+    ckb_types::{packed::Script, prelude::Unpack},
+    since::Since,
+};
+
+use crate::{
+    schema::proof::{ProofCellData, ProofTypeArgs},
+    script::ScriptRef,
+};
+
+/// Checks whether the proof has expired for an input spent with `since`.
+///
+/// The input's `since` is the only trusted view of the current time: the CKB
+/// VM rejects the transaction until the chain's median time has reached it.
+pub fn is_expired(proof_data: &ProofCellData, since: u64) -> bool {
+    let expiry: u64 = proof_data.expiry().unpack();
+    if expiry == 0 {
+        return false;
+    }
+
+    let since = Since::new(since);
+    if !since.is_absolute() {
+        return false;
+    }
+    let since_timestamp = since.extract_lock_value().and_then(|v| v.timestamp());
+
+    since_timestamp.is_some_and(|timestamp| timestamp >= expiry)
+}
+
+/// Checks whether `lock`, whose hash is `lock_hash`, guards the proof on
+/// behalf of `subscriber_lock_hash`: it is either the subscriber's own lock,
+/// or the proof lock named in the proof's args with the subscriber's lock hash
+/// as args.
+pub fn is_subscriber_lock(
+    proof_args: &ProofTypeArgs,
+    lock: &Script,
+    lock_hash: &[u8],
+    subscriber_lock_hash: &[u8],
+) -> bool {
+    lock_hash == subscriber_lock_hash
+        || (ScriptRef::from(proof_args.proof_lock()).matches(lock)
+            && lock.args().raw_data().as_ref() == subscriber_lock_hash)
+}
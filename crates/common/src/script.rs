@@ -0,0 +1,55 @@
+use ckb_std::ckb_types::packed::Script;
+use molecule::prelude::Entity;
+
+/// The length of a serialized `ScriptRef`: a code hash followed by a hash type.
+pub const SCRIPT_REF_LEN: usize = 33;
+
+/// Identifies the code a script runs, without its args.
+///
+/// Both the code hash and the hash type are compared, so a script deployed
+/// behind a Type ID (`hash_type` of `type`) cannot be mistaken for one that
+/// happens to use the same hash as a data hash, and the other way around.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ScriptRef {
+    pub code_hash: [u8; 32],
+    pub hash_type: u8,
+}
+
+impl ScriptRef {
+    /// Parses a `ScriptRef` from exactly `SCRIPT_REF_LEN` bytes.
+    pub fn from_slice(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != SCRIPT_REF_LEN {
+            return None;
+        }
+        Some(Self {
+            code_hash: bytes[..32].try_into().ok()?,
+            hash_type: bytes[32],
+        })
+    }
+
+    /// Serializes the reference as the molecule `ScriptRef` of cell data and
+    /// script args.
+    pub fn to_packed(&self) -> crate::base::ScriptRef {
+        use molecule::prelude::Builder;
+
+        crate::base::ScriptRef::new_builder()
+            .code_hash(self.code_hash.into())
+            .hash_type(self.hash_type.into())
+            .build()
+    }
+
+    /// Checks whether `script` runs the referenced code.
+    pub fn matches(&self, script: &Script) -> bool {
+        script.code_hash().as_slice() == self.code_hash
+            && script.hash_type().as_slice() == [self.hash_type]
+    }
+}
+
+impl From<crate::base::ScriptRef> for ScriptRef {
+    fn from(script_ref: crate::base::ScriptRef) -> Self {
+        Self {
+            code_hash: script_ref.code_hash().into(),
+            hash_type: script_ref.hash_type().into(),
+        }
+    }
+}
@@ -0,0 +1,208 @@
+//! Versioned cell data.
+//!
+//! Vault, distribution shard and proof cells store a version byte in front of
+//! their molecule data. Contracts dispatch on it, so a later layout can ship as
+//! a new version while cells created with an older one keep working.
+//!
+//! Cells created before the data was versioned hold bare `VaultCellData`,
+//! `DistributionCellData` and `ProofCellData` structs, and are not read here.
+//! That release deployed its scripts by `data1` hash, so those cells are only
+//! ever verified by its own binaries, and these contracts match platform cells
+//! on code hash and hash type, so they never take one for a vault, shard or
+//! proof. Such data is rejected like an unknown version.
+
+use alloc::vec::Vec;
+use molecule::prelude::Entity;
+
+/// The first data layout. Vault and shard data name the proof script and the
+/// vesting lock by code hash alone, and pin no entity registry.
+pub const DATA_VERSION_V1: u8 = 1;
+
+/// The data layout described by the schemas in this release, which pins the
+/// hash types of the proof script and the vesting lock, and the entity
+/// registry and creator of the campaign.
+pub const DATA_VERSION_V2: u8 = 2;
+
+/// The version new cells must be created with.
+pub const LATEST_DATA_VERSION: u8 = DATA_VERSION_V2;
+
+/// The hash type a version 1 code hash is read with. The platform scripts are
+/// deployed behind a Type ID.
+#[cfg(any(feature = "vault", feature = "distribution"))]
+const V1_HASH_TYPE: u8 = 1;
+
+/// Splits cell data into its version byte and the molecule data behind it.
+pub fn split_version(data: &[u8]) -> Option<(u8, &[u8])> {
+    data.split_first()
+        .map(|(version, payload)| (*version, payload))
+}
+
+/// Serializes `entity` with the latest version byte in front of it.
+pub fn to_versioned_bytes<T: Entity>(entity: &T) -> Vec<u8> {
+    let mut data = Vec::with_capacity(entity.as_slice().len() + 1);
+    data.push(LATEST_DATA_VERSION);
+    data.extend_from_slice(entity.as_slice());
+    data
+}
+
+/// Parses versioned `VaultCellData`. Version 1 data is converted, see
+/// [`vault_data_from_v1`]. Unknown versions are rejected.
+#[cfg(feature = "vault")]
+pub fn parse_vault_data(data: &[u8]) -> Option<crate::schema::vault::VaultCellData> {
+    use crate::schema::vault::{VaultCellData, VaultCellDataV1};
+
+    match split_version(data)? {
+        (DATA_VERSION_V1, payload) => VaultCellDataV1::from_slice(payload)
+            .ok()
+            .map(|data| vault_data_from_v1(&data)),
+        (DATA_VERSION_V2, payload) => VaultCellData::from_slice(payload).ok(),
+        _ => None,
+    }
+}
+
+/// Parses versioned `DistributionCellData`. Version 1 data is converted, see
+/// [`distribution_data_from_v1`]. Unknown versions are rejected.
+#[cfg(feature = "distribution")]
+pub fn parse_distribution_data(
+    data: &[u8],
+) -> Option<crate::schema::distribution::DistributionCellData> {
+    use crate::schema::distribution::{DistributionCellData, DistributionCellDataV1};
+
+    match split_version(data)? {
+        (DATA_VERSION_V1, payload) => DistributionCellDataV1::from_slice(payload)
+            .ok()
+            .map(|data| distribution_data_from_v1(&data)),
+        (DATA_VERSION_V2, payload) => DistributionCellData::from_slice(payload).ok(),
+        _ => None,
+    }
+}
+
+/// Converts version 1 `VaultCellData`. The proof script and the vesting lock
+/// are read behind a Type ID, and the entity registry and creator are left
+/// null, so the campaign keeps accepting proofs from any registry.
+#[cfg(feature = "vault")]
+pub fn vault_data_from_v1(
+    data: &crate::schema::vault::VaultCellDataV1,
+) -> crate::schema::vault::VaultCellData {
+    use molecule::prelude::Builder;
+
+    crate::schema::vault::VaultCellData::new_builder()
+        .campaign_id(data.campaign_id())
+        .fee_percentage(data.fee_percentage())
+        .proof_script_code_hash(data.proof_script_code_hash())
+        .proof_script_hash_type(V1_HASH_TYPE.into())
+        .funders(data.funders())
+        .fee_schedule(data.fee_schedule())
+        .fee_model(data.fee_model())
+        .vesting(vesting_config_from_v1(&data.vesting()))
+        .entity_root(data.entity_root())
+        .entity_ids(data.entity_ids())
+        .build()
+}
+
+/// Converts version 1 `DistributionCellData` the same way as
+/// [`vault_data_from_v1`].
+#[cfg(feature = "distribution")]
+pub fn distribution_data_from_v1(
+    data: &crate::schema::distribution::DistributionCellDataV1,
+) -> crate::schema::distribution::DistributionCellData {
+    use molecule::prelude::Builder;
+
+    crate::schema::distribution::DistributionCellData::new_builder()
+        .campaign_id(data.campaign_id())
+        .admin_lock_hash(data.admin_lock_hash())
+        .merkle_root(data.merkle_root())
+        .proof_script_code_hash(data.proof_script_code_hash())
+        .proof_script_hash_type(V1_HASH_TYPE.into())
+        .uniform_reward_amount(data.uniform_reward_amount())
+        .deadline(data.deadline())
+        .remaining_claims(data.remaining_claims())
+        .vesting(vesting_config_from_v1(&data.vesting()))
+        .referral_budget(data.referral_budget())
+        .entity_root(data.entity_root())
+        .build()
+}
+
+/// Converts a version 1 `VestingConfig`. A null config stays null, with a zero
+/// hash type.
+#[cfg(any(feature = "vault", feature = "distribution"))]
+fn vesting_config_from_v1(config: &crate::base::VestingConfigV1) -> crate::base::VestingConfig {
+    use molecule::prelude::Builder;
+
+    let lock_hash_type = if config.lock_code_hash().as_slice() == crate::NULL_HASH {
+        0
+    } else {
+        V1_HASH_TYPE
+    };
+    crate::base::VestingConfig::new_builder()
+        .lock_code_hash(config.lock_code_hash())
+        .lock_hash_type(lock_hash_type.into())
+        .start_epoch(config.start_epoch())
+        .end_epoch(config.end_epoch())
+        .build()
+}
+
+/// Parses versioned `ProofCellData`. Unknown versions are rejected.
+#[cfg(feature = "proof")]
+pub fn parse_proof_data(data: &[u8]) -> Option<crate::schema::proof::ProofCellData> {
+    match split_version(data)? {
+        (DATA_VERSION_V1 | DATA_VERSION_V2, payload) => {
+            crate::schema::proof::ProofCellData::from_slice(payload).ok()
+        }
+        _ => None,
+    }
+}
+
+#[cfg(all(test, feature = "vault"))]
+mod tests {
+    use molecule::prelude::Builder;
+
+    use super::*;
+    use crate::{
+        base::{Byte32, VestingConfigV1},
+        schema::vault::VaultCellDataV1,
+    };
+
+    fn versioned(version: u8, payload: &[u8]) -> Vec<u8> {
+        let mut data = alloc::vec![version];
+        data.extend_from_slice(payload);
+        data
+    }
+
+    #[test]
+    fn parses_version_1_vault_data_with_legacy_rules() {
+        let vesting = VestingConfigV1::new_builder()
+            .lock_code_hash(Byte32::new_unchecked([3u8; 32].to_vec().into()))
+            .build();
+        let v1 = VaultCellDataV1::new_builder()
+            .proof_script_code_hash(Byte32::new_unchecked([2u8; 32].to_vec().into()))
+            .vesting(vesting)
+            .build();
+
+        let data = parse_vault_data(&versioned(DATA_VERSION_V1, v1.as_slice())).unwrap();
+        assert_eq!(data.proof_script_code_hash().as_slice(), [2u8; 32]);
+        assert_eq!(u8::from(data.proof_script_hash_type()), V1_HASH_TYPE);
+        assert_eq!(data.vesting().lock_code_hash().as_slice(), [3u8; 32]);
+        assert_eq!(u8::from(data.vesting().lock_hash_type()), V1_HASH_TYPE);
+        assert_eq!(
+            data.entity_registry().code_hash().as_slice(),
+            crate::NULL_HASH
+        );
+        assert_eq!(data.entity_creator_lock_hash().as_slice(), crate::NULL_HASH);
+
+        // The same bytes are not a version 2 layout.
+        assert!(parse_vault_data(&versioned(DATA_VERSION_V2, v1.as_slice())).is_none());
+    }
+
+    #[test]
+    fn parses_the_latest_vault_data_as_written() {
+        let data = crate::schema::vault::VaultCellData::default();
+        assert_eq!(
+            parse_vault_data(&to_versioned_bytes(&data))
+                .unwrap()
+                .as_slice(),
+            data.as_slice()
+        );
+        assert!(parse_vault_data(&versioned(3, data.as_slice())).is_none());
+    }
+}
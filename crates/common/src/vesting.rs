@@ -0,0 +1,112 @@
+//! The release schedule of `vesting-lock`.
+//!
+//! `vesting-lock` enforces it and the SDK builds withdrawals with the same
+//! routines, so a relock the SDK builds is one the chain accepts.
+
+use ckb_std::ckb_types::prelude::{Pack, Unpack};
+use molecule::prelude::{Builder, Entity};
+
+use crate::schema::vesting::VestingLockArgs;
+
+/// Returns the part of `locked_amount` that has not vested at `current_epoch`.
+pub fn unvested_amount(
+    locked_amount: u64,
+    start_epoch: u64,
+    end_epoch: u64,
+    current_epoch: u64,
+) -> u64 {
+    if current_epoch <= start_epoch {
+        locked_amount
+    } else if current_epoch >= end_epoch {
+        0
+    } else {
+        // Linear release, rounded down in favor of the beneficiary.
+        (locked_amount as u128 * (end_epoch - current_epoch) as u128
+            / (end_epoch - start_epoch) as u128) as u64
+    }
+}
+
+/// Returns the amount of the vesting cell locked by `args` that has not vested
+/// at `current_epoch`.
+pub fn unvested_amount_of(args: &VestingLockArgs, current_epoch: u64) -> u64 {
+    unvested_amount(
+        args.locked_amount().unpack(),
+        args.start_epoch().unpack(),
+        args.end_epoch().unpack(),
+        current_epoch,
+    )
+}
+
+/// Returns the earliest start epoch of a relock made at `current_epoch` from
+/// vesting cells whose latest start epoch is `start_epoch`.
+///
+/// Restarting the linear release there, with the unvested amount as locked
+/// amount, releases nothing earlier than the original schedule would.
+pub fn relock_start_epoch(start_epoch: u64, current_epoch: u64) -> u64 {
+    start_epoch.max(current_epoch)
+}
+
+/// Returns the args of a relock of `locked_amount`, releasing to the same
+/// beneficiary by the same end epoch as `args` from `start_epoch`.
+pub fn relock_args(
+    args: &VestingLockArgs,
+    locked_amount: u64,
+    start_epoch: u64,
+) -> VestingLockArgs {
+    args.clone()
+        .as_builder()
+        .locked_amount(locked_amount.pack())
+        .start_epoch(start_epoch.pack())
+        .build()
+}
+
+/// Checks whether the vesting cells locked by `args` and `other` belong to the
+/// same position: they release to the same beneficiary by the same end epoch,
+/// so their unvested amounts can be relocked together.
+pub fn same_position(args: &VestingLockArgs, other: &VestingLockArgs) -> bool {
+    args.beneficiary_lock_hash().as_slice() == other.beneficiary_lock_hash().as_slice()
+        && args.end_epoch().as_slice() == other.end_epoch().as_slice()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(locked_amount: u64, start_epoch: u64, end_epoch: u64) -> VestingLockArgs {
+        VestingLockArgs::new_builder()
+            .locked_amount(locked_amount.pack())
+            .start_epoch(start_epoch.pack())
+            .end_epoch(end_epoch.pack())
+            .build()
+    }
+
+    #[test]
+    fn relock_releases_no_earlier_than_the_original_schedule() {
+        let original = args(1000, 10, 20);
+        for relock_epoch in 0..20 {
+            let start_epoch = relock_start_epoch(10, relock_epoch);
+            let relock = relock_args(
+                &original,
+                unvested_amount_of(&original, relock_epoch),
+                start_epoch,
+            );
+            for epoch in relock_epoch..=20 {
+                assert!(
+                    unvested_amount_of(&relock, epoch) >= unvested_amount_of(&original, epoch),
+                    "relock at {} releases early at {}",
+                    relock_epoch,
+                    epoch
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn unvested_amount_is_linear_between_the_epochs() {
+        assert_eq!(unvested_amount(1000, 10, 20, 5), 1000);
+        assert_eq!(unvested_amount(1000, 10, 20, 10), 1000);
+        assert_eq!(unvested_amount(1000, 10, 20, 15), 500);
+        assert_eq!(unvested_amount(1000, 10, 20, 20), 0);
+        assert_eq!(unvested_amount(1000, 10, 20, 25), 0);
+    }
+}
@@ -0,0 +1,51 @@
+use std::error::Error;
+
+use ckb_types::{prelude::*, H256};
+use polymeer_sdk::bundle::{bundle_leaf, shard_bundles, shard_out_point, ClaimBundle};
+
+use crate::plan::Plan;
+
+/// Builds the claim bundle of every claimant of a plan whose fan-out was
+/// committed in `fan_out_tx_hash`. The fan-out creates the shards as its first
+/// outputs, in the order of the plan.
+pub fn build_bundles(
+    plan: &Plan,
+    campaign_id: &[u8; 32],
+    fan_out_tx_hash: &H256,
+) -> Result<Vec<ClaimBundle>, Box<dyn Error>> {
+    let mut bundles = Vec::new();
+    for (shard_index, shard) in plan.shards.iter().enumerate() {
+        let claimants = shard
+            .claimants
+            .iter()
+            .map(|planned| {
+                planned.claimant.bundle_claimant().ok_or_else(|| {
+                    format!(
+                        "claimant {:#x} has no proof out point",
+                        planned.claimant.proof_type_id
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let out_point = shard_out_point(&fan_out_tx_hash.0, shard_index as u32);
+        for (bundle, planned) in shard_bundles(campaign_id, &out_point, &claimants)
+            .into_iter()
+            .zip(shard.claimants.iter())
+        {
+            // The bundles rebuild the shard's tree, which must be the one the
+            // fan-out committed.
+            if bundle_leaf(&bundle) != planned.leaf.0
+                || bundle.merkle_root().as_slice() != shard.merkle_root.as_bytes()
+            {
+                return Err(format!(
+                    "shard {} does not match the plan's Merkle root",
+                    shard_index
+                )
+                .into());
+            }
+            bundles.push(bundle);
+        }
+    }
+    Ok(bundles)
+}
@@ -0,0 +1,99 @@
+use std::{error::Error, fs, path::Path};
+
+use ckb_types::H256;
+use ckb_types::{packed::OutPoint, prelude::*};
+use polymeer_sdk::{
+    bundle::BundleClaimant,
+    merkle::{claimant_leaf, referral},
+};
+use serde::{Deserialize, Serialize};
+
+/// A claimant of a campaign, as verified by the backend.
+///
+/// `lock_hash` is the subscriber's lock hash, or the hash of its secret for a
+/// private claim. A referral is committed in the leaf when both referral
+/// fields are set. The out point of the claimant's proof cell is optional for
+/// planning, and required to generate its claim bundle.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Claimant {
+    pub proof_type_id: H256,
+    pub lock_hash: H256,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub referrer_lock_hash: Option<H256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bonus_amount: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof_tx_hash: Option<H256>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof_index: Option<u32>,
+}
+
+impl Claimant {
+    /// Returns the referral bonus drawn from the shard's budget.
+    pub fn bonus(&self) -> u64 {
+        match self.referrer_lock_hash {
+            Some(_) => self.bonus_amount.unwrap_or(0),
+            None => 0,
+        }
+    }
+
+    /// Returns the Merkle leaf committing to this claimant.
+    pub fn leaf(&self) -> [u8; 32] {
+        let referral = self
+            .referrer_lock_hash
+            .as_ref()
+            .map(|referrer| referral(&referrer.0, self.bonus()));
+        claimant_leaf(&self.proof_type_id.0, &self.lock_hash.0, referral.as_ref())
+    }
+
+    /// Returns this claimant as the SDK expects it for a claim bundle, or
+    /// `None` without the out point of its proof cell.
+    pub fn bundle_claimant(&self) -> Option<BundleClaimant> {
+        let proof_out_point = OutPoint::new_builder()
+            .tx_hash(self.proof_tx_hash.as_ref()?.pack())
+            .index(self.proof_index?.pack())
+            .build();
+        Some(BundleClaimant {
+            proof_type_id: self.proof_type_id.0,
+            subscriber_lock_hash: self.lock_hash.0,
+            referral: self
+                .referrer_lock_hash
+                .as_ref()
+                .map(|referrer| referral(&referrer.0, self.bonus())),
+            proof_out_point,
+        })
+    }
+}
+
+/// Reads claimants from a `.json` array or a `.csv` file with a header row.
+pub fn load_claimants(path: &Path) -> Result<Vec<Claimant>, Box<dyn Error>> {
+    let claimants: Vec<Claimant> = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => serde_json::from_str(&fs::read_to_string(path)?)?,
+        Some("csv") => csv::Reader::from_path(path)?
+            .deserialize()
+            .collect::<Result<_, _>>()?,
+        _ => return Err(format!("unsupported claimants file {}", path.display()).into()),
+    };
+
+    if claimants.is_empty() {
+        return Err("the claimants file is empty".into());
+    }
+    for claimant in claimants.iter() {
+        if claimant.referrer_lock_hash.is_some() != claimant.bonus_amount.is_some() {
+            return Err(format!(
+                "claimant {:#x} must set both referral fields or neither",
+                claimant.proof_type_id
+            )
+            .into());
+        }
+        if claimant.proof_tx_hash.is_some() != claimant.proof_index.is_some() {
+            return Err(format!(
+                "claimant {:#x} must set both proof out point fields or neither",
+                claimant.proof_type_id
+            )
+            .into());
+        }
+    }
+
+    Ok(claimants)
+}
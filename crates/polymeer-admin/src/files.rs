@@ -0,0 +1,115 @@
+//! The JSON files the CLI reads and writes.
+
+use std::{error::Error, fs, path::Path};
+
+use ckb_jsonrpc_types::{CellDep, CellOutput, JsonBytes, OutPoint, Script, Transaction};
+use ckb_types::{core::TransactionView, packed, prelude::*, H256};
+use polymeer_sdk::{DeployedScript, Deployment, LiveCell};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// A deployed contract, in the format of the RPC types.
+#[derive(Debug, Deserialize)]
+pub struct DeployedScriptFile {
+    pub code_hash: H256,
+    pub hash_type: ckb_jsonrpc_types::ScriptHashType,
+    pub cell_dep: CellDep,
+}
+
+/// The platform's contracts on the target chain.
+#[derive(Debug, Deserialize)]
+pub struct DeploymentFile {
+    pub vault_lock: DeployedScriptFile,
+    pub vault_type: DeployedScriptFile,
+    pub distribution_lock: DeployedScriptFile,
+    pub distribution_type: DeployedScriptFile,
+    pub proof_type: DeployedScriptFile,
+    pub vesting_lock: DeployedScriptFile,
+    pub entity_type: DeployedScriptFile,
+    pub proof_lock: DeployedScriptFile,
+}
+
+/// A live cell, as returned by `get_live_cell` with its data and out point.
+#[derive(Debug, Deserialize)]
+pub struct LiveCellFile {
+    pub out_point: OutPoint,
+    pub output: CellOutput,
+    pub data: JsonBytes,
+}
+
+/// An unsigned transaction, in the layout `ckb-cli tx` and CCC read.
+#[derive(Debug, Serialize)]
+pub struct TransactionFile {
+    pub transaction: Transaction,
+    pub multisig_configs: serde_json::Map<String, serde_json::Value>,
+    pub signatures: serde_json::Map<String, serde_json::Value>,
+}
+
+impl From<DeployedScriptFile> for DeployedScript {
+    fn from(file: DeployedScriptFile) -> Self {
+        DeployedScript::new(
+            file.code_hash.pack(),
+            file.hash_type.into(),
+            file.cell_dep.into(),
+        )
+    }
+}
+
+impl From<DeploymentFile> for Deployment {
+    fn from(file: DeploymentFile) -> Self {
+        Deployment {
+            vault_lock: file.vault_lock.into(),
+            vault_type: file.vault_type.into(),
+            distribution_lock: file.distribution_lock.into(),
+            distribution_type: file.distribution_type.into(),
+            proof_type: file.proof_type.into(),
+            vesting_lock: file.vesting_lock.into(),
+            entity_type: file.entity_type.into(),
+            proof_lock: file.proof_lock.into(),
+        }
+    }
+}
+
+impl From<LiveCellFile> for LiveCell {
+    fn from(file: LiveCellFile) -> Self {
+        LiveCell::new(
+            file.out_point.into(),
+            file.output.into(),
+            file.data.into_bytes(),
+        )
+    }
+}
+
+impl From<TransactionView> for TransactionFile {
+    fn from(tx: TransactionView) -> Self {
+        TransactionFile {
+            transaction: tx.data().into(),
+            multisig_configs: Default::default(),
+            signatures: Default::default(),
+        }
+    }
+}
+
+pub fn read_json<T: DeserializeOwned>(path: &Path) -> Result<T, Box<dyn Error>> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+    serde_json::from_str(&content)
+        .map_err(|err| format!("cannot parse {}: {}", path.display(), err).into())
+}
+
+/// Writes `value` as pretty JSON to `path`, or to stdout without a path.
+pub fn write_json<T: Serialize>(path: Option<&Path>, value: &T) -> Result<(), Box<dyn Error>> {
+    let json = serde_json::to_string_pretty(value)?;
+    match path {
+        Some(path) => fs::write(path, json + "\n")?,
+        None => println!("{}", json),
+    }
+    Ok(())
+}
+
+/// Parses a script given inline as RPC JSON, such as
+/// `{"code_hash":"0x..","hash_type":"type","args":"0x.."}`.
+pub fn parse_script(json: &str) -> Result<packed::Script, String> {
+    serde_json::from_str::<Script>(json)
+        .map(Into::into)
+        .map_err(|err| format!("invalid script: {}", err))
+}
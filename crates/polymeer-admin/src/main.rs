@@ -0,0 +1,352 @@
+//! Offline admin tooling for the campaign lifecycle.
+//!
+//! Every command reads JSON or CSV files and writes JSON, so the CLI never
+//! talks to a node: live cells are exported beforehand, and the unsigned
+//! transactions it emits are balanced and signed with `ckb-cli` or CCC.
+
+mod bundles;
+mod claimants;
+mod files;
+mod plan;
+
+use std::{error::Error, fs, path::PathBuf};
+
+use ckb_types::{packed::Script, H256};
+use clap::{Parser, Subcommand};
+use common::version::{parse_distribution_data, parse_vault_data};
+use polymeer_sdk::{
+    bundle::{to_out_point, verify_bundle, ClaimBundle, ClaimBundleJson},
+    planner::{
+        reward_occupied_capacity, shard_occupied_capacity, DEFAULT_PAYOUT_OCCUPIED_CAPACITY,
+    },
+    Deployment, FanOutBuilder, LiveCell, ReclaimBuilder, RefundBuilder, ShardPlanner, ShardTarget,
+};
+
+use crate::{
+    bundles::build_bundles,
+    claimants::load_claimants,
+    files::{parse_script, read_json, write_json, DeploymentFile, LiveCellFile, TransactionFile},
+    plan::{build_plan, Plan},
+};
+
+#[derive(Parser)]
+#[command(
+    name = "polymeer-admin",
+    version,
+    about = "Admin tooling for Polymeer campaigns"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Partition claimants into shards and build their Merkle trees and proofs.
+    Plan {
+        #[arg(long)]
+        deployment: PathBuf,
+        /// The live vault cell to distribute.
+        #[arg(long)]
+        vault: PathBuf,
+        /// The claimants, as a `.csv` file with a header row or a `.json` array.
+        #[arg(long)]
+        claimants: PathBuf,
+        /// The number of shards to create.
+        #[arg(
+            long,
+            required_unless_present = "claims_per_shard",
+            conflicts_with = "claims_per_shard"
+        )]
+        shards: Option<usize>,
+        /// The maximum number of claimants per shard, bounding the contention
+        /// on every shard.
+        #[arg(long)]
+        claims_per_shard: Option<usize>,
+        /// The capacity a payout cell occupies, in Shannons, which every
+        /// reward, referral bonus and remainder must cover. Defaults to a cell
+        /// with the secp256k1-blake160 lock.
+        #[arg(long, default_value_t = DEFAULT_PAYOUT_OCCUPIED_CAPACITY)]
+        payout_occupied_capacity: u64,
+        /// Where to write the plan. Defaults to stdout.
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Build the unsigned fan-out of a vault into the shards of a plan.
+    FanOut {
+        #[arg(long)]
+        deployment: PathBuf,
+        /// The live vault cell.
+        #[arg(long)]
+        vault: PathBuf,
+        #[arg(long)]
+        plan: PathBuf,
+        /// The timestamp, in seconds, after which shards can be reclaimed.
+        #[arg(long)]
+        deadline: u64,
+        /// The lock script of a fee beneficiary, or of the admin, as RPC JSON.
+        #[arg(long = "fee-lock", value_parser = parse_script)]
+        fee_locks: Vec<Script>,
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Write the claim bundle of every claimant of a committed fan-out, as JSON
+    /// files named after the claimants' proof Type IDs.
+    Bundles {
+        /// The vault cell the fan-out consumed.
+        #[arg(long)]
+        vault: PathBuf,
+        /// The plan the fan-out was built from. Every claimant must have the
+        /// out point of its proof cell.
+        #[arg(long)]
+        plan: PathBuf,
+        /// The hash of the committed fan-out transaction.
+        #[arg(long, value_parser = parse_hash)]
+        fan_out_tx_hash: H256,
+        #[arg(long)]
+        out_dir: PathBuf,
+    },
+    /// Check a claim bundle against the data of its shard.
+    VerifyBundle {
+        #[arg(long)]
+        bundle: PathBuf,
+        /// The live shard cell.
+        #[arg(long)]
+        shard: PathBuf,
+    },
+    /// Build the unsigned reclamation of a shard after its deadline.
+    Reclaim {
+        #[arg(long)]
+        deployment: PathBuf,
+        /// The live shard cell.
+        #[arg(long)]
+        shard: PathBuf,
+        /// The admin's lock script, as RPC JSON.
+        #[arg(long, value_parser = parse_script)]
+        admin_lock: Script,
+        /// The timestamp, in seconds, the shard is locked until. Defaults to
+        /// the shard deadline.
+        #[arg(long)]
+        since_timestamp: Option<u64>,
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Build the unsigned refund of a vault to its funders.
+    Refund {
+        #[arg(long)]
+        deployment: PathBuf,
+        /// The live vault cell.
+        #[arg(long)]
+        vault: PathBuf,
+        /// The lock script of a funder to be paid, as RPC JSON.
+        #[arg(long = "funder-lock", value_parser = parse_script)]
+        funder_locks: Vec<Script>,
+        /// Refund only `<lock_hash>:<amount>` and keep the vault. Repeatable.
+        #[arg(long, value_parser = parse_partial)]
+        partial: Vec<(H256, u64)>,
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+}
+
+fn main() {
+    if let Err(err) = run(Cli::parse()) {
+        eprintln!("error: {}", err);
+        std::process::exit(1);
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    match cli.command {
+        Command::Plan {
+            deployment,
+            vault,
+            claimants,
+            shards,
+            claims_per_shard,
+            payout_occupied_capacity,
+            out,
+        } => {
+            let deployment: Deployment = read_json::<DeploymentFile>(&deployment)?.into();
+            let vault: LiveCell = read_json::<LiveCellFile>(&vault)?.into();
+            let claimants = load_claimants(&claimants)?;
+
+            let target = match (shards, claims_per_shard) {
+                (Some(count), _) => ShardTarget::Count(count),
+                (None, Some(claims)) => ShardTarget::ClaimsPerShard(claims),
+                (None, None) => unreachable!("enforced by clap"),
+            };
+            let reward_occupied_capacity =
+                reward_occupied_capacity(&deployment, &vault, payout_occupied_capacity)?;
+            let planner = ShardPlanner::new(
+                vault.capacity(),
+                FanOutBuilder::new(deployment.clone(), vault.clone())?.fee()?,
+                target,
+            )
+            .shard_occupied_capacity(shard_occupied_capacity(&deployment))
+            .reward_occupied_capacity(reward_occupied_capacity)
+            .payout_occupied_capacity(payout_occupied_capacity);
+
+            write_json(out.as_deref(), &build_plan(&claimants, &planner)?)
+        }
+        Command::FanOut {
+            deployment,
+            vault,
+            plan,
+            deadline,
+            fee_locks,
+            out,
+        } => {
+            let deployment: Deployment = read_json::<DeploymentFile>(&deployment)?.into();
+            let vault: LiveCell = read_json::<LiveCellFile>(&vault)?.into();
+            let plan: Plan = read_json(&plan)?;
+
+            let mut builder = FanOutBuilder::new(deployment, vault)?
+                .uniform_reward_amount(plan.uniform_reward_amount)
+                .deadline(deadline);
+            for lock in fee_locks {
+                builder = builder.fee_lock(lock);
+            }
+            for shard in plan.shards.iter() {
+                builder = builder.shard_with_referral_budget(
+                    shard.merkle_root.0,
+                    shard.claims,
+                    shard.referral_budget,
+                );
+            }
+
+            write_json(out.as_deref(), &TransactionFile::from(builder.build()?))
+        }
+        Command::Bundles {
+            vault,
+            plan,
+            fan_out_tx_hash,
+            out_dir,
+        } => {
+            let vault: LiveCell = read_json::<LiveCellFile>(&vault)?.into();
+            let plan: Plan = read_json(&plan)?;
+            let vault_data = parse_vault_data(&vault.data).ok_or("invalid vault cell data")?;
+
+            let bundles = build_bundles(&plan, &vault_data.campaign_id().into(), &fan_out_tx_hash)?;
+            fs::create_dir_all(&out_dir)?;
+            for bundle in bundles.iter() {
+                let path = out_dir.join(format!("{:x}.json", bundle.proof_type_id()));
+                write_json(Some(&path), &ClaimBundleJson::from(bundle))?;
+            }
+            eprintln!("wrote {} bundles to {}", bundles.len(), out_dir.display());
+            Ok(())
+        }
+        Command::VerifyBundle { bundle, shard } => {
+            let bundle = ClaimBundle::try_from(&read_json::<ClaimBundleJson>(&bundle)?)
+                .map_err(|err| format!("invalid bundle: {:?}", err))?;
+            let shard: LiveCell = read_json::<LiveCellFile>(&shard)?.into();
+            let shard_data =
+                parse_distribution_data(&shard.data).ok_or("invalid shard cell data")?;
+
+            verify_bundle(&bundle, &shard_data)
+                .map_err(|err| format!("bundle rejected: {:?}", err))?;
+            // Claims recreate the shard, so a bundle stays valid for every
+            // shard cell that descends from the one it names.
+            if to_out_point(&bundle.shard_out_point()) != shard.out_point {
+                eprintln!("note: the shard has moved since the bundle was generated");
+            }
+            println!("ok");
+            Ok(())
+        }
+        Command::Reclaim {
+            deployment,
+            shard,
+            admin_lock,
+            since_timestamp,
+            out,
+        } => {
+            let deployment: Deployment = read_json::<DeploymentFile>(&deployment)?.into();
+            let shard: LiveCell = read_json::<LiveCellFile>(&shard)?.into();
+
+            let mut builder = ReclaimBuilder::new(deployment, shard, admin_lock)?;
+            if let Some(since_timestamp) = since_timestamp {
+                builder = builder.since_timestamp(since_timestamp);
+            }
+
+            write_json(out.as_deref(), &TransactionFile::from(builder.build()?))
+        }
+        Command::Refund {
+            deployment,
+            vault,
+            funder_locks,
+            partial,
+            out,
+        } => {
+            let deployment: Deployment = read_json::<DeploymentFile>(&deployment)?.into();
+            let vault: LiveCell = read_json::<LiveCellFile>(&vault)?.into();
+
+            let mut builder = RefundBuilder::new(deployment, vault)?;
+            for lock in funder_locks {
+                builder = builder.funder_lock(lock);
+            }
+            for (lock_hash, amount) in partial {
+                builder = builder.partial(lock_hash.0, amount);
+            }
+
+            write_json(out.as_deref(), &TransactionFile::from(builder.build()?))
+        }
+    }
+}
+
+fn parse_hash(value: &str) -> Result<H256, String> {
+    value
+        .trim_start_matches("0x")
+        .parse::<H256>()
+        .map_err(|err| format!("invalid hash: {}", err))
+}
+
+fn parse_partial(value: &str) -> Result<(H256, u64), String> {
+    let (lock_hash, amount) = value
+        .split_once(':')
+        .ok_or("expected <lock_hash>:<amount>")?;
+    let lock_hash = parse_hash(lock_hash)?;
+    let amount = amount
+        .parse::<u64>()
+        .map_err(|err| format!("invalid amount: {}", err))?;
+    Ok((lock_hash, amount))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_plan(target: &[&str]) -> Result<Command, clap::Error> {
+        let args = [
+            "polymeer-admin",
+            "plan",
+            "--deployment",
+            "deployment.json",
+            "--vault",
+            "vault.json",
+            "--claimants",
+            "claimants.csv",
+        ];
+        Cli::try_parse_from(args.iter().chain(target.iter())).map(|cli| cli.command)
+    }
+
+    #[test]
+    fn plan_takes_exactly_one_shard_target() {
+        match parse_plan(&["--shards", "3"]).unwrap() {
+            Command::Plan {
+                shards,
+                claims_per_shard,
+                payout_occupied_capacity,
+                ..
+            } => {
+                assert_eq!(shards, Some(3));
+                assert_eq!(claims_per_shard, None);
+                assert_eq!(payout_occupied_capacity, DEFAULT_PAYOUT_OCCUPIED_CAPACITY);
+            }
+            _ => panic!("expected the plan command"),
+        }
+        assert!(parse_plan(&["--claims-per-shard", "100"]).is_ok());
+
+        assert!(parse_plan(&[]).is_err());
+        assert!(parse_plan(&["--shards", "3", "--claims-per-shard", "100"]).is_err());
+    }
+}
@@ -0,0 +1,176 @@
+use std::{collections::HashSet, error::Error};
+
+use ckb_types::H256;
+use polymeer_sdk::{merkle::merkle_proof, planner::PlannedLeaf, ShardPlanner};
+use serde::{Deserialize, Serialize};
+
+use crate::claimants::Claimant;
+
+/// The claimants of a campaign partitioned into shards, with everything the
+/// fan-out and the claimants need.
+#[derive(Debug, Deserialize, Serialize)]
+pub struct Plan {
+    pub uniform_reward_amount: u64,
+    pub fee: u64,
+    /// The capacity left over by the even split, returned to the admin with
+    /// the last claim of the first shard.
+    pub remainder: u64,
+    pub shards: Vec<PlannedShard>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PlannedShard {
+    pub merkle_root: H256,
+    pub claims: u32,
+    pub capacity: u64,
+    /// The referral bonuses committed in the shard's leaves, plus the
+    /// remainder for the first shard.
+    pub referral_budget: u64,
+    pub claimants: Vec<PlannedClaim>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct PlannedClaim {
+    #[serde(flatten)]
+    pub claimant: Claimant,
+    pub leaf: H256,
+    pub merkle_proof: Vec<H256>,
+}
+
+/// Partitions the claimants with `planner` and attaches every claimant's Merkle
+/// proof.
+pub fn build_plan(claimants: &[Claimant], planner: &ShardPlanner) -> Result<Plan, Box<dyn Error>> {
+    // A proof can claim a campaign only once, so a duplicate would lock up a
+    // reward nobody can claim.
+    let mut proof_type_ids = HashSet::new();
+    for claimant in claimants.iter() {
+        if !proof_type_ids.insert(claimant.proof_type_id.clone()) {
+            return Err(format!("duplicate claimant {:#x}", claimant.proof_type_id).into());
+        }
+    }
+
+    let leaves: Vec<PlannedLeaf> = claimants
+        .iter()
+        .map(|claimant| PlannedLeaf {
+            leaf: claimant.leaf(),
+            bonus_amount: claimant.bonus(),
+        })
+        .collect();
+    let shard_plan = planner.plan(&leaves)?;
+
+    let shards = shard_plan
+        .shards
+        .iter()
+        .map(|shard| PlannedShard {
+            merkle_root: H256(shard.merkle_root),
+            claims: shard.claims(),
+            capacity: shard.capacity,
+            referral_budget: shard.referral_budget,
+            claimants: shard
+                .claimants
+                .iter()
+                .enumerate()
+                .map(|(position, &index)| PlannedClaim {
+                    claimant: claimants[index].clone(),
+                    leaf: H256(shard.leaves[position]),
+                    merkle_proof: merkle_proof(&shard.leaves, position)
+                        .into_iter()
+                        .map(H256)
+                        .collect(),
+                })
+                .collect(),
+        })
+        .collect();
+
+    Ok(Plan {
+        uniform_reward_amount: shard_plan.uniform_reward_amount,
+        fee: shard_plan.fee,
+        remainder: shard_plan.remainder,
+        shards,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use ckb_types::prelude::{Builder, Entity};
+    use common::{
+        base::{Byte32, Byte32Vec},
+        merkle::verify_merkle_proof,
+    };
+    use polymeer_sdk::ShardTarget;
+
+    use super::*;
+
+    fn claimant(i: u8, bonus_amount: Option<u64>) -> Claimant {
+        Claimant {
+            proof_type_id: H256([i; 32]),
+            lock_hash: H256([i + 100; 32]),
+            referrer_lock_hash: bonus_amount.map(|_| H256([200; 32])),
+            bonus_amount,
+            proof_tx_hash: None,
+            proof_index: None,
+        }
+    }
+
+    #[test]
+    fn plan_places_the_remainder_and_proves_every_claimant() {
+        let claimants: Vec<Claimant> = (1..=5)
+            .map(|i| claimant(i, (i == 2).then_some(100)))
+            .collect();
+        let planner = ShardPlanner::new(10_000, 7, ShardTarget::Count(2));
+        let plan = build_plan(&claimants, &planner).unwrap();
+
+        // 10000 - 7 - 100 = 9893 = 5 * 1978 + 3
+        assert_eq!(plan.uniform_reward_amount, 1978);
+        assert_eq!(plan.fee, 7);
+        assert_eq!(plan.remainder, 3);
+        assert_eq!(
+            plan.shards.iter().map(|s| s.claims).collect::<Vec<_>>(),
+            vec![3, 2]
+        );
+        let bonuses: u64 = plan.shards[0]
+            .claimants
+            .iter()
+            .map(|claim| claim.claimant.bonus())
+            .sum();
+        assert_eq!(plan.shards[0].referral_budget, bonuses + 3);
+        assert_eq!(
+            plan.shards.iter().map(|s| s.capacity).sum::<u64>() + plan.fee,
+            10_000
+        );
+
+        for shard in plan.shards.iter() {
+            for claim in shard.claimants.iter() {
+                assert_eq!(claim.leaf.0, claim.claimant.leaf());
+                let proof = Byte32Vec::new_builder()
+                    .extend(claim.merkle_proof.iter().map(|hash| Byte32::from(hash.0)))
+                    .build();
+                assert!(verify_merkle_proof(
+                    &shard.merkle_root.0,
+                    claim.leaf.0,
+                    &proof
+                ));
+            }
+        }
+    }
+
+    #[test]
+    fn plan_rejects_duplicate_claimants() {
+        let claimants = vec![claimant(1, None), claimant(2, None), claimant(1, Some(100))];
+        let planner = ShardPlanner::new(10_000, 0, ShardTarget::Count(1));
+        let err = build_plan(&claimants, &planner).unwrap_err();
+        assert!(err.to_string().starts_with("duplicate claimant"));
+    }
+
+    #[test]
+    fn plan_rejects_infeasible_plans() {
+        let claimants = vec![claimant(1, Some(10)), claimant(2, None)];
+        let planner =
+            ShardPlanner::new(10_000, 0, ShardTarget::Count(1)).payout_occupied_capacity(61);
+        let err = build_plan(&claimants, &planner).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "infeasible shard plan: a referral bonus does not cover the occupied capacity of a payout cell"
+        );
+    }
+}
@@ -0,0 +1,186 @@
+//! Which leaves of a shard have been claimed.
+//!
+//! Shards only record how many claims they can still pay, so the claimed
+//! leaves are reconstructed by replaying the claims along a shard's chain and
+//! decoding the leaf each `ClaimWitness` reveals.
+
+use std::collections::HashSet;
+
+use ckb_types::packed::{Byte32, OutPoint};
+use common::merkle::{verify_merkle_proof, witness_leaf};
+
+use crate::{Indexer, ShardRecord};
+
+/// A leaf claimed from a shard.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClaimedLeaf {
+    pub leaf: [u8; 32],
+    pub proof_type_id: [u8; 32],
+    /// The shard the claim consumed.
+    pub shard: OutPoint,
+    pub tx_hash: Byte32,
+}
+
+/// Something in a shard's chain that the distribution rules do not allow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Inconsistency {
+    /// A shard does not hold its remaining claims at the uniform reward plus
+    /// its referral budget.
+    CapacityMismatch {
+        shard: OutPoint,
+        expected: u64,
+        actual: u64,
+    },
+    /// A claim did not pay out exactly one claim.
+    ClaimCountMismatch {
+        tx_hash: Byte32,
+        before: u32,
+        after: u32,
+    },
+    /// A claim's Merkle proof does not lead to the shard's root.
+    MerkleProofInvalid { tx_hash: Byte32, leaf: [u8; 32] },
+    /// A leaf was claimed more than once.
+    LeafClaimedTwice { tx_hash: Byte32, leaf: [u8; 32] },
+    /// A claimed leaf is not one of the shard's planned leaves.
+    LeafUnknown { tx_hash: Byte32, leaf: [u8; 32] },
+    /// The claims made and the claims left do not add up to the planned leaves.
+    LeafCountMismatch { leaves: usize, accounted: usize },
+}
+
+/// The claim status of a shard created by a fan-out.
+#[derive(Clone, Debug)]
+pub struct ClaimStatus {
+    pub merkle_root: [u8; 32],
+    /// The claimed leaves, in claim order.
+    pub claimed: Vec<ClaimedLeaf>,
+    /// The planned leaves that have not been claimed, in plan order.
+    pub unclaimed: Vec<[u8; 32]>,
+    /// The current shard of the chain, or `None` once the last claim or a
+    /// reclamation consumed it.
+    pub live_shard: Option<OutPoint>,
+    pub reclaimed: bool,
+    /// The claims the shard can still pay, or could when it was reclaimed.
+    pub remaining_claims: u32,
+    /// The capacity the current shard must hold.
+    pub expected_capacity: u64,
+    /// The capacity the current shard holds.
+    pub capacity: u64,
+    pub inconsistencies: Vec<Inconsistency>,
+}
+
+impl ClaimStatus {
+    pub fn is_consistent(&self) -> bool {
+        self.inconsistencies.is_empty()
+    }
+}
+
+impl Indexer {
+    /// Replays the chain of the shard a fan-out created at `out_point`, and
+    /// reports its claims against `leaves`, the shard's planned leaves.
+    ///
+    /// Returns `None` when no shard was indexed at `out_point`.
+    pub fn claim_status(&self, out_point: &OutPoint, leaves: &[[u8; 32]]) -> Option<ClaimStatus> {
+        let mut chain: Vec<&ShardRecord> = vec![self.shard(out_point)?];
+        while let Some(next) = self.successor(chain[chain.len() - 1].out_point()) {
+            chain.push(next);
+        }
+
+        let origin = chain[0];
+        let merkle_root = origin.merkle_root();
+        let planned: HashSet<[u8; 32]> = leaves.iter().copied().collect();
+        let mut claimed_leaves = HashSet::new();
+        let mut claimed = Vec::new();
+        let mut inconsistencies = Vec::new();
+
+        for (position, shard) in chain.iter().enumerate() {
+            if shard.remaining_capacity() != shard.expected_capacity() {
+                inconsistencies.push(Inconsistency::CapacityMismatch {
+                    shard: shard.out_point().clone(),
+                    expected: shard.expected_capacity(),
+                    actual: shard.remaining_capacity(),
+                });
+            }
+
+            let (Some(witness), Some(tx_hash)) = (&shard.claim, &shard.shard.consumed_by) else {
+                continue;
+            };
+            let leaf = witness_leaf(witness);
+            if !verify_merkle_proof(&merkle_root, leaf, &witness.merkle_proof()) {
+                inconsistencies.push(Inconsistency::MerkleProofInvalid {
+                    tx_hash: tx_hash.clone(),
+                    leaf,
+                });
+            }
+            if !claimed_leaves.insert(leaf) {
+                inconsistencies.push(Inconsistency::LeafClaimedTwice {
+                    tx_hash: tx_hash.clone(),
+                    leaf,
+                });
+            }
+            if !planned.contains(&leaf) {
+                inconsistencies.push(Inconsistency::LeafUnknown {
+                    tx_hash: tx_hash.clone(),
+                    leaf,
+                });
+            }
+
+            // The last claim destroys the shard, leaving no claims to pay.
+            let after = chain
+                .get(position + 1)
+                .map(|next| next.remaining_claims())
+                .unwrap_or(0);
+            if shard.remaining_claims().checked_sub(after) != Some(1) {
+                inconsistencies.push(Inconsistency::ClaimCountMismatch {
+                    tx_hash: tx_hash.clone(),
+                    before: shard.remaining_claims(),
+                    after,
+                });
+            }
+
+            claimed.push(ClaimedLeaf {
+                leaf,
+                proof_type_id: witness.proof_type_id().into(),
+                shard: shard.out_point().clone(),
+                tx_hash: tx_hash.clone(),
+            });
+        }
+
+        let last = chain[chain.len() - 1];
+        let (live_shard, remaining_claims, expected_capacity, capacity) = if last.is_live() {
+            (
+                Some(last.out_point().clone()),
+                last.remaining_claims(),
+                last.expected_capacity(),
+                last.remaining_capacity(),
+            )
+        } else if last.reclaimed {
+            (None, last.remaining_claims(), 0, 0)
+        } else {
+            (None, 0, 0, 0)
+        };
+
+        let accounted = claimed.len() + remaining_claims as usize;
+        if accounted != leaves.len() {
+            inconsistencies.push(Inconsistency::LeafCountMismatch {
+                leaves: leaves.len(),
+                accounted,
+            });
+        }
+
+        Some(ClaimStatus {
+            merkle_root,
+            unclaimed: leaves
+                .iter()
+                .filter(|leaf| !claimed_leaves.contains(*leaf))
+                .copied()
+                .collect(),
+            claimed,
+            live_shard,
+            reclaimed: last.reclaimed,
+            remaining_claims,
+            expected_capacity,
+            capacity,
+            inconsistencies,
+        })
+    }
+}
@@ -0,0 +1,10 @@
+//! JSON block dumps.
+
+use ckb_jsonrpc_types::BlockView as JsonBlockView;
+use ckb_types::core::BlockView;
+
+/// Parses a JSON array of blocks in the format returned by the `get_block` RPC.
+pub fn blocks_from_json(json: &str) -> Result<Vec<BlockView>, serde_json::Error> {
+    let blocks: Vec<JsonBlockView> = serde_json::from_str(json)?;
+    Ok(blocks.into_iter().map(Into::into).collect())
+}
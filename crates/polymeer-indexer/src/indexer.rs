@@ -0,0 +1,272 @@
+use std::collections::HashMap;
+
+use ckb_types::{
+    core::{BlockView, TransactionView},
+    packed::{Byte32, OutPoint, WitnessArgs},
+    prelude::*,
+};
+use common::{
+    schema::distribution::ClaimWitness,
+    version::{parse_distribution_data, parse_proof_data, parse_vault_data},
+};
+use polymeer_sdk::{Deployment, LiveCell};
+
+use crate::records::{IndexedCell, ProofRecord, Reclamation, ShardRecord, VaultRecord};
+
+/// An in-memory store of the cells of one deployment.
+///
+/// Records are kept in the order they were indexed, and consumed cells are
+/// kept alongside live ones so their history can be queried.
+pub struct Indexer {
+    deployment: Deployment,
+    vaults: Vec<VaultRecord>,
+    shards: Vec<ShardRecord>,
+    proofs: Vec<ProofRecord>,
+    reclamations: Vec<Reclamation>,
+    cells: HashMap<OutPoint, CellRef>,
+}
+
+#[derive(Clone, Copy)]
+enum CellRef {
+    Vault(usize),
+    Shard(usize),
+    Proof(usize),
+}
+
+impl Indexer {
+    pub fn new(deployment: Deployment) -> Self {
+        Self {
+            deployment,
+            vaults: Vec::new(),
+            shards: Vec::new(),
+            proofs: Vec::new(),
+            reclamations: Vec::new(),
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Indexes a cell that was not created by an indexed transaction, such as
+    /// one created directly in a ckb-testtool `Context`.
+    pub fn index_cell(&mut self, cell: LiveCell) {
+        self.insert(cell, None, &[]);
+    }
+
+    /// Indexes every transaction of a block, in order.
+    pub fn index_block(&mut self, block: &BlockView) {
+        for tx in block.transactions() {
+            self.index_transaction(&tx);
+        }
+    }
+
+    /// Indexes a committed transaction: the platform cells it consumes are
+    /// marked as consumed, and the ones it creates are added.
+    pub fn index_transaction(&mut self, tx: &TransactionView) {
+        let tx_hash = tx.hash();
+
+        let mut consumed_shards = Vec::new();
+        for (index, input) in tx.inputs().into_iter().enumerate() {
+            let out_point = input.previous_output();
+            match self.cells.get(&out_point).copied() {
+                Some(CellRef::Vault(position)) => {
+                    self.vaults[position].consumed_by = Some(tx_hash.clone());
+                }
+                Some(CellRef::Proof(position)) => {
+                    self.proofs[position].consumed_by = Some(tx_hash.clone());
+                }
+                Some(CellRef::Shard(position)) => {
+                    // A claim carries its `ClaimWitness` in the lock of the
+                    // shard's witness. A shard consumed without one is reclaimed.
+                    let claim = tx
+                        .witnesses()
+                        .get(index)
+                        .and_then(|witness| WitnessArgs::from_slice(&witness.raw_data()).ok())
+                        .and_then(|witness_args| witness_args.lock().to_opt())
+                        .and_then(|lock| ClaimWitness::from_slice(&lock.raw_data()).ok());
+
+                    let shard = &mut self.shards[position];
+                    shard.shard.consumed_by = Some(tx_hash.clone());
+                    if claim.is_some() {
+                        shard.claim = claim;
+                        consumed_shards.push(position);
+                    } else {
+                        shard.reclaimed = true;
+                        self.reclamations.push(Reclamation {
+                            shard: out_point,
+                            campaign_id: shard.campaign_id(),
+                            tx_hash: tx_hash.clone(),
+                            capacity: shard.remaining_capacity(),
+                            unclaimed: shard.remaining_claims(),
+                        });
+                    }
+                }
+                None => {}
+            }
+        }
+
+        for (index, (output, data)) in tx.outputs_with_data_iter().enumerate() {
+            let out_point = OutPoint::new_builder()
+                .tx_hash(tx_hash.clone())
+                .index((index as u32).pack())
+                .build();
+            self.insert(
+                LiveCell::new(out_point, output, data),
+                Some(tx_hash.clone()),
+                &consumed_shards,
+            );
+        }
+    }
+
+    fn insert(&mut self, cell: LiveCell, created_by: Option<Byte32>, claimed: &[usize]) {
+        let Some(type_script) = cell.output.type_().to_opt() else {
+            return;
+        };
+
+        let out_point = cell.out_point.clone();
+        if self.deployment.vault_type.is_script(&type_script) {
+            if let Some(data) = parse_vault_data(&cell.data) {
+                self.cells
+                    .insert(out_point, CellRef::Vault(self.vaults.len()));
+                self.vaults.push(indexed(cell, data, created_by));
+            }
+        } else if self.deployment.proof_type.is_script(&type_script) {
+            if let Some(data) = parse_proof_data(&cell.data) {
+                if type_script.args().raw_data().len() < 32 {
+                    return;
+                }
+                self.cells
+                    .insert(out_point, CellRef::Proof(self.proofs.len()));
+                self.proofs.push(indexed(cell, data, created_by));
+            }
+        } else if self.deployment.distribution_type.is_script(&type_script) {
+            if let Some(data) = parse_distribution_data(&cell.data) {
+                let shard = indexed(cell, data, created_by);
+
+                // A claim recreates the shard it consumed with the same root.
+                let previous = claimed.iter().find_map(|&position| {
+                    let previous = &self.shards[position];
+                    (previous.shard.data.campaign_id().as_slice()
+                        == shard.data.campaign_id().as_slice()
+                        && previous.shard.data.merkle_root().as_slice()
+                            == shard.data.merkle_root().as_slice())
+                    .then_some(previous)
+                });
+                let record = match previous {
+                    Some(previous) => {
+                        let remaining_claims: u32 = shard.data.remaining_claims().unpack();
+                        ShardRecord {
+                            previous: Some(previous.out_point().clone()),
+                            claims_made: previous.claims_made
+                                + previous.remaining_claims().saturating_sub(remaining_claims),
+                            claim: None,
+                            reclaimed: false,
+                            shard,
+                        }
+                    }
+                    None => ShardRecord {
+                        shard,
+                        previous: None,
+                        claims_made: 0,
+                        claim: None,
+                        reclaimed: false,
+                    },
+                };
+
+                self.cells
+                    .insert(out_point, CellRef::Shard(self.shards.len()));
+                self.shards.push(record);
+            }
+        }
+    }
+
+    /// Returns the live vault of a campaign.
+    pub fn live_vault(&self, campaign_id: &[u8; 32]) -> Option<&VaultRecord> {
+        self.vaults
+            .iter()
+            .find(|vault| vault.is_live() && &vault.campaign_id() == campaign_id)
+    }
+
+    /// Returns every vault of a campaign, the consumed ones included.
+    pub fn vaults(&self, campaign_id: &[u8; 32]) -> Vec<&VaultRecord> {
+        self.vaults
+            .iter()
+            .filter(|vault| &vault.campaign_id() == campaign_id)
+            .collect()
+    }
+
+    /// Returns the shards of a campaign that can still be claimed from or
+    /// reclaimed.
+    pub fn live_shards(&self, campaign_id: &[u8; 32]) -> Vec<&ShardRecord> {
+        self.shards(campaign_id)
+            .into_iter()
+            .filter(|shard| shard.is_live())
+            .collect()
+    }
+
+    /// Returns every shard of a campaign, the consumed ones included.
+    pub fn shards(&self, campaign_id: &[u8; 32]) -> Vec<&ShardRecord> {
+        self.shards
+            .iter()
+            .filter(|shard| &shard.campaign_id() == campaign_id)
+            .collect()
+    }
+
+    /// Returns the shard created at `out_point`, live or consumed.
+    pub fn shard(&self, out_point: &OutPoint) -> Option<&ShardRecord> {
+        match self.cells.get(out_point) {
+            Some(CellRef::Shard(position)) => Some(&self.shards[*position]),
+            _ => None,
+        }
+    }
+
+    /// Returns the live shard descending from the shard created at
+    /// `out_point`, which is the one to claim from with a claim bundle naming
+    /// it.
+    pub fn live_descendant(&self, out_point: &OutPoint) -> Option<&ShardRecord> {
+        let mut current = self.shard(out_point)?;
+        while !current.is_live() {
+            current = self.successor(current.out_point())?;
+        }
+        Some(current)
+    }
+
+    /// Returns the shard a claim recreated from the shard at `out_point`.
+    pub fn successor(&self, out_point: &OutPoint) -> Option<&ShardRecord> {
+        self.shards
+            .iter()
+            .find(|shard| shard.previous.as_ref() == Some(out_point))
+    }
+
+    /// Returns the live proofs of a subscriber.
+    pub fn live_proofs(&self, subscriber_lock_hash: &[u8; 32]) -> Vec<&ProofRecord> {
+        self.proofs
+            .iter()
+            .filter(|proof| {
+                proof.is_live() && &proof.subscriber_lock_hash() == subscriber_lock_hash
+            })
+            .collect()
+    }
+
+    /// Returns the live proof with the given Type ID.
+    pub fn live_proof(&self, proof_type_id: &[u8; 32]) -> Option<&ProofRecord> {
+        self.proofs
+            .iter()
+            .find(|proof| proof.is_live() && &proof.proof_type_id() == proof_type_id)
+    }
+
+    /// Returns the shards of a campaign reclaimed by the admin.
+    pub fn reclamations(&self, campaign_id: &[u8; 32]) -> Vec<&Reclamation> {
+        self.reclamations
+            .iter()
+            .filter(|reclamation| &reclamation.campaign_id == campaign_id)
+            .collect()
+    }
+}
+
+fn indexed<T>(cell: LiveCell, data: T, created_by: Option<Byte32>) -> IndexedCell<T> {
+    IndexedCell {
+        cell,
+        data,
+        created_by,
+        consumed_by: None,
+    }
+}
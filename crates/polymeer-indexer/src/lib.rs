@@ -0,0 +1,18 @@
+//! A local indexer of the platform's cells.
+//!
+//! The indexer stands in for a chain indexer in end-to-end flows. It is fed
+//! cells, transactions or blocks in chain order, such as the transactions a
+//! test verifies with ckb-testtool's `Context` or a JSON dump of RPC blocks,
+//! and keeps the vaults, shards and proofs it finds along with the shards
+//! reclaimed by the admin. Cells it has not seen created are ignored when
+//! consumed.
+
+pub mod claims;
+pub mod dump;
+pub mod indexer;
+pub mod records;
+
+pub use claims::{ClaimStatus, ClaimedLeaf, Inconsistency};
+pub use dump::blocks_from_json;
+pub use indexer::Indexer;
+pub use records::{IndexedCell, ProofRecord, Reclamation, ShardRecord, VaultRecord};
@@ -0,0 +1,119 @@
+//! The records kept by the indexer.
+
+use ckb_types::{packed::Byte32, packed::OutPoint, prelude::*};
+use common::schema::{
+    distribution::{ClaimWitness, DistributionCellData},
+    proof::ProofCellData,
+    vault::VaultCellData,
+};
+use polymeer_sdk::LiveCell;
+
+/// A cell of the platform, with its decoded data and the transactions that
+/// created and consumed it.
+#[derive(Clone, Debug)]
+pub struct IndexedCell<T> {
+    pub cell: LiveCell,
+    pub data: T,
+    /// The transaction that created the cell, or `None` for a cell indexed
+    /// directly.
+    pub created_by: Option<Byte32>,
+    /// The transaction that consumed the cell, or `None` while it is live.
+    pub consumed_by: Option<Byte32>,
+}
+
+impl<T> IndexedCell<T> {
+    pub fn out_point(&self) -> &OutPoint {
+        &self.cell.out_point
+    }
+
+    pub fn is_live(&self) -> bool {
+        self.consumed_by.is_none()
+    }
+}
+
+pub type VaultRecord = IndexedCell<VaultCellData>;
+
+impl VaultRecord {
+    pub fn campaign_id(&self) -> [u8; 32] {
+        self.data.campaign_id().into()
+    }
+}
+
+pub type ProofRecord = IndexedCell<ProofCellData>;
+
+impl ProofRecord {
+    /// Returns the Type ID of the proof, which stays the same across updates.
+    pub fn proof_type_id(&self) -> [u8; 32] {
+        let proof_type = self.cell.output.type_().to_opt().unwrap();
+        proof_type.args().raw_data()[..32].try_into().unwrap()
+    }
+
+    pub fn subscriber_lock_hash(&self) -> [u8; 32] {
+        self.data.subscriber_lock_hash().into()
+    }
+}
+
+/// A distribution shard. Every claim that leaves claims to pay consumes the
+/// shard and recreates it, so a shard created by a fan-out is followed by a
+/// chain of records, one per claim.
+#[derive(Clone, Debug)]
+pub struct ShardRecord {
+    pub shard: IndexedCell<DistributionCellData>,
+    /// The shard this one was recreated from, or `None` for a shard created by
+    /// a fan-out.
+    pub previous: Option<OutPoint>,
+    /// The claims paid out of the chain before this shard was created.
+    pub claims_made: u32,
+    /// The witness of the claim that consumed this shard, if any.
+    pub claim: Option<ClaimWitness>,
+    /// Whether the admin reclaimed this shard after its deadline.
+    pub reclaimed: bool,
+}
+
+impl ShardRecord {
+    pub fn out_point(&self) -> &OutPoint {
+        self.shard.out_point()
+    }
+
+    pub fn is_live(&self) -> bool {
+        self.shard.is_live()
+    }
+
+    pub fn campaign_id(&self) -> [u8; 32] {
+        self.shard.data.campaign_id().into()
+    }
+
+    pub fn merkle_root(&self) -> [u8; 32] {
+        self.shard.data.merkle_root().into()
+    }
+
+    pub fn remaining_capacity(&self) -> u64 {
+        self.shard.cell.capacity()
+    }
+
+    pub fn remaining_claims(&self) -> u32 {
+        self.shard.data.remaining_claims().unpack()
+    }
+
+    /// Returns the capacity the shard must hold: the remaining claims at the
+    /// uniform reward, plus the remaining referral budget.
+    pub fn expected_capacity(&self) -> u64 {
+        let uniform_reward_amount: u64 = self.shard.data.uniform_reward_amount().unpack();
+        let referral_budget: u64 = self.shard.data.referral_budget().unpack();
+        u64::from(self.remaining_claims())
+            .saturating_mul(uniform_reward_amount)
+            .saturating_add(referral_budget)
+    }
+}
+
+/// A shard the admin reclaimed after its deadline.
+#[derive(Clone, Debug)]
+pub struct Reclamation {
+    pub shard: OutPoint,
+    pub campaign_id: [u8; 32],
+    pub tx_hash: Byte32,
+    /// The capacity returned to the admin.
+    pub capacity: u64,
+    /// The claims the shard could still pay when it was reclaimed.
+    pub unclaimed: u32,
+}
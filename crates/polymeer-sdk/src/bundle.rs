@@ -0,0 +1,99 @@
+//! Claim bundles handed to claimants.
+//!
+//! A bundle is generated per claimant once the fan-out is committed, and holds
+//! everything `ClaimBuilder` needs besides the live cells themselves.
+
+use ckb_types::{packed, prelude::*};
+use common::{
+    base::{Byte32, Byte32Vec},
+    schema::{
+        bundle::OutPoint,
+        distribution::{Referral, ReferralOpt},
+    },
+};
+use molecule::prelude::{Builder, Entity};
+
+pub use common::{
+    bundle::{bundle_leaf, verify_bundle, BundleError, ClaimBundleJson},
+    schema::bundle::ClaimBundle,
+};
+
+use crate::merkle::{claimant_leaf, merkle_proof, merkle_root};
+
+/// A claimant of a shard, with the proof cell it claims with.
+#[derive(Debug, Clone)]
+pub struct BundleClaimant {
+    pub proof_type_id: [u8; 32],
+    /// The subscriber's lock hash, or the hash of its secret for a private
+    /// claim.
+    pub subscriber_lock_hash: [u8; 32],
+    pub referral: Option<Referral>,
+    pub proof_out_point: packed::OutPoint,
+}
+
+impl BundleClaimant {
+    /// Returns the leaf committing to this claimant.
+    pub fn leaf(&self) -> [u8; 32] {
+        claimant_leaf(
+            &self.proof_type_id,
+            &self.subscriber_lock_hash,
+            self.referral.as_ref(),
+        )
+    }
+}
+
+/// Returns the out point of the shard a fan-out created as its output
+/// `shard_index`.
+pub fn shard_out_point(fan_out_tx_hash: &[u8; 32], shard_index: u32) -> packed::OutPoint {
+    packed::OutPoint::new_builder()
+        .tx_hash(fan_out_tx_hash.pack())
+        .index(shard_index.pack())
+        .build()
+}
+
+/// Builds the bundle of every claimant of a shard, in order. `claimants` must be
+/// in the order of the shard's leaves, as planned by `ShardPlanner`.
+pub fn shard_bundles(
+    campaign_id: &[u8; 32],
+    shard_out_point: &packed::OutPoint,
+    claimants: &[BundleClaimant],
+) -> Vec<ClaimBundle> {
+    let leaves: Vec<[u8; 32]> = claimants.iter().map(BundleClaimant::leaf).collect();
+    let root = merkle_root(&leaves);
+
+    claimants
+        .iter()
+        .enumerate()
+        .map(|(index, claimant)| {
+            let siblings: Vec<Byte32> = merkle_proof(&leaves, index)
+                .into_iter()
+                .map(Byte32::from)
+                .collect();
+
+            ClaimBundle::new_builder()
+                .campaign_id(Byte32::from(*campaign_id))
+                .shard_out_point(to_bundle_out_point(shard_out_point))
+                .merkle_root(Byte32::from(root))
+                .proof_out_point(to_bundle_out_point(&claimant.proof_out_point))
+                .proof_type_id(Byte32::from(claimant.proof_type_id))
+                .subscriber_lock_hash(Byte32::from(claimant.subscriber_lock_hash))
+                .referral(
+                    ReferralOpt::new_builder()
+                        .set(claimant.referral.clone())
+                        .build(),
+                )
+                .merkle_proof(Byte32Vec::new_builder().set(siblings).build())
+                .build()
+        })
+        .collect()
+}
+
+/// Returns the out point of a bundle as a CKB out point, which has the same
+/// layout.
+pub fn to_out_point(out_point: &OutPoint) -> packed::OutPoint {
+    packed::OutPoint::new_unchecked(out_point.as_bytes())
+}
+
+fn to_bundle_out_point(out_point: &packed::OutPoint) -> OutPoint {
+    OutPoint::new_unchecked(out_point.as_bytes())
+}
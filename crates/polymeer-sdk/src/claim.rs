@@ -0,0 +1,386 @@
+//! Claims from a distribution shard.
+
+use ckb_types::{
+    bytes::Bytes,
+    core::{TransactionBuilder, TransactionView},
+    packed::{CellDep, CellOutput, Script, WitnessArgs},
+    prelude::*,
+};
+use common::{
+    base::{Byte32, Byte32Vec, Bytes as MolBytes},
+    bitmap::is_bit_set,
+    schema::{
+        distribution::{
+            ClaimWitness, DistributionCellData, PrivateClaim, PrivateClaimOpt, ProofReveal,
+            Referral, ReferralOpt,
+        },
+        proof::{ProofCellData, ProofTypeArgs},
+        vesting::VestingLockArgs,
+    },
+    version::{parse_distribution_data, parse_proof_data},
+    NULL_HASH,
+};
+use molecule::prelude::{Builder, Byte, Entity};
+
+use crate::{
+    util::{lock_hash, payout_cell, versioned},
+    Deployment, Error, LiveCell,
+};
+
+/// Builds the transaction claiming a reward from a distribution shard with a
+/// proof cell.
+///
+/// A claim that leaves the shard with claims to pay updates it; the last claim
+/// destroys it and returns the unused referral budget to the admin. The proof
+/// is consumed, or kept with the campaign marked as claimed. The caller adds
+/// the inputs paying the transaction fee, one of which must belong to the
+/// subscriber when the proof lock guards the proof.
+pub struct ClaimBuilder {
+    deployment: Deployment,
+    shard: LiveCell,
+    shard_data: DistributionCellData,
+    proof: LiveCell,
+    proof_data: ProofCellData,
+    proof_args: ProofTypeArgs,
+    merkle_proof: Vec<[u8; 32]>,
+    proof_reveal: Option<([u8; 32], [u8; 32])>,
+    referral: Option<(Script, u64)>,
+    private_claim: Option<([u8; 32], Script)>,
+    entity_proof: Vec<[u8; 32]>,
+    keep_proof: bool,
+    admin_lock: Option<Script>,
+    subscriber_lock: Option<Script>,
+    entity_cell_dep: Option<CellDep>,
+}
+
+impl ClaimBuilder {
+    pub fn new(deployment: Deployment, shard: LiveCell, proof: LiveCell) -> Result<Self, Error> {
+        let shard_data =
+            parse_distribution_data(&shard.data).ok_or(Error::CellDataInvalid("distribution"))?;
+        let proof_data = parse_proof_data(&proof.data).ok_or(Error::CellDataInvalid("proof"))?;
+
+        let proof_type = proof
+            .output
+            .type_()
+            .to_opt()
+            .ok_or(Error::ScriptArgsInvalid("proof type"))?;
+        if !deployment.proof_type.is_script(&proof_type) {
+            return Err(Error::ScriptArgsInvalid("proof type"));
+        }
+        let proof_args = ProofTypeArgs::from_slice(&proof_type.args().raw_data())
+            .map_err(|_| Error::ScriptArgsInvalid("proof type"))?;
+        // A shard created with data version 1 pins no registry.
+        if shard_data.entity_registry().code_hash().as_slice() != NULL_HASH
+            && proof_args.entity_registry().as_slice() != shard_data.entity_registry().as_slice()
+        {
+            return Err(Error::ClaimInvalid(
+                "the proof names another entity registry than the campaign",
+            ));
+        }
+        if proof_args.distribution_type().as_slice()
+            != deployment.distribution_type.packed_script_ref().as_slice()
+        {
+            return Err(Error::ClaimInvalid(
+                "the proof names another distribution type than the deployment",
+            ));
+        }
+
+        Ok(Self {
+            deployment,
+            shard,
+            shard_data,
+            proof,
+            proof_data,
+            proof_args,
+            merkle_proof: Vec::new(),
+            proof_reveal: None,
+            referral: None,
+            private_claim: None,
+            entity_proof: Vec::new(),
+            keep_proof: false,
+            admin_lock: None,
+            subscriber_lock: None,
+            entity_cell_dep: None,
+        })
+    }
+
+    /// Returns the Type ID of the proof, which the claimant's leaf commits to.
+    pub fn proof_type_id(&self) -> [u8; 32] {
+        self.proof_args.type_id().into()
+    }
+
+    /// Sets the path of the claimant's leaf in the shard's tree.
+    pub fn merkle_proof(mut self, merkle_proof: Vec<[u8; 32]>) -> Self {
+        self.merkle_proof = merkle_proof;
+        self
+    }
+
+    /// Reveals the proof of consumption and the salt committed in the proof.
+    pub fn proof_reveal(mut self, proof: [u8; 32], salt: [u8; 32]) -> Self {
+        self.proof_reveal = Some((proof, salt));
+        self
+    }
+
+    /// Pays the referrer committed in the claimant's leaf its bonus.
+    pub fn referral(mut self, referrer_lock: Script, bonus_amount: u64) -> Self {
+        self.referral = Some((referrer_lock, bonus_amount));
+        self
+    }
+
+    /// Makes a private claim: the leaf commits to the hash of `secret`, and the
+    /// reward is paid to `destination_lock`. The claim still spends or updates
+    /// the subscriber's proof cell, so it does not hide who received the reward.
+    pub fn private_claim(mut self, secret: [u8; 32], destination_lock: Script) -> Self {
+        self.private_claim = Some((secret, destination_lock));
+        self
+    }
+
+    /// Sets the path of the proof's entity in the shard's entity tree, when the
+    /// campaign targets specific entities.
+    pub fn entity_proof(mut self, entity_proof: Vec<[u8; 32]>) -> Self {
+        self.entity_proof = entity_proof;
+        self
+    }
+
+    /// Keeps the proof, with the campaign marked as claimed, so it can claim
+    /// from the other campaigns it covers.
+    pub fn keep_proof(mut self, keep_proof: bool) -> Self {
+        self.keep_proof = keep_proof;
+        self
+    }
+
+    /// Provides the admin's lock, which the last claim returns the unused
+    /// referral budget to.
+    pub fn admin_lock(mut self, admin_lock: Script) -> Self {
+        self.admin_lock = Some(admin_lock);
+        self
+    }
+
+    /// Provides the subscriber's lock, which the reward is paid to when the
+    /// proof is guarded by the proof lock rather than by that lock itself.
+    pub fn subscriber_lock(mut self, subscriber_lock: Script) -> Self {
+        self.subscriber_lock = Some(subscriber_lock);
+        self
+    }
+
+    /// Provides the registry cell of the proof's entity, which a campaign
+    /// scoped to a creator checks the creator of.
+    pub fn entity_cell_dep(mut self, entity_cell_dep: CellDep) -> Self {
+        self.entity_cell_dep = Some(entity_cell_dep);
+        self
+    }
+
+    /// Returns the claim witness of the shard input.
+    pub fn claim_witness(&self) -> Result<ClaimWitness, Error> {
+        let (proof, salt) = self
+            .proof_reveal
+            .ok_or(Error::MissingField("proof_reveal"))?;
+
+        let referral = self.referral.as_ref().map(|(referrer_lock, bonus_amount)| {
+            Referral::new_builder()
+                .referrer_lock_hash(Byte32::from(lock_hash(referrer_lock)))
+                .bonus_amount(bonus_amount.pack())
+                .build()
+        });
+        let private_claim = self
+            .private_claim
+            .as_ref()
+            .map(|(secret, destination_lock)| {
+                PrivateClaim::new_builder()
+                    .secret(Byte32::from(*secret))
+                    .destination_lock_hash(Byte32::from(lock_hash(destination_lock)))
+                    .build()
+            });
+
+        Ok(ClaimWitness::new_builder()
+            .merkle_proof(to_byte32_vec(&self.merkle_proof))
+            .subscriber_lock_hash(self.proof_data.subscriber_lock_hash())
+            .proof_type_id(Byte32::from(self.proof_type_id()))
+            .referral(ReferralOpt::new_builder().set(referral).build())
+            .private_claim(PrivateClaimOpt::new_builder().set(private_claim).build())
+            .proof_reveal(
+                ProofReveal::new_builder()
+                    .proof(Byte32::from(proof))
+                    .salt(Byte32::from(salt))
+                    .build(),
+            )
+            .entity_proof(to_byte32_vec(&self.entity_proof))
+            .build())
+    }
+
+    pub fn build(&self) -> Result<TransactionView, Error> {
+        let claim_witness = self.claim_witness()?;
+
+        let campaign_index = self
+            .proof_data
+            .campaign_ids()
+            .into_iter()
+            .position(|campaign_id| {
+                campaign_id.as_slice() == self.shard_data.campaign_id().as_slice()
+            })
+            .ok_or(Error::ClaimInvalid("the proof does not cover the campaign"))?;
+        let claimed_campaigns = self.proof_data.claimed_campaigns().raw_data();
+        if is_bit_set(&claimed_campaigns, campaign_index) {
+            return Err(Error::ClaimInvalid("the campaign is already claimed"));
+        }
+
+        let remaining_claims: u32 = self.shard_data.remaining_claims().unpack();
+        let reward_amount: u64 = self.shard_data.uniform_reward_amount().unpack();
+        let referral_budget: u64 = self.shard_data.referral_budget().unpack();
+        let bonus_amount = self.referral.as_ref().map_or(0, |(_, bonus)| *bonus);
+        if remaining_claims == 0 {
+            return Err(Error::ClaimInvalid("the shard has no claims left"));
+        }
+        if bonus_amount > referral_budget {
+            return Err(Error::ClaimInvalid("the bonus exceeds the referral budget"));
+        }
+
+        let mut outputs = Vec::new();
+        let mut outputs_data = Vec::new();
+
+        // The shard goes on with one claim less, unless this is the last one.
+        if remaining_claims > 1 {
+            let shard_data = self
+                .shard_data
+                .clone()
+                .as_builder()
+                .remaining_claims((remaining_claims - 1).pack())
+                .referral_budget((referral_budget - bonus_amount).pack())
+                .build();
+            outputs.push(
+                self.shard
+                    .output
+                    .clone()
+                    .as_builder()
+                    .capacity((self.shard.capacity() - reward_amount - bonus_amount).pack())
+                    .build(),
+            );
+            outputs_data.push(versioned(&shard_data));
+        }
+
+        let released_proof_capacity = if self.keep_proof {
+            0
+        } else {
+            self.proof.capacity()
+        };
+        outputs.push(self.reward_cell(reward_amount + released_proof_capacity)?);
+        outputs_data.push(Bytes::new());
+
+        if let Some((referrer_lock, bonus_amount)) = self.referral.as_ref() {
+            outputs.push(payout_cell(referrer_lock.clone(), *bonus_amount));
+            outputs_data.push(Bytes::new());
+        }
+
+        let unused_referral_budget = referral_budget - bonus_amount;
+        if remaining_claims == 1 && unused_referral_budget > 0 {
+            let admin_lock = self
+                .admin_lock
+                .clone()
+                .ok_or(Error::LockMissing(self.shard_data.admin_lock_hash().into()))?;
+            outputs.push(payout_cell(admin_lock, unused_referral_budget));
+            outputs_data.push(Bytes::new());
+        }
+
+        if self.keep_proof {
+            let mut claimed_campaigns = claimed_campaigns.to_vec();
+            claimed_campaigns[campaign_index / 8] |= 1 << (campaign_index % 8);
+            let proof_data = self
+                .proof_data
+                .clone()
+                .as_builder()
+                .claimed_campaigns(
+                    MolBytes::new_builder()
+                        .extend(claimed_campaigns.into_iter().map(Byte::new))
+                        .build(),
+                )
+                .build();
+            outputs.push(self.proof.output.clone());
+            outputs_data.push(versioned(&proof_data));
+        }
+
+        let shard_witness = WitnessArgs::new_builder()
+            .lock(Some(claim_witness.as_bytes()).pack())
+            .build();
+
+        let mut tx = TransactionBuilder::default()
+            .cell_dep(self.deployment.distribution_lock.cell_dep.clone())
+            .cell_dep(self.deployment.distribution_type.cell_dep.clone())
+            .cell_dep(self.deployment.proof_type.cell_dep.clone());
+        if self
+            .deployment
+            .proof_lock
+            .is_script(&self.proof.output.lock())
+        {
+            tx = tx.cell_dep(self.deployment.proof_lock.cell_dep.clone());
+        }
+        if self.shard_data.entity_creator_lock_hash().as_slice() != NULL_HASH {
+            let entity_cell_dep = self
+                .entity_cell_dep
+                .clone()
+                .ok_or(Error::MissingField("entity_cell_dep"))?;
+            tx = tx.cell_dep(entity_cell_dep);
+        }
+
+        Ok(tx
+            .input(self.shard.input(0))
+            .input(self.proof.input(0))
+            .outputs(outputs)
+            .outputs_data(outputs_data.pack())
+            .witness(shard_witness.as_bytes().pack())
+            .build())
+    }
+
+    /// Builds the reward cell: paid to the payout lock directly, or locked by
+    /// the vesting lock when the campaign vests its rewards.
+    fn reward_cell(&self, capacity: u64) -> Result<CellOutput, Error> {
+        let payout_lock = match self.private_claim.as_ref() {
+            Some((_, destination_lock)) => destination_lock.clone(),
+            None => self.payout_subscriber_lock()?,
+        };
+
+        let vesting = self.shard_data.vesting();
+        if vesting.lock_code_hash().as_slice() == NULL_HASH {
+            return Ok(payout_cell(payout_lock, capacity));
+        }
+        let vesting_lock_ref = self.deployment.vesting_lock.packed_script_ref();
+        if vesting.lock_code_hash().as_slice() != vesting_lock_ref.code_hash().as_slice()
+            || vesting.lock_hash_type() != vesting_lock_ref.hash_type()
+        {
+            return Err(Error::VestingInvalid);
+        }
+
+        let vesting_lock_args = VestingLockArgs::new_builder()
+            .beneficiary_lock_hash(Byte32::from(lock_hash(&payout_lock)))
+            .locked_amount(self.shard_data.uniform_reward_amount())
+            .start_epoch(vesting.start_epoch())
+            .end_epoch(vesting.end_epoch())
+            .build();
+        let vesting_lock = self
+            .deployment
+            .vesting_lock
+            .script(vesting_lock_args.as_bytes());
+
+        Ok(payout_cell(vesting_lock, capacity))
+    }
+
+    /// Returns the subscriber's lock: the proof's own lock, unless the proof
+    /// lock guards it on the subscriber's behalf.
+    fn payout_subscriber_lock(&self) -> Result<Script, Error> {
+        let subscriber_lock_hash: [u8; 32] = self.proof_data.subscriber_lock_hash().into();
+        let proof_lock = self.proof.output.lock();
+        if lock_hash(&proof_lock) == subscriber_lock_hash {
+            return Ok(proof_lock);
+        }
+
+        self.subscriber_lock
+            .clone()
+            .filter(|subscriber_lock| lock_hash(subscriber_lock) == subscriber_lock_hash)
+            .ok_or(Error::LockMissing(subscriber_lock_hash))
+    }
+}
+
+fn to_byte32_vec(hashes: &[[u8; 32]]) -> Byte32Vec {
+    Byte32Vec::new_builder()
+        .extend(hashes.iter().copied().map(Byte32::from))
+        .build()
+}
@@ -0,0 +1,103 @@
+use ckb_types::{
+    bytes::Bytes,
+    core::ScriptHashType,
+    packed::{Byte32, CellDep, CellInput, CellOutput, OutPoint, Script},
+    prelude::*,
+};
+use common::script::{ScriptRef, SCRIPT_REF_LEN};
+
+/// A contract deployed on chain: how scripts refer to its code, and the cell
+/// dep providing it.
+#[derive(Clone, Debug)]
+pub struct DeployedScript {
+    pub code_hash: Byte32,
+    pub hash_type: ScriptHashType,
+    pub cell_dep: CellDep,
+}
+
+impl DeployedScript {
+    pub fn new(code_hash: Byte32, hash_type: ScriptHashType, cell_dep: CellDep) -> Self {
+        Self {
+            code_hash,
+            hash_type,
+            cell_dep,
+        }
+    }
+
+    /// Builds a script running this contract with `args`.
+    pub fn script(&self, args: Bytes) -> Script {
+        Script::new_builder()
+            .code_hash(self.code_hash.clone())
+            .hash_type(self.hash_type.into())
+            .args(args.pack())
+            .build()
+    }
+
+    /// Returns the code hash followed by the hash type, the way the contracts
+    /// refer to each other.
+    pub fn script_ref(&self) -> [u8; SCRIPT_REF_LEN] {
+        let mut script_ref = [0u8; SCRIPT_REF_LEN];
+        script_ref[..32].copy_from_slice(self.code_hash.as_slice());
+        script_ref[32] = self.hash_type.into();
+        script_ref
+    }
+
+    /// Returns the molecule `ScriptRef` of this contract, as stored in cell
+    /// data and script args.
+    pub fn packed_script_ref(&self) -> common::base::ScriptRef {
+        ScriptRef {
+            code_hash: self.code_hash.unpack(),
+            hash_type: self.hash_type.into(),
+        }
+        .to_packed()
+    }
+
+    /// Checks whether `script` runs this contract.
+    pub fn is_script(&self, script: &Script) -> bool {
+        let hash_type: u8 = self.hash_type.into();
+        script.code_hash() == self.code_hash && script.hash_type().as_slice() == [hash_type]
+    }
+}
+
+/// The platform's contracts, as deployed on a chain.
+#[derive(Clone, Debug)]
+pub struct Deployment {
+    pub vault_lock: DeployedScript,
+    pub vault_type: DeployedScript,
+    pub distribution_lock: DeployedScript,
+    pub distribution_type: DeployedScript,
+    pub proof_type: DeployedScript,
+    pub vesting_lock: DeployedScript,
+    pub entity_type: DeployedScript,
+    pub proof_lock: DeployedScript,
+}
+
+/// A live cell to be consumed by a transaction.
+#[derive(Clone, Debug)]
+pub struct LiveCell {
+    pub out_point: OutPoint,
+    pub output: CellOutput,
+    pub data: Bytes,
+}
+
+impl LiveCell {
+    pub fn new(out_point: OutPoint, output: CellOutput, data: Bytes) -> Self {
+        Self {
+            out_point,
+            output,
+            data,
+        }
+    }
+
+    pub fn capacity(&self) -> u64 {
+        self.output.capacity().unpack()
+    }
+
+    /// Returns the input consuming this cell with the given `since`.
+    pub fn input(&self, since: u64) -> CellInput {
+        CellInput::new_builder()
+            .previous_output(self.out_point.clone())
+            .since(since.pack())
+            .build()
+    }
+}
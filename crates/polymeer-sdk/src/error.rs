@@ -0,0 +1,57 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A required builder field was not set.
+    MissingField(&'static str),
+    /// The data of a cell could not be parsed.
+    CellDataInvalid(&'static str),
+    /// The args of a script do not have the expected layout, or refer to
+    /// other contracts than the deployment.
+    ScriptArgsInvalid(&'static str),
+    /// The fee model or the fee schedule is malformed.
+    FeeConfigInvalid,
+    /// The funders ledger is malformed or does not add up to the vault capacity.
+    FundersInvalid,
+    /// The entity targeting is malformed.
+    EntityTargetingInvalid,
+    /// The vesting configuration is malformed.
+    VestingInvalid,
+    /// The shards and the fee do not add up to the vault capacity.
+    CapacityMismatch { expected: u64, actual: u64 },
+    /// No lock script was provided for an account that must be paid.
+    LockMissing([u8; 32]),
+    /// The claim does not fit the shard or the proof.
+    ClaimInvalid(&'static str),
+    /// No shard plan can satisfy the distribution rules.
+    PlanInfeasible(&'static str),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::MissingField(field) => write!(f, "missing field `{}`", field),
+            Error::CellDataInvalid(cell) => write!(f, "invalid {} cell data", cell),
+            Error::ScriptArgsInvalid(script) => write!(f, "invalid {} script args", script),
+            Error::FeeConfigInvalid => write!(f, "invalid fee model or fee schedule"),
+            Error::FundersInvalid => write!(f, "invalid funders ledger"),
+            Error::EntityTargetingInvalid => write!(f, "invalid entity targeting"),
+            Error::VestingInvalid => write!(f, "invalid vesting configuration"),
+            Error::CapacityMismatch { expected, actual } => write!(
+                f,
+                "capacity mismatch: expected {} shannons, got {}",
+                expected, actual
+            ),
+            Error::LockMissing(lock_hash) => {
+                write!(f, "missing lock script for lock hash 0x")?;
+                lock_hash
+                    .iter()
+                    .try_for_each(|byte| write!(f, "{:02x}", byte))
+            }
+            Error::ClaimInvalid(reason) => write!(f, "invalid claim: {}", reason),
+            Error::PlanInfeasible(reason) => write!(f, "infeasible shard plan: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,215 @@
+//! Vault distribution into claim shards.
+
+use std::collections::HashMap;
+
+use ckb_types::{
+    bytes::Bytes,
+    core::{TransactionBuilder, TransactionView},
+    packed::{CellOutput, Script, WitnessArgs},
+    prelude::*,
+};
+use common::{
+    base::Byte32,
+    schema::{distribution::DistributionCellData, vault::VaultCellData},
+    version::parse_vault_data,
+};
+use molecule::prelude::{Builder, Entity};
+
+use crate::{
+    fees::{expected_fee, fee_shares, parse_fee_schedule},
+    util::{lock_hash, payout_cell, versioned},
+    vault::{expected_entity_root, parse_vault_lock_args},
+    Deployment, Error, LiveCell,
+};
+
+struct Shard {
+    merkle_root: [u8; 32],
+    claims: u32,
+    referral_budget: u64,
+}
+
+/// Builds the transaction distributing a vault into claim shards and paying
+/// the platform fee.
+///
+/// Every shard gets the campaign settings of the vault, so only the claimant
+/// trees, the reward and the deadline are chosen here. The caller adds the
+/// admin's input authorizing the distribution.
+pub struct FanOutBuilder {
+    deployment: Deployment,
+    vault: LiveCell,
+    vault_data: VaultCellData,
+    creator_lock_hash: [u8; 32],
+    admin_lock_hash: [u8; 32],
+    locks: HashMap<[u8; 32], Script>,
+    uniform_reward_amount: Option<u64>,
+    deadline: Option<u64>,
+    shards: Vec<Shard>,
+}
+
+impl FanOutBuilder {
+    pub fn new(deployment: Deployment, vault: LiveCell) -> Result<Self, Error> {
+        let vault_data = parse_vault_data(&vault.data).ok_or(Error::CellDataInvalid("vault"))?;
+
+        let vault_lock_args = parse_vault_lock_args(&deployment, &vault.output.lock())?;
+        let creator_lock_hash: [u8; 32] = vault_lock_args.creator_lock_hash().into();
+        let admin_lock_hash: [u8; 32] = vault_lock_args.admin_lock_hash().into();
+
+        Ok(Self {
+            deployment,
+            vault,
+            vault_data,
+            creator_lock_hash,
+            admin_lock_hash,
+            locks: HashMap::new(),
+            uniform_reward_amount: None,
+            deadline: None,
+            shards: Vec::new(),
+        })
+    }
+
+    pub fn creator_lock_hash(&self) -> [u8; 32] {
+        self.creator_lock_hash
+    }
+
+    pub fn admin_lock_hash(&self) -> [u8; 32] {
+        self.admin_lock_hash
+    }
+
+    /// Provides the lock script of a fee beneficiary, or of the admin when the
+    /// vault has no fee schedule.
+    pub fn fee_lock(mut self, lock: Script) -> Self {
+        self.locks.insert(lock_hash(&lock), lock);
+        self
+    }
+
+    /// Sets the reward every claimant receives, in Shannons.
+    pub fn uniform_reward_amount(mut self, uniform_reward_amount: u64) -> Self {
+        self.uniform_reward_amount = Some(uniform_reward_amount);
+        self
+    }
+
+    /// Sets the timestamp, in seconds, after which the admin can reclaim the
+    /// shards.
+    pub fn deadline(mut self, deadline: u64) -> Self {
+        self.deadline = Some(deadline);
+        self
+    }
+
+    /// Adds a shard paying `claims` claimants committed in `merkle_root`.
+    pub fn shard(self, merkle_root: [u8; 32], claims: u32) -> Self {
+        self.shard_with_referral_budget(merkle_root, claims, 0)
+    }
+
+    /// Adds a shard that also reserves `referral_budget` for referral bonuses.
+    pub fn shard_with_referral_budget(
+        mut self,
+        merkle_root: [u8; 32],
+        claims: u32,
+        referral_budget: u64,
+    ) -> Self {
+        self.shards.push(Shard {
+            merkle_root,
+            claims,
+            referral_budget,
+        });
+        self
+    }
+
+    /// Returns the platform fee owed on the vault.
+    pub fn fee(&self) -> Result<u64, Error> {
+        expected_fee(&self.vault_data, self.vault.capacity())
+    }
+
+    pub fn build(&self) -> Result<TransactionView, Error> {
+        let uniform_reward_amount = self
+            .uniform_reward_amount
+            .ok_or(Error::MissingField("uniform_reward_amount"))?;
+        let deadline = self.deadline.ok_or(Error::MissingField("deadline"))?;
+        if self.shards.is_empty() {
+            return Err(Error::MissingField("shards"));
+        }
+        if uniform_reward_amount == 0 || deadline == 0 {
+            return Err(Error::CellDataInvalid("distribution"));
+        }
+
+        let entity_root = expected_entity_root(&self.vault_data)?;
+        let shard_template = DistributionCellData::new_builder()
+            .campaign_id(self.vault_data.campaign_id())
+            .admin_lock_hash(Byte32::from(self.admin_lock_hash))
+            .proof_script_code_hash(self.vault_data.proof_script_code_hash())
+            .proof_script_hash_type(self.vault_data.proof_script_hash_type())
+            .uniform_reward_amount(uniform_reward_amount.pack())
+            .deadline(deadline.pack())
+            .vesting(self.vault_data.vesting())
+            .entity_root(Byte32::from(entity_root))
+            .entity_registry(self.vault_data.entity_registry())
+            .entity_creator_lock_hash(self.vault_data.entity_creator_lock_hash())
+            .build();
+
+        let shard_lock = self.deployment.distribution_lock.script(Bytes::new());
+        let shard_type = self.deployment.distribution_type.script(Bytes::new());
+
+        let mut outputs = Vec::new();
+        let mut outputs_data = Vec::new();
+        let mut shards_capacity: u64 = 0;
+        for shard in self.shards.iter() {
+            if shard.claims == 0 {
+                return Err(Error::CellDataInvalid("distribution"));
+            }
+            let capacity = (shard.claims as u64)
+                .checked_mul(uniform_reward_amount)
+                .and_then(|rewards| rewards.checked_add(shard.referral_budget))
+                .ok_or(Error::CellDataInvalid("distribution"))?;
+            shards_capacity = shards_capacity.saturating_add(capacity);
+
+            let shard_data = shard_template
+                .clone()
+                .as_builder()
+                .merkle_root(Byte32::from(shard.merkle_root))
+                .remaining_claims(shard.claims.pack())
+                .referral_budget(shard.referral_budget.pack())
+                .build();
+
+            outputs.push(
+                CellOutput::new_builder()
+                    .capacity(capacity.pack())
+                    .lock(shard_lock.clone())
+                    .type_(Some(shard_type.clone()).pack())
+                    .build(),
+            );
+            outputs_data.push(versioned(&shard_data));
+        }
+
+        let vault_capacity = self.vault.capacity();
+        let fee = self.fee()?;
+        if shards_capacity.saturating_add(fee) != vault_capacity {
+            return Err(Error::CapacityMismatch {
+                expected: vault_capacity,
+                actual: shards_capacity.saturating_add(fee),
+            });
+        }
+
+        let beneficiaries = parse_fee_schedule(&self.vault_data, &self.admin_lock_hash)?;
+        for (beneficiary_lock_hash, fee_capacity) in fee_shares(&beneficiaries, fee) {
+            if fee_capacity == 0 {
+                continue;
+            }
+            let lock = self
+                .locks
+                .get(&beneficiary_lock_hash)
+                .ok_or(Error::LockMissing(beneficiary_lock_hash))?;
+            outputs.push(payout_cell(lock.clone(), fee_capacity));
+            outputs_data.push(Bytes::new());
+        }
+
+        Ok(TransactionBuilder::default()
+            .cell_dep(self.deployment.vault_lock.cell_dep.clone())
+            .cell_dep(self.deployment.vault_type.cell_dep.clone())
+            .cell_dep(self.deployment.distribution_type.cell_dep.clone())
+            .input(self.vault.input(0))
+            .outputs(outputs)
+            .outputs_data(outputs_data.pack())
+            .witness(WitnessArgs::new_builder().build().as_bytes().pack())
+            .build())
+    }
+}
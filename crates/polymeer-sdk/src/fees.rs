@@ -0,0 +1,42 @@
+//! The platform fee rules of `vault-type`.
+//!
+//! The rules live in `common::fees`, which `vault-type` enforces; this module
+//! maps their errors to the SDK's.
+
+use common::schema::vault::VaultCellData;
+
+pub use common::fees::{
+    fee_shares, FeeModel, FeeShare, FEE_KIND_FLAT, FEE_KIND_NONE, FEE_KIND_PERCENTAGE,
+    FEE_SCHEDULE_TOTAL_BASIS_POINTS,
+};
+
+use crate::Error;
+
+/// Checks that the fee model of the vault data is well formed.
+pub fn verify_fee_model(vault_data: &VaultCellData) -> Result<FeeModel, Error> {
+    common::fees::verify_fee_model(vault_data).map_err(|_| Error::FeeConfigInvalid)
+}
+
+/// Checks that a vault of `vault_capacity` can pay the smallest fee of
+/// `fee_model`.
+pub fn verify_fee_capacity(fee_model: &FeeModel, vault_capacity: u64) -> Result<(), Error> {
+    common::fees::verify_fee_capacity(fee_model, vault_capacity)
+        .map_err(|_| Error::FeeConfigInvalid)
+}
+
+/// Computes the platform fee owed on a vault of `vault_capacity`.
+pub fn expected_fee(vault_data: &VaultCellData, vault_capacity: u64) -> Result<u64, Error> {
+    common::fees::expected_fee(vault_data, vault_capacity).map_err(|_| Error::FeeConfigInvalid)
+}
+
+/// Parses the fee schedule from the vault data.
+///
+/// An empty schedule sends the whole fee to the admin, so it is normalized into
+/// a single admin entry.
+pub fn parse_fee_schedule(
+    vault_data: &VaultCellData,
+    admin_lock_hash: &[u8; 32],
+) -> Result<Vec<([u8; 32], u16)>, Error> {
+    common::fees::parse_fee_schedule(vault_data, admin_lock_hash)
+        .map_err(|_| Error::FeeConfigInvalid)
+}
@@ -0,0 +1,25 @@
+//! The funders ledger rules of `vault-type`.
+//!
+//! The rules live in `common::funders`, which `vault-type` enforces; this
+//! module maps their errors to the SDK's.
+
+use common::schema::vault::VaultCellData;
+
+pub use common::funders::{pro_rata_shares, FunderShare};
+
+use crate::Error;
+
+/// Parses the funders ledger from the vault data.
+///
+/// An empty ledger means the creator funded the whole vault, so it is
+/// normalized into a single creator entry owning `vault_capacity`. Entries of
+/// a non-empty ledger must be unique and non-zero, and add up to
+/// `vault_capacity`.
+pub fn parse_funders(
+    vault_data: &VaultCellData,
+    creator_lock_hash: &[u8; 32],
+    vault_capacity: u64,
+) -> Result<Vec<FunderShare>, Error> {
+    common::funders::load_funders(vault_data, creator_lock_hash, vault_capacity)
+        .map_err(|_| Error::FundersInvalid)
+}
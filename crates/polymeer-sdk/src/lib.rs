@@ -0,0 +1,34 @@
+//! Off-chain transaction builders for the platform's contracts.
+//!
+//! Every builder produces a `TransactionView` that satisfies the rules of the
+//! contracts it touches. Balancing is left to the caller: builders never add
+//! the inputs paying the transaction fee, nor change outputs. The caller also
+//! adds the input of the account that authorizes the action, such as the
+//! admin for a fan-out or the creator for a refund, and signs the transaction.
+
+pub mod bundle;
+pub mod claim;
+pub mod deployment;
+pub mod error;
+pub mod fan_out;
+pub mod fees;
+pub mod funders;
+pub mod merkle;
+pub mod planner;
+pub mod proof;
+pub mod reclaim;
+pub mod refund;
+pub mod vault;
+pub mod vesting;
+
+mod util;
+
+pub use claim::ClaimBuilder;
+pub use deployment::{DeployedScript, Deployment, LiveCell};
+pub use error::Error;
+pub use fan_out::FanOutBuilder;
+pub use planner::{ShardPlan, ShardPlanner, ShardTarget};
+pub use reclaim::ReclaimBuilder;
+pub use refund::RefundBuilder;
+pub use vault::VaultBuilder;
+pub use vesting::VestingWithdrawalBuilder;
@@ -0,0 +1,48 @@
+//! The Merkle trees committed in distribution shards.
+//!
+//! Parents hash their two children in ascending order and odd nodes are paired
+//! with themselves. Roots, proofs and leaves are computed with
+//! `common::merkle`, the routines the contracts verify them with.
+
+use ckb_types::prelude::Pack;
+pub use common::merkle::{blake2b_256, merkle_proof, merkle_root};
+use common::{base::Byte32, schema::distribution::Referral};
+use molecule::prelude::{Builder, Entity};
+
+/// Returns the referral a claimant's leaf commits to.
+pub fn referral(referrer_lock_hash: &[u8; 32], bonus_amount: u64) -> Referral {
+    Referral::new_builder()
+        .referrer_lock_hash(Byte32::from(*referrer_lock_hash))
+        .bonus_amount(bonus_amount.pack())
+        .build()
+}
+
+/// Returns the leaf of a claimant identified by its proof Type ID and lock
+/// hash, with the referral it commits to, if any.
+pub fn claimant_leaf(
+    proof_type_id: &[u8; 32],
+    subscriber_lock_hash: &[u8; 32],
+    referral: Option<&Referral>,
+) -> [u8; 32] {
+    common::merkle::claimant_leaf(
+        proof_type_id,
+        subscriber_lock_hash,
+        referral.map(|referral| referral.as_slice()),
+    )
+}
+
+/// Returns the leaf of a private claimant, which commits to the hash of a
+/// secret in place of its lock hash.
+pub fn private_claimant_leaf(
+    proof_type_id: &[u8; 32],
+    secret: &[u8; 32],
+    referral: Option<&Referral>,
+) -> [u8; 32] {
+    claimant_leaf(proof_type_id, &blake2b_256(secret), referral)
+}
+
+/// Computes the entity root a vault targeting `entity_ids` expects in its
+/// shards. The ids are the leaves themselves.
+pub fn entity_root(entity_ids: &[[u8; 32]]) -> [u8; 32] {
+    merkle_root(entity_ids)
+}
@@ -0,0 +1,526 @@
+//! Deterministic partitioning of claimants into distribution shards.
+//!
+//! A plan satisfies `vault-type` by construction: every shard holds exactly
+//! `claims * uniform_reward_amount + referral_budget`, so the final claim of
+//! each shard empties it exactly, and the shards plus the fee add up to the
+//! vault capacity. What an even split of the rewards cannot place is the
+//! remainder, which is added to the referral budget of the first shard and
+//! returned to the admin with the last claim of that shard.
+//!
+//! Every cell a claim creates must also cover the capacity it occupies: the
+//! reward cell, every referral payout, the remainder returned to the admin,
+//! and the shard left holding its last claim.
+
+use ckb_types::{bytes::Bytes, core::Capacity, packed::CellOutput, prelude::*};
+use common::{
+    schema::{distribution::DistributionCellData, vesting::VestingLockArgs},
+    version::parse_vault_data,
+    NULL_HASH,
+};
+use molecule::prelude::Entity;
+
+use crate::{merkle::merkle_root, Deployment, Error, LiveCell};
+
+/// The capacity a cell locked by the default secp256k1-blake160 lock occupies,
+/// in Shannons: 8 bytes of capacity, 33 of code hash and hash type, and 20 of
+/// args.
+pub const DEFAULT_PAYOUT_OCCUPIED_CAPACITY: u64 = 61 * 100_000_000;
+
+/// Returns the capacity a distribution shard cell occupies, in Shannons.
+pub fn shard_occupied_capacity(deployment: &Deployment) -> u64 {
+    let shard = CellOutput::new_builder()
+        .lock(deployment.distribution_lock.script(Bytes::new()))
+        .type_(Some(deployment.distribution_type.script(Bytes::new())).pack())
+        .build();
+    // The shard data is a fixed-size struct behind the version byte.
+    let data_capacity = Capacity::bytes(1 + DistributionCellData::TOTAL_SIZE).unwrap();
+    shard
+        .occupied_capacity(data_capacity)
+        .expect("shard occupied capacity")
+        .as_u64()
+}
+
+/// Returns the capacity a reward cell of the vault's campaign occupies, in
+/// Shannons. A campaign that vests its rewards pays them into vesting-lock
+/// cells; otherwise a reward is a payout cell of `payout_occupied_capacity`.
+pub fn reward_occupied_capacity(
+    deployment: &Deployment,
+    vault: &LiveCell,
+    payout_occupied_capacity: u64,
+) -> Result<u64, Error> {
+    let vault_data = parse_vault_data(&vault.data).ok_or(Error::CellDataInvalid("vault"))?;
+    if vault_data.vesting().lock_code_hash().as_slice() == NULL_HASH {
+        return Ok(payout_occupied_capacity);
+    }
+
+    let reward = CellOutput::new_builder()
+        .lock(
+            deployment
+                .vesting_lock
+                .script(VestingLockArgs::default().as_bytes()),
+        )
+        .build();
+    Ok(reward
+        .occupied_capacity(Capacity::zero())
+        .expect("reward occupied capacity")
+        .as_u64())
+}
+
+/// How many shards to create.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShardTarget {
+    /// Exactly this many shards, or one per claimant if there are fewer.
+    Count(usize),
+    /// As few shards as possible holding at most this many claimants each,
+    /// which bounds the contention on every shard.
+    ClaimsPerShard(usize),
+}
+
+/// A claimant as the planner sees it: its Merkle leaf and the referral bonus
+/// the leaf commits to, if any.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PlannedLeaf {
+    pub leaf: [u8; 32],
+    pub bonus_amount: u64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlannedShard {
+    /// The indices of the shard's claimants in the planner's input, in leaf
+    /// order.
+    pub claimants: Vec<usize>,
+    /// The leaves of the shard, in the order the Merkle tree is built from.
+    pub leaves: Vec<[u8; 32]>,
+    pub merkle_root: [u8; 32],
+    pub referral_budget: u64,
+    pub capacity: u64,
+}
+
+impl PlannedShard {
+    pub fn claims(&self) -> u32 {
+        self.leaves.len() as u32
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShardPlan {
+    pub uniform_reward_amount: u64,
+    pub fee: u64,
+    pub shards: Vec<PlannedShard>,
+    /// The capacity an even split of the rewards cannot place. It is part of
+    /// the first shard's referral budget.
+    pub remainder: u64,
+}
+
+/// Plans the shards of a distribution.
+pub struct ShardPlanner {
+    vault_capacity: u64,
+    fee: u64,
+    target: ShardTarget,
+    shard_occupied_capacity: u64,
+    reward_occupied_capacity: u64,
+    payout_occupied_capacity: u64,
+}
+
+impl ShardPlanner {
+    /// `fee` is the platform fee owed on the vault, as computed by
+    /// `fees::expected_fee`.
+    pub fn new(vault_capacity: u64, fee: u64, target: ShardTarget) -> Self {
+        Self {
+            vault_capacity,
+            fee,
+            target,
+            shard_occupied_capacity: 0,
+            reward_occupied_capacity: 0,
+            payout_occupied_capacity: 0,
+        }
+    }
+
+    /// Sets the capacity a shard cell occupies, in Shannons, as computed by
+    /// `shard_occupied_capacity`. A shard left with its last claim must still
+    /// cover it.
+    pub fn shard_occupied_capacity(mut self, shard_occupied_capacity: u64) -> Self {
+        self.shard_occupied_capacity = shard_occupied_capacity;
+        self
+    }
+
+    /// Sets the capacity a reward cell occupies, in Shannons, as computed by
+    /// `reward_occupied_capacity`. The reward must cover it.
+    pub fn reward_occupied_capacity(mut self, reward_occupied_capacity: u64) -> Self {
+        self.reward_occupied_capacity = reward_occupied_capacity;
+        self
+    }
+
+    /// Sets the capacity a payout cell occupies, in Shannons. Every referral
+    /// bonus must cover it, and so must a non-zero remainder, which is returned
+    /// to the admin in a cell of its own.
+    pub fn payout_occupied_capacity(mut self, payout_occupied_capacity: u64) -> Self {
+        self.payout_occupied_capacity = payout_occupied_capacity;
+        self
+    }
+
+    /// Partitions `claimants` into shards.
+    ///
+    /// Claimants are sorted by leaf first, so the plan only depends on the set
+    /// of claimants, not on their order. Shards are as even as possible: their
+    /// sizes differ by at most one.
+    pub fn plan(&self, claimants: &[PlannedLeaf]) -> Result<ShardPlan, Error> {
+        if claimants.is_empty() {
+            return Err(Error::PlanInfeasible("no claimants"));
+        }
+
+        let mut order: Vec<usize> = (0..claimants.len()).collect();
+        order.sort_by_key(|&index| claimants[index].leaf);
+        if order
+            .windows(2)
+            .any(|pair| claimants[pair[0]].leaf == claimants[pair[1]].leaf)
+        {
+            return Err(Error::PlanInfeasible("duplicate claimant leaves"));
+        }
+
+        let shard_count = match self.target {
+            ShardTarget::Count(0) | ShardTarget::ClaimsPerShard(0) => {
+                return Err(Error::PlanInfeasible("the shard target must be positive"));
+            }
+            ShardTarget::Count(count) => count.min(claimants.len()),
+            ShardTarget::ClaimsPerShard(claims) => claimants.len().div_ceil(claims),
+        };
+
+        if claimants.iter().any(|claimant| {
+            claimant.bonus_amount > 0 && claimant.bonus_amount < self.payout_occupied_capacity
+        }) {
+            return Err(Error::PlanInfeasible(
+                "a referral bonus does not cover the occupied capacity of a payout cell",
+            ));
+        }
+
+        let referral_budget = claimants
+            .iter()
+            .try_fold(0u64, |total, claimant| {
+                total.checked_add(claimant.bonus_amount)
+            })
+            .ok_or(Error::PlanInfeasible("the referral bonuses overflow"))?;
+        let distributable = self
+            .vault_capacity
+            .checked_sub(self.fee)
+            .and_then(|capacity| capacity.checked_sub(referral_budget))
+            .ok_or(Error::PlanInfeasible(
+                "the vault cannot cover the fee and the referral bonuses",
+            ))?;
+
+        let total_claims = claimants.len() as u64;
+        let mut uniform_reward_amount = distributable / total_claims;
+        let mut remainder = distributable % total_claims;
+
+        // The remainder comes back to the admin in a cell of its own, so it
+        // must be large enough to exist. Lowering the reward grows it.
+        if remainder > 0 && remainder < self.payout_occupied_capacity {
+            let reduction = (self.payout_occupied_capacity - remainder).div_ceil(total_claims);
+            uniform_reward_amount = uniform_reward_amount.saturating_sub(reduction);
+            remainder += reduction * total_claims;
+        }
+        if uniform_reward_amount == 0 || uniform_reward_amount < self.reward_occupied_capacity {
+            return Err(Error::PlanInfeasible(
+                "the reward does not cover the occupied capacity of a reward cell",
+            ));
+        }
+
+        // The first `extra` shards take one more claimant than the others.
+        let base_size = claimants.len() / shard_count;
+        let extra = claimants.len() % shard_count;
+        let mut shards = Vec::with_capacity(shard_count);
+        let mut start = 0;
+        for shard_index in 0..shard_count {
+            let size = base_size + usize::from(shard_index < extra);
+            let members = order[start..start + size].to_vec();
+            start += size;
+
+            let leaves: Vec<[u8; 32]> = members.iter().map(|&i| claimants[i].leaf).collect();
+            let mut referral_budget: u64 = members.iter().map(|&i| claimants[i].bonus_amount).sum();
+            if shard_index == 0 {
+                referral_budget += remainder;
+            }
+
+            // Bonuses are paid as they are claimed, so a shard left with its
+            // last claim may hold as little as the reward, the smallest bonus
+            // of its claimants and its share of the remainder.
+            let last_claim_capacity = uniform_reward_amount
+                + members
+                    .iter()
+                    .map(|&i| claimants[i].bonus_amount)
+                    .min()
+                    .unwrap_or(0)
+                + if shard_index == 0 { remainder } else { 0 };
+            if last_claim_capacity < self.shard_occupied_capacity {
+                return Err(Error::PlanInfeasible("a shard cannot hold its last claim"));
+            }
+
+            shards.push(PlannedShard {
+                merkle_root: merkle_root(&leaves),
+                capacity: size as u64 * uniform_reward_amount + referral_budget,
+                claimants: members,
+                leaves,
+                referral_budget,
+            });
+        }
+
+        let plan = ShardPlan {
+            uniform_reward_amount,
+            fee: self.fee,
+            shards,
+            remainder,
+        };
+        plan.verify(self.vault_capacity)?;
+
+        Ok(plan)
+    }
+}
+
+impl ShardPlan {
+    /// Checks the plan against the capacity rules of `vault-type` and
+    /// `distribution-type`.
+    pub fn verify(&self, vault_capacity: u64) -> Result<(), Error> {
+        let mut total: u128 = self.fee as u128;
+        for shard in self.shards.iter() {
+            let expected = shard.claims() as u128 * self.uniform_reward_amount as u128
+                + shard.referral_budget as u128;
+            if shard.leaves.is_empty() || shard.capacity as u128 != expected {
+                return Err(Error::PlanInfeasible(
+                    "a shard capacity does not match its claims",
+                ));
+            }
+            total += shard.capacity as u128;
+        }
+
+        if total != vault_capacity as u128 {
+            return Err(Error::CapacityMismatch {
+                expected: vault_capacity,
+                actual: total.min(u64::MAX as u128) as u64,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn claimants(bonuses: &[u64]) -> Vec<PlannedLeaf> {
+        bonuses
+            .iter()
+            .enumerate()
+            .map(|(i, &bonus_amount)| PlannedLeaf {
+                leaf: [i as u8 + 1; 32],
+                bonus_amount,
+            })
+            .collect()
+    }
+
+    fn infeasible(planner: ShardPlanner, claimants: &[PlannedLeaf]) -> &'static str {
+        match planner.plan(claimants) {
+            Err(Error::PlanInfeasible(reason)) => reason,
+            other => panic!("expected an infeasible plan, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn remainder_goes_to_the_first_shard() {
+        let claimants = claimants(&[0, 20, 0, 0, 0]);
+        let plan = ShardPlanner::new(1_000, 7, ShardTarget::Count(2))
+            .plan(&claimants)
+            .unwrap();
+
+        // 1000 - 7 - 20 = 973 = 5 * 194 + 3
+        assert_eq!(plan.uniform_reward_amount, 194);
+        assert_eq!(plan.remainder, 3);
+        assert_eq!(plan.shards.len(), 2);
+        assert_eq!(plan.shards[0].claimants, vec![0, 1, 2]);
+        assert_eq!(plan.shards[0].referral_budget, 23);
+        assert_eq!(plan.shards[0].capacity, 3 * 194 + 23);
+        assert_eq!(plan.shards[1].claimants, vec![3, 4]);
+        assert_eq!(plan.shards[1].referral_budget, 0);
+        assert_eq!(plan.shards[1].capacity, 2 * 194);
+        for shard in plan.shards.iter() {
+            assert_eq!(shard.merkle_root, merkle_root(&shard.leaves));
+        }
+        plan.verify(1_000).unwrap();
+    }
+
+    #[test]
+    fn small_remainder_lowers_the_reward() {
+        let plan = ShardPlanner::new(1_000, 0, ShardTarget::Count(1))
+            .payout_occupied_capacity(10)
+            .plan(&claimants(&[0, 0, 0]))
+            .unwrap();
+
+        // 1000 = 3 * 333 + 1, and a remainder of 1 cannot be paid out.
+        assert_eq!(plan.uniform_reward_amount, 330);
+        assert_eq!(plan.remainder, 10);
+        assert_eq!(plan.shards[0].referral_budget, 10);
+        plan.verify(1_000).unwrap();
+    }
+
+    #[test]
+    fn plans_do_not_depend_on_the_claimant_order() {
+        let mut claimants = claimants(&[0, 5, 0, 0, 5, 0, 0]);
+        let planner = ShardPlanner::new(10_000, 0, ShardTarget::ClaimsPerShard(3));
+        let plan = planner.plan(&claimants).unwrap();
+        assert_eq!(
+            plan.shards.iter().map(|s| s.claims()).collect::<Vec<_>>(),
+            vec![3, 2, 2]
+        );
+
+        claimants.reverse();
+        let reversed = planner.plan(&claimants).unwrap();
+        assert_eq!(plan.uniform_reward_amount, reversed.uniform_reward_amount);
+        for (shard, reversed) in plan.shards.iter().zip(reversed.shards.iter()) {
+            assert_eq!(shard.merkle_root, reversed.merkle_root);
+            assert_eq!(shard.capacity, reversed.capacity);
+        }
+    }
+
+    #[test]
+    fn shard_count_is_capped_by_the_claimants() {
+        let plan = ShardPlanner::new(1_000, 0, ShardTarget::Count(5))
+            .plan(&claimants(&[0, 0]))
+            .unwrap();
+        assert_eq!(plan.shards.len(), 2);
+    }
+
+    #[test]
+    fn malformed_inputs_are_infeasible() {
+        let planner = || ShardPlanner::new(1_000, 0, ShardTarget::Count(1));
+        assert_eq!(infeasible(planner(), &[]), "no claimants");
+
+        let mut duplicates = claimants(&[0, 0]);
+        duplicates[1].leaf = duplicates[0].leaf;
+        assert_eq!(
+            infeasible(planner(), &duplicates),
+            "duplicate claimant leaves"
+        );
+
+        assert_eq!(
+            infeasible(
+                ShardPlanner::new(1_000, 0, ShardTarget::ClaimsPerShard(0)),
+                &claimants(&[0])
+            ),
+            "the shard target must be positive"
+        );
+        assert_eq!(
+            infeasible(planner(), &claimants(&[u64::MAX, 1])),
+            "the referral bonuses overflow"
+        );
+        assert_eq!(
+            infeasible(
+                ShardPlanner::new(1_000, 600, ShardTarget::Count(1)),
+                &claimants(&[500])
+            ),
+            "the vault cannot cover the fee and the referral bonuses"
+        );
+    }
+
+    #[test]
+    fn bonuses_must_cover_a_payout_cell() {
+        let planner = || ShardPlanner::new(1_000, 0, ShardTarget::Count(1));
+        assert_eq!(
+            infeasible(planner().payout_occupied_capacity(61), &claimants(&[60, 0])),
+            "a referral bonus does not cover the occupied capacity of a payout cell"
+        );
+        planner()
+            .payout_occupied_capacity(61)
+            .plan(&claimants(&[61, 0]))
+            .unwrap();
+    }
+
+    #[test]
+    fn rewards_must_cover_a_reward_cell() {
+        // 1000 = 10 * 100, but a vesting reward cell occupies more.
+        let planner = || ShardPlanner::new(1_000, 0, ShardTarget::Count(2));
+        let claimants = claimants(&[0; 10]);
+        assert_eq!(
+            infeasible(planner().reward_occupied_capacity(101), &claimants),
+            "the reward does not cover the occupied capacity of a reward cell"
+        );
+        planner()
+            .reward_occupied_capacity(100)
+            .plan(&claimants)
+            .unwrap();
+
+        // Growing the remainder to a payout cell can push the reward below it.
+        assert_eq!(
+            infeasible(
+                ShardPlanner::new(1_001, 0, ShardTarget::Count(1))
+                    .reward_occupied_capacity(100)
+                    .payout_occupied_capacity(50),
+                &claimants
+            ),
+            "the reward does not cover the occupied capacity of a reward cell"
+        );
+    }
+
+    #[test]
+    fn shards_must_hold_their_last_claim() {
+        // 1000 - 400 = 2 * 300, and the last claim may leave 300 + 200 behind.
+        let planner = || ShardPlanner::new(1_000, 0, ShardTarget::Count(1));
+        let claimants = claimants(&[200, 200]);
+        assert_eq!(
+            infeasible(planner().shard_occupied_capacity(501), &claimants),
+            "a shard cannot hold its last claim"
+        );
+        planner()
+            .shard_occupied_capacity(500)
+            .plan(&claimants)
+            .unwrap();
+
+        // The remainder stays in the first shard until its last claim: 1000
+        // = 3 * 330 + 10 once the remainder covers a payout cell.
+        let planner =
+            || ShardPlanner::new(1_000, 0, ShardTarget::Count(1)).payout_occupied_capacity(10);
+        let claimants = self::claimants(&[0, 0, 0]);
+        planner()
+            .shard_occupied_capacity(340)
+            .plan(&claimants)
+            .unwrap();
+        assert_eq!(
+            infeasible(planner().shard_occupied_capacity(341), &claimants),
+            "a shard cannot hold its last claim"
+        );
+    }
+
+    #[test]
+    fn verify_rejects_tampered_plans() {
+        let plan = ShardPlanner::new(1_000, 10, ShardTarget::Count(2))
+            .plan(&claimants(&[0, 0, 0, 0]))
+            .unwrap();
+        plan.verify(1_000).unwrap();
+
+        assert_eq!(
+            plan.verify(1_001),
+            Err(Error::CapacityMismatch {
+                expected: 1_001,
+                actual: 1_000
+            })
+        );
+
+        let mut tampered = plan.clone();
+        tampered.shards[1].capacity += 1;
+        assert_eq!(
+            tampered.verify(1_001),
+            Err(Error::PlanInfeasible(
+                "a shard capacity does not match its claims"
+            ))
+        );
+
+        let mut tampered = plan.clone();
+        tampered.shards[1].leaves.clear();
+        tampered.shards[1].capacity = 0;
+        assert_eq!(
+            tampered.verify(1_000 - plan.shards[1].capacity),
+            Err(Error::PlanInfeasible(
+                "a shard capacity does not match its claims"
+            ))
+        );
+    }
+}
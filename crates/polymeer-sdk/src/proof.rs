@@ -0,0 +1,30 @@
+//! Proof cells.
+
+use ckb_types::{bytes::Bytes, packed::Script};
+use common::{base::Byte32, schema::proof::ProofTypeArgs};
+use molecule::prelude::{Builder, Entity};
+
+use crate::Deployment;
+
+/// Returns the proof type args: the proof cell's Type ID followed by the
+/// deployment's entity registry, which campaigns pin, its distribution type,
+/// whose shards can consume the proof in a claim, and its proof lock.
+pub fn proof_type_args(deployment: &Deployment, type_id: &[u8; 32]) -> Bytes {
+    ProofTypeArgs::new_builder()
+        .type_id(Byte32::from(*type_id))
+        .entity_registry(deployment.entity_type.packed_script_ref())
+        .distribution_type(deployment.distribution_type.packed_script_ref())
+        .proof_lock(deployment.proof_lock.packed_script_ref())
+        .build()
+        .as_bytes()
+}
+
+/// Returns the proof lock guarding a proof on behalf of the subscriber whose
+/// lock hash is `subscriber_lock_hash`. Unlike the subscriber's own lock, it
+/// lets anyone clean the proof up once it has expired, refunding the
+/// subscriber.
+pub fn proof_lock(deployment: &Deployment, subscriber_lock_hash: &[u8; 32]) -> Script {
+    deployment
+        .proof_lock
+        .script(Bytes::copy_from_slice(subscriber_lock_hash))
+}
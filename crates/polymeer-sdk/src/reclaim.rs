@@ -0,0 +1,74 @@
+//! Reclamation of a distribution shard after its deadline.
+
+use ckb_types::{
+    bytes::Bytes,
+    core::{TransactionBuilder, TransactionView},
+    packed::Script,
+    prelude::*,
+};
+use common::{schema::distribution::DistributionCellData, version::parse_distribution_data};
+
+use crate::{
+    util::{lock_hash, payout_cell},
+    Deployment, Error, LiveCell,
+};
+
+/// The `since` flags of an absolute lock on the median block timestamp.
+const SINCE_ABSOLUTE_TIMESTAMP: u64 = 0x4000_0000_0000_0000;
+
+/// Builds the transaction returning what is left in a shard to the admin once
+/// its deadline has passed.
+///
+/// The shard input is locked until the deadline through its `since`, so the
+/// caller must add a header dep proving the chain has reached it.
+pub struct ReclaimBuilder {
+    deployment: Deployment,
+    shard: LiveCell,
+    shard_data: DistributionCellData,
+    admin_lock: Script,
+    since_timestamp: u64,
+}
+
+impl ReclaimBuilder {
+    pub fn new(deployment: Deployment, shard: LiveCell, admin_lock: Script) -> Result<Self, Error> {
+        let shard_data =
+            parse_distribution_data(&shard.data).ok_or(Error::CellDataInvalid("distribution"))?;
+        if lock_hash(&admin_lock) != <[u8; 32]>::from(shard_data.admin_lock_hash()) {
+            return Err(Error::LockMissing(shard_data.admin_lock_hash().into()));
+        }
+        let since_timestamp = shard_data.deadline().unpack();
+
+        Ok(Self {
+            deployment,
+            shard,
+            shard_data,
+            admin_lock,
+            since_timestamp,
+        })
+    }
+
+    /// Sets the timestamp, in seconds, the shard input is locked until. It
+    /// defaults to the deadline and cannot be earlier.
+    pub fn since_timestamp(mut self, since_timestamp: u64) -> Self {
+        self.since_timestamp = since_timestamp;
+        self
+    }
+
+    pub fn build(&self) -> Result<TransactionView, Error> {
+        let deadline: u64 = self.shard_data.deadline().unpack();
+        if self.since_timestamp < deadline {
+            return Err(Error::ClaimInvalid("the shard deadline has not passed"));
+        }
+
+        Ok(TransactionBuilder::default()
+            .cell_dep(self.deployment.distribution_lock.cell_dep.clone())
+            .cell_dep(self.deployment.distribution_type.cell_dep.clone())
+            .input(
+                self.shard
+                    .input(SINCE_ABSOLUTE_TIMESTAMP | self.since_timestamp),
+            )
+            .output(payout_cell(self.admin_lock.clone(), self.shard.capacity()))
+            .output_data(Bytes::new().pack())
+            .build())
+    }
+}
@@ -0,0 +1,165 @@
+//! Refunds of a vault to its funders.
+
+use std::collections::HashMap;
+
+use ckb_types::{
+    bytes::Bytes,
+    core::{TransactionBuilder, TransactionView},
+    packed::Script,
+    prelude::*,
+};
+use common::{
+    base::Byte32,
+    schema::vault::{Funder, FunderVec, VaultCellData},
+    version::parse_vault_data,
+};
+use molecule::prelude::{Builder, Entity};
+
+use crate::{
+    funders::{parse_funders, pro_rata_shares, FunderShare},
+    util::{lock_hash, payout_cell, versioned},
+    vault::parse_vault_lock_args,
+    Deployment, Error, LiveCell,
+};
+
+/// Builds the transaction refunding a vault.
+///
+/// Without any amount set, the whole vault is refunded and every funder is paid
+/// its pro-rata share. Otherwise only the given funders are paid back, and the
+/// vault stays with a reduced capacity and ledger. The caller adds the
+/// creator's input authorizing the refund.
+pub struct RefundBuilder {
+    deployment: Deployment,
+    vault: LiveCell,
+    vault_data: VaultCellData,
+    funders: Vec<FunderShare>,
+    locks: HashMap<[u8; 32], Script>,
+    refunds: Vec<([u8; 32], u64)>,
+}
+
+impl RefundBuilder {
+    pub fn new(deployment: Deployment, vault: LiveCell) -> Result<Self, Error> {
+        let vault_data = parse_vault_data(&vault.data).ok_or(Error::CellDataInvalid("vault"))?;
+
+        let vault_lock_args = parse_vault_lock_args(&deployment, &vault.output.lock())?;
+        let creator_lock_hash: [u8; 32] = vault_lock_args.creator_lock_hash().into();
+        let funders = parse_funders(&vault_data, &creator_lock_hash, vault.capacity())?;
+
+        Ok(Self {
+            deployment,
+            vault,
+            vault_data,
+            funders,
+            locks: HashMap::new(),
+            refunds: Vec::new(),
+        })
+    }
+
+    /// Returns the funders ledger, with the creator owning the whole vault when
+    /// the vault has no ledger.
+    pub fn funders(&self) -> &[FunderShare] {
+        &self.funders
+    }
+
+    /// Provides the lock script of a funder to be paid.
+    pub fn funder_lock(mut self, lock: Script) -> Self {
+        self.locks.insert(lock_hash(&lock), lock);
+        self
+    }
+
+    /// Refunds `amount` to the funder of `lock_hash` only, keeping the vault.
+    pub fn partial(mut self, lock_hash: [u8; 32], amount: u64) -> Self {
+        self.refunds.push((lock_hash, amount));
+        self
+    }
+
+    pub fn build(&self) -> Result<TransactionView, Error> {
+        if self.refunds.is_empty() {
+            return self.build_full_refund();
+        }
+
+        let mut ledger = self.funders.clone();
+        for (lock_hash, amount) in self.refunds.iter() {
+            let entry = ledger
+                .iter_mut()
+                .find(|(hash, _)| hash == lock_hash)
+                .ok_or(Error::FundersInvalid)?;
+            entry.1 = entry.1.checked_sub(*amount).ok_or(Error::FundersInvalid)?;
+        }
+
+        let refunded: u64 = self.refunds.iter().map(|(_, amount)| *amount).sum();
+        let vault_capacity = self.vault.capacity() - refunded;
+
+        // A vault without a ledger stays without one, as the creator keeps
+        // owning all of it.
+        let vault_data = if self.vault_data.funders().is_empty() {
+            self.vault_data.clone()
+        } else {
+            let funders =
+                ledger
+                    .iter()
+                    .filter(|(_, amount)| *amount > 0)
+                    .map(|(lock_hash, amount)| {
+                        Funder::new_builder()
+                            .lock_hash(Byte32::from(*lock_hash))
+                            .amount(amount.pack())
+                            .build()
+                    });
+            self.vault_data
+                .clone()
+                .as_builder()
+                .funders(FunderVec::new_builder().extend(funders).build())
+                .build()
+        };
+
+        let mut outputs = vec![self
+            .vault
+            .output
+            .clone()
+            .as_builder()
+            .capacity(vault_capacity.pack())
+            .build()];
+        let mut outputs_data = vec![versioned(&vault_data)];
+        for (lock_hash, amount) in self.refunds.iter() {
+            outputs.push(payout_cell(self.lock(lock_hash)?, *amount));
+            outputs_data.push(Bytes::new());
+        }
+
+        Ok(self.transaction(outputs, outputs_data))
+    }
+
+    fn build_full_refund(&self) -> Result<TransactionView, Error> {
+        let mut outputs = Vec::new();
+        let mut outputs_data = Vec::new();
+        for (lock_hash, share) in pro_rata_shares(&self.funders, self.vault.capacity()) {
+            if share == 0 {
+                continue;
+            }
+            outputs.push(payout_cell(self.lock(&lock_hash)?, share));
+            outputs_data.push(Bytes::new());
+        }
+
+        Ok(self.transaction(outputs, outputs_data))
+    }
+
+    fn lock(&self, lock_hash: &[u8; 32]) -> Result<Script, Error> {
+        self.locks
+            .get(lock_hash)
+            .cloned()
+            .ok_or(Error::LockMissing(*lock_hash))
+    }
+
+    fn transaction(
+        &self,
+        outputs: Vec<ckb_types::packed::CellOutput>,
+        outputs_data: Vec<Bytes>,
+    ) -> TransactionView {
+        TransactionBuilder::default()
+            .cell_dep(self.deployment.vault_lock.cell_dep.clone())
+            .cell_dep(self.deployment.vault_type.cell_dep.clone())
+            .input(self.vault.input(0))
+            .outputs(outputs)
+            .outputs_data(outputs_data.pack())
+            .build()
+    }
+}
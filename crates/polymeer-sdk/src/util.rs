@@ -0,0 +1,21 @@
+use ckb_types::{bytes::Bytes, packed::CellOutput, packed::Script, prelude::*};
+use common::version::to_versioned_bytes;
+use molecule::prelude::Entity;
+
+/// Serializes cell data behind the latest data version byte.
+pub fn versioned<T: Entity>(data: &T) -> Bytes {
+    to_versioned_bytes(data).into()
+}
+
+/// Builds a plain cell paying `capacity` to `lock`.
+pub fn payout_cell(lock: Script, capacity: u64) -> CellOutput {
+    CellOutput::new_builder()
+        .capacity(capacity.pack())
+        .lock(lock)
+        .build()
+}
+
+/// Returns the hash the contracts identify `lock` by.
+pub fn lock_hash(lock: &Script) -> [u8; 32] {
+    lock.calc_script_hash().unpack()
+}
@@ -0,0 +1,294 @@
+//! Vault creation.
+
+use ckb_types::{
+    bytes::Bytes,
+    core::{TransactionBuilder, TransactionView},
+    packed::{CellOutput, Script},
+    prelude::*,
+};
+use common::{
+    base::{Byte32, Byte32Vec, ScriptRef, VestingConfig},
+    schema::vault::{
+        FeeBeneficiary, FeeBeneficiaryVec, Funder, FunderVec, VaultCellData, VaultLockArgs,
+        VaultTypeArgs,
+    },
+    NULL_HASH,
+};
+use molecule::prelude::{Builder, Byte, Entity};
+
+use crate::{
+    fees::{parse_fee_schedule, verify_fee_capacity, verify_fee_model, FeeModel},
+    funders::parse_funders,
+    merkle::entity_root,
+    util::versioned,
+    Deployment, Error,
+};
+
+/// The maximum number of entity ids a vault can list directly. Longer lists
+/// must be committed to through an entity root instead.
+pub const MAX_ENTITY_IDS: usize = 16;
+
+/// Returns the vault lock args: the creator's lock hash and the admin's.
+pub fn vault_lock_args(creator_lock_hash: &[u8; 32], admin_lock_hash: &[u8; 32]) -> Bytes {
+    VaultLockArgs::new_builder()
+        .creator_lock_hash((*creator_lock_hash).into())
+        .admin_lock_hash((*admin_lock_hash).into())
+        .build()
+        .as_bytes()
+}
+
+/// Returns the vault type args: the references to the distribution lock and
+/// the distribution type the vault fans out to.
+pub fn vault_type_args(deployment: &Deployment) -> Bytes {
+    VaultTypeArgs::new_builder()
+        .distribution_lock(deployment.distribution_lock.packed_script_ref())
+        .distribution_type(deployment.distribution_type.packed_script_ref())
+        .build()
+        .as_bytes()
+}
+
+/// Parses the args of `vault_lock`, which must run the vault lock of
+/// `deployment`.
+pub fn parse_vault_lock_args(
+    deployment: &Deployment,
+    vault_lock: &Script,
+) -> Result<VaultLockArgs, Error> {
+    if !deployment.vault_lock.is_script(vault_lock) {
+        return Err(Error::ScriptArgsInvalid("vault lock"));
+    }
+    VaultLockArgs::from_slice(&vault_lock.args().raw_data())
+        .map_err(|_| Error::ScriptArgsInvalid("vault lock"))
+}
+
+/// Returns the entity root every shard of the vault must carry, checking the
+/// entity targeting the way `vault-type` does.
+pub fn expected_entity_root(vault_data: &VaultCellData) -> Result<[u8; 32], Error> {
+    let root: [u8; 32] = vault_data.entity_root().into();
+    let entity_ids = vault_data.entity_ids();
+
+    if entity_ids.is_empty() {
+        return Ok(root);
+    }
+    if root != NULL_HASH || entity_ids.len() > MAX_ENTITY_IDS {
+        return Err(Error::EntityTargetingInvalid);
+    }
+
+    let mut leaves: Vec<[u8; 32]> = Vec::with_capacity(entity_ids.len());
+    for entity_id in entity_ids.into_iter() {
+        let entity_id: [u8; 32] = entity_id.into();
+        if entity_id == NULL_HASH || leaves.contains(&entity_id) {
+            return Err(Error::EntityTargetingInvalid);
+        }
+        leaves.push(entity_id);
+    }
+
+    Ok(entity_root(&leaves))
+}
+
+/// Builds the transaction creating a vault.
+///
+/// The vault is locked by `vault-lock` for the creator and the admin, and its
+/// data is checked against the rules of `vault-type` before building. The
+/// caller adds the inputs funding the vault.
+pub struct VaultBuilder {
+    deployment: Deployment,
+    creator_lock_hash: [u8; 32],
+    admin_lock_hash: [u8; 32],
+    campaign_id: Option<[u8; 32]>,
+    capacity: Option<u64>,
+    fee_percentage: u16,
+    fee_model: FeeModel,
+    fee_schedule: Vec<([u8; 32], u16)>,
+    funders: Vec<([u8; 32], u64)>,
+    vesting: Option<(u64, u64)>,
+    entity_root: [u8; 32],
+    entity_ids: Vec<[u8; 32]>,
+    entity_creator_lock_hash: [u8; 32],
+}
+
+impl VaultBuilder {
+    pub fn new(
+        deployment: Deployment,
+        creator_lock_hash: [u8; 32],
+        admin_lock_hash: [u8; 32],
+    ) -> Self {
+        Self {
+            deployment,
+            creator_lock_hash,
+            admin_lock_hash,
+            campaign_id: None,
+            capacity: None,
+            fee_percentage: 0,
+            fee_model: FeeModel::default(),
+            fee_schedule: Vec::new(),
+            funders: Vec::new(),
+            vesting: None,
+            entity_root: NULL_HASH,
+            entity_ids: Vec::new(),
+            entity_creator_lock_hash: NULL_HASH,
+        }
+    }
+
+    pub fn campaign_id(mut self, campaign_id: [u8; 32]) -> Self {
+        self.campaign_id = Some(campaign_id);
+        self
+    }
+
+    /// Sets the vault capacity, in Shannons.
+    pub fn capacity(mut self, capacity: u64) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Sets the fee percentage in basis points (e.g., 500 for 5.00%).
+    pub fn fee_percentage(mut self, fee_percentage: u16) -> Self {
+        self.fee_percentage = fee_percentage;
+        self
+    }
+
+    pub fn fee_model(mut self, fee_model: FeeModel) -> Self {
+        self.fee_model = fee_model;
+        self
+    }
+
+    /// Adds a beneficiary of the platform fee. Without any, the admin receives
+    /// the whole fee.
+    pub fn fee_beneficiary(mut self, lock_hash: [u8; 32], basis_points: u16) -> Self {
+        self.fee_schedule.push((lock_hash, basis_points));
+        self
+    }
+
+    /// Adds an entry to the funders ledger. Without any, the creator owns the
+    /// whole vault.
+    pub fn funder(mut self, lock_hash: [u8; 32], amount: u64) -> Self {
+        self.funders.push((lock_hash, amount));
+        self
+    }
+
+    /// Makes rewards vest linearly between the two epochs, through the
+    /// deployment's vesting lock.
+    pub fn vesting(mut self, start_epoch: u64, end_epoch: u64) -> Self {
+        self.vesting = Some((start_epoch, end_epoch));
+        self
+    }
+
+    pub fn entity_root(mut self, entity_root: [u8; 32]) -> Self {
+        self.entity_root = entity_root;
+        self
+    }
+
+    pub fn entity_ids(mut self, entity_ids: Vec<[u8; 32]>) -> Self {
+        self.entity_ids = entity_ids;
+        self
+    }
+
+    /// Scopes the campaign to the content `creator_lock_hash` published in the
+    /// deployment's entity registry.
+    pub fn entity_creator(mut self, creator_lock_hash: [u8; 32]) -> Self {
+        self.entity_creator_lock_hash = creator_lock_hash;
+        self
+    }
+
+    /// Returns the data of the vault cell, checked against `vault-type`.
+    pub fn vault_data(&self) -> Result<VaultCellData, Error> {
+        let campaign_id = self.campaign_id.ok_or(Error::MissingField("campaign_id"))?;
+        let capacity = self.capacity.ok_or(Error::MissingField("capacity"))?;
+
+        let (vesting_lock_ref, start_epoch, end_epoch) = match self.vesting {
+            Some((start_epoch, end_epoch)) => {
+                if end_epoch <= start_epoch {
+                    return Err(Error::VestingInvalid);
+                }
+                (
+                    self.deployment.vesting_lock.packed_script_ref(),
+                    start_epoch,
+                    end_epoch,
+                )
+            }
+            None => (ScriptRef::default(), 0, 0),
+        };
+        let proof_script_ref = self.deployment.proof_type.script_ref();
+
+        let vault_data = VaultCellData::new_builder()
+            .campaign_id(Byte32::from(campaign_id))
+            .fee_percentage(self.fee_percentage.pack())
+            .proof_script_code_hash(Byte32::from_slice(&proof_script_ref[..32]).unwrap())
+            .proof_script_hash_type(Byte::new(proof_script_ref[32]))
+            .funders(
+                FunderVec::new_builder()
+                    .extend(self.funders.iter().map(|(lock_hash, amount)| {
+                        Funder::new_builder()
+                            .lock_hash(Byte32::from(*lock_hash))
+                            .amount(amount.pack())
+                            .build()
+                    }))
+                    .build(),
+            )
+            .fee_schedule(
+                FeeBeneficiaryVec::new_builder()
+                    .extend(self.fee_schedule.iter().map(|(lock_hash, basis_points)| {
+                        FeeBeneficiary::new_builder()
+                            .lock_hash(Byte32::from(*lock_hash))
+                            .basis_points(basis_points.pack())
+                            .build()
+                    }))
+                    .build(),
+            )
+            .fee_model(self.fee_model.to_molecule())
+            .vesting(
+                VestingConfig::new_builder()
+                    .lock_code_hash(vesting_lock_ref.code_hash())
+                    .lock_hash_type(vesting_lock_ref.hash_type())
+                    .start_epoch(start_epoch.pack())
+                    .end_epoch(end_epoch.pack())
+                    .build(),
+            )
+            .entity_root(Byte32::from(self.entity_root))
+            .entity_ids(
+                Byte32Vec::new_builder()
+                    .extend(self.entity_ids.iter().copied().map(Byte32::from))
+                    .build(),
+            )
+            .entity_registry(self.deployment.entity_type.packed_script_ref())
+            .entity_creator_lock_hash(Byte32::from(self.entity_creator_lock_hash))
+            .build();
+
+        if campaign_id == NULL_HASH {
+            return Err(Error::CellDataInvalid("vault"));
+        }
+        let fee_model = verify_fee_model(&vault_data)?;
+        verify_fee_capacity(&fee_model, capacity)?;
+        parse_fee_schedule(&vault_data, &self.admin_lock_hash)?;
+        expected_entity_root(&vault_data)?;
+        parse_funders(&vault_data, &self.creator_lock_hash, capacity)?;
+
+        Ok(vault_data)
+    }
+
+    pub fn build(&self) -> Result<TransactionView, Error> {
+        let vault_data = self.vault_data()?;
+        let capacity = self.capacity.ok_or(Error::MissingField("capacity"))?;
+
+        let vault_output = CellOutput::new_builder()
+            .capacity(capacity.pack())
+            .lock(self.deployment.vault_lock.script(vault_lock_args(
+                &self.creator_lock_hash,
+                &self.admin_lock_hash,
+            )))
+            .type_(
+                Some(
+                    self.deployment
+                        .vault_type
+                        .script(vault_type_args(&self.deployment)),
+                )
+                .pack(),
+            )
+            .build();
+
+        Ok(TransactionBuilder::default()
+            .cell_dep(self.deployment.vault_type.cell_dep.clone())
+            .output(vault_output)
+            .output_data(versioned(&vault_data).pack())
+            .build())
+    }
+}
@@ -0,0 +1,155 @@
+//! Withdrawals of vested rewards.
+
+use ckb_types::{
+    bytes::Bytes,
+    core::{Capacity, EpochNumberWithFraction, TransactionBuilder, TransactionView},
+    packed::{CellOutput, Script},
+    prelude::*,
+};
+use common::{
+    schema::vesting::VestingLockArgs,
+    vesting::{relock_args, relock_start_epoch, same_position, unvested_amount_of},
+};
+use molecule::prelude::Entity;
+
+use crate::{
+    util::{lock_hash, payout_cell},
+    Deployment, Error, LiveCell,
+};
+
+/// The `since` flags of an absolute lock on the epoch number.
+const SINCE_ABSOLUTE_EPOCH: u64 = 0x2000_0000_0000_0000;
+
+/// Builds the transaction withdrawing what has vested from vesting cells that
+/// release to the same beneficiary by the same end epoch.
+///
+/// The unvested amount is relocked in a single vesting cell whose release
+/// restarts at the withdrawal epoch, and the rest is paid to the beneficiary.
+/// A relock of less than the cell's occupied capacity holds the occupied
+/// capacity instead.
+/// The vesting inputs are locked until that epoch through their `since`. The
+/// caller adds the beneficiary's input authorizing the withdrawal.
+pub struct VestingWithdrawalBuilder {
+    deployment: Deployment,
+    cells: Vec<(LiveCell, VestingLockArgs)>,
+    beneficiary_lock: Script,
+    epoch: u64,
+}
+
+impl VestingWithdrawalBuilder {
+    pub fn new(
+        deployment: Deployment,
+        cells: Vec<LiveCell>,
+        beneficiary_lock: Script,
+    ) -> Result<Self, Error> {
+        let beneficiary_lock_hash = lock_hash(&beneficiary_lock);
+
+        let mut vesting_cells: Vec<(LiveCell, VestingLockArgs)> = Vec::with_capacity(cells.len());
+        for cell in cells {
+            let lock = cell.output.lock();
+            if !deployment.vesting_lock.is_script(&lock) {
+                return Err(Error::ScriptArgsInvalid("vesting lock"));
+            }
+            let args = VestingLockArgs::from_slice(&lock.args().raw_data())
+                .map_err(|_| Error::ScriptArgsInvalid("vesting lock"))?;
+            if <[u8; 32]>::from(args.beneficiary_lock_hash()) != beneficiary_lock_hash {
+                return Err(Error::LockMissing(args.beneficiary_lock_hash().into()));
+            }
+            if let Some((_, first_args)) = vesting_cells.first() {
+                if !same_position(first_args, &args) {
+                    return Err(Error::ScriptArgsInvalid("vesting lock"));
+                }
+            }
+            vesting_cells.push((cell, args));
+        }
+        if vesting_cells.is_empty() {
+            return Err(Error::MissingField("vesting cells"));
+        }
+
+        Ok(Self {
+            deployment,
+            cells: vesting_cells,
+            beneficiary_lock,
+            epoch: 0,
+        })
+    }
+
+    /// Sets the epoch the withdrawal is made at. The vesting inputs are locked
+    /// until it, so the chain must have reached it. Nothing has vested at the
+    /// default epoch 0.
+    pub fn epoch(mut self, epoch: u64) -> Self {
+        self.epoch = epoch;
+        self
+    }
+
+    /// Returns the amount that has not vested at the withdrawal epoch.
+    pub fn unvested(&self) -> u64 {
+        self.cells.iter().fold(0u64, |unvested, (_, args)| {
+            unvested.saturating_add(unvested_amount_of(args, self.epoch))
+        })
+    }
+
+    pub fn build(&self) -> Result<TransactionView, Error> {
+        let since = if self.epoch == 0 {
+            0
+        } else {
+            SINCE_ABSOLUTE_EPOCH | EpochNumberWithFraction::new(self.epoch, 0, 1).full_value()
+        };
+        let capacity: u64 = self.cells.iter().map(|(cell, _)| cell.capacity()).sum();
+
+        let mut tx = TransactionBuilder::default()
+            .cell_dep(self.deployment.vesting_lock.cell_dep.clone())
+            .inputs(self.cells.iter().map(|(cell, _)| cell.input(since)));
+
+        let unvested = self.unvested();
+        let mut payout = capacity;
+        if unvested > 0 {
+            let latest_start_epoch = self
+                .cells
+                .iter()
+                .map(|(_, args)| args.start_epoch().unpack())
+                .max()
+                .unwrap_or_default();
+            let args = relock_args(
+                &self.cells[0].1,
+                unvested,
+                relock_start_epoch(latest_start_epoch, self.epoch),
+            );
+            let relock = payout_cell(self.deployment.vesting_lock.script(args.as_bytes()), 0);
+
+            // The relock holds at least the unvested amount, and at least what
+            // the cell itself occupies.
+            let relock_capacity = unvested.max(occupied_capacity(&relock));
+            if capacity < relock_capacity {
+                return Err(Error::CapacityMismatch {
+                    expected: relock_capacity,
+                    actual: capacity,
+                });
+            }
+            tx = tx
+                .output(relock.as_builder().capacity(relock_capacity.pack()).build())
+                .output_data(Bytes::new().pack());
+            payout -= relock_capacity;
+        }
+        if payout > 0 {
+            let payout_cell = payout_cell(self.beneficiary_lock.clone(), payout);
+            let payout_occupied_capacity = occupied_capacity(&payout_cell);
+            if payout < payout_occupied_capacity {
+                return Err(Error::CapacityMismatch {
+                    expected: payout_occupied_capacity,
+                    actual: payout,
+                });
+            }
+            tx = tx.output(payout_cell).output_data(Bytes::new().pack());
+        }
+
+        Ok(tx.build())
+    }
+}
+
+/// Returns the capacity `output` occupies without data.
+fn occupied_capacity(output: &CellOutput) -> u64 {
+    output
+        .occupied_capacity(Capacity::zero())
+        .map_or(u64::MAX, |capacity| capacity.as_u64())
+}
@@ -37,6 +37,7 @@ export function buildCccClient(network: Network) {
 type MyScript =
   | "distribution-lock"
   | "distribution-type"
+  | "entity-type"
   | "proof-type"
   | "vault-lock"
   | "vault-type";
@@ -1,10 +1,15 @@
 import { CellOutput, OutPoint, Transaction, WitnessArgs } from "@ckb-ccc/core";
 import { ClaimWitness, DistributionData } from "./type";
 import { getMyScript } from "./ccc-client";
-import { logTx } from "./utils";
+import {
+  logTx,
+  hashStringToByte32,
+  fromVersioned,
+  toVersioned,
+} from "./utils";
 import { buildMerkleProof, hashLeaf } from "./merkle";
 import { subscriberSigner } from "./dependencies";
-import { getClaimants } from "./info";
+import { data, getClaimants } from "./info";
 
 export async function claimDistribution(
   distOutPoint: OutPoint,
@@ -33,12 +38,17 @@ export async function claimDistribution(
     throw new Error("Proof cell not found");
   }
 
-  const distData = DistributionData.decode(distCell.outputData);
+  const distData = DistributionData.decode(fromVersioned(distCell.outputData));
   const distCapacity = BigInt(distCell.cellOutput.capacity);
   const rewardAmount = BigInt(distData.uniform_reward_amount);
   const proofCapacity = BigInt(proofCell.cellOutput.capacity);
 
-  const claimants = getClaimants(proofOutPoint, subscriberLock.hash());
+  // the Type ID is followed by the entity registry code hash in the args
+  const proofTypeId = proofCell.cellOutput.type?.args.slice(0, 66);
+  if (!proofTypeId) {
+    throw new Error("Proof cell has no type script");
+  }
+  const claimants = getClaimants(proofTypeId, subscriberLock.hash());
 
   // Find the claimant's index to generate the correct proof
   const claimantIndex = claimants.findIndex(
@@ -52,7 +62,7 @@ export async function claimDistribution(
 
   const leaves = claimants.map((c) =>
     hashLeaf(
-      OutPoint.encode(c.proofOutPoint),
+      Buffer.from(c.proofTypeId.slice(2), "hex"),
       Buffer.from(c.lockHash.slice(2), "hex")
     )
   );
@@ -61,10 +71,15 @@ export async function claimDistribution(
   const claimWitness = ClaimWitness.encode({
     merkle_proof: merkleProof.map((p) => "0x" + Buffer.from(p).toString("hex")),
     subscriber_lock_hash: subscriberLock.hash(),
-    proof_cell_out_point: {
-      tx_hash: proofOutPoint.txHash,
-      index: proofOutPoint.index,
+    proof_type_id: proofTypeId,
+    referral: undefined,
+    private_claim: undefined,
+    proof_reveal: {
+      proof: hashStringToByte32(data.proof),
+      salt: hashStringToByte32(data.proofSalt),
     },
+    // the shard targets no entity
+    entity_proof: [],
   });
 
   const outputs: Transaction["outputs"] = [];
@@ -81,7 +96,14 @@ export async function claimDistribution(
         capacity: distCapacity - rewardAmount,
       })
     );
-    outputsData.push(distCell.outputData);
+    outputsData.push(
+      toVersioned(
+        DistributionData.encode({
+          ...distData,
+          remaining_claims: Number(distData.remaining_claims) - 1,
+        })
+      )
+    );
   }
 
   // Add reward cell
@@ -1,7 +1,7 @@
 import { OutPoint, Script, Transaction } from "@ckb-ccc/core";
 import { VaultData, DistributionData } from "./type";
 import { getMyScript } from "./ccc-client";
-import { logTx } from "./utils";
+import { fromVersioned, logTx, toVersioned } from "./utils";
 import { buildMerkleRoot, hashLeaf } from "./merkle";
 import { adminSigner, subscriberSigner } from "./dependencies";
 import { getClaimants } from "./info";
@@ -18,7 +18,6 @@ export async function createDistribution(
   const vaultTypeContract = getMyScript("vault-type");
   const distLockContract = getMyScript("distribution-lock");
   const distTypeContract = getMyScript("distribution-type");
-  const proofContract = getMyScript("proof-type");
 
   const vaultCell = await adminSigner.client.getCellLive(vaultOutPoint, true);
   if (!vaultCell) {
@@ -33,19 +32,28 @@ export async function createDistribution(
     throw new Error("Proof cell not found");
   }
 
-  const claimants = getClaimants(proofOutPoint, subscriberLock.hash());
+  // the Type ID is followed by the entity registry code hash in the args
+  const proofTypeId = proofCell.cellOutput.type?.args.slice(0, 66);
+  if (!proofTypeId) {
+    throw new Error("Proof cell has no type script");
+  }
+  const claimants = getClaimants(proofTypeId, subscriberLock.hash());
 
-  const vaultData = VaultData.decode(vaultCell.outputData);
+  const vaultData = VaultData.decode(fromVersioned(vaultCell.outputData));
   const vaultCapacity = BigInt(vaultCell.cellOutput.capacity);
 
   const feePercentage = BigInt(vaultData.fee_percentage);
   const feeCapacity = (vaultCapacity * feePercentage) / 10000n;
   const totalRewardCapacity = vaultCapacity - feeCapacity;
   const uniformRewardAmount = totalRewardCapacity / BigInt(claimants.length);
+  if (uniformRewardAmount * BigInt(claimants.length) !== totalRewardCapacity) {
+    // The shard capacity must be exactly `remaining_claims` rewards.
+    throw new Error("Reward pool is not evenly divisible by the number of claimants");
+  }
 
   const leaves = claimants.map((c) =>
     hashLeaf(
-      OutPoint.encode(c.proofOutPoint),
+      Buffer.from(c.proofTypeId.slice(2), "hex"),
       Buffer.from(c.lockHash.slice(2), "hex")
     )
   );
@@ -55,9 +63,15 @@ export async function createDistribution(
     campaign_id: vaultData.campaign_id,
     admin_lock_hash: adminLock.hash(),
     merkle_root: "0x" + Buffer.from(merkleRoot).toString("hex"),
-    proof_script_code_hash: proofContract.codeHash,
+    proof_script_code_hash: vaultData.proof_script_code_hash,
+    proof_script_hash_type: vaultData.proof_script_hash_type,
     uniform_reward_amount: uniformRewardAmount,
     deadline: BigInt(Math.floor(Date.now() / 1000) + 900), // 15 minutes from now, in seconds
+    remaining_claims: claimants.length,
+    vesting: vaultData.vesting,
+    referral_budget: 0,
+    // the vault targets no entity, so the root stays null
+    entity_root: vaultData.entity_root,
   });
 
   const distShardOutput = {
@@ -97,7 +111,7 @@ export async function createDistribution(
       },
     ],
     outputs: [distShardOutput, feeOutput],
-    outputsData: [distData, "0x"],
+    outputsData: [toVersioned(distData), "0x"],
   });
 
   await tx.completeFeeBy(adminSigner);
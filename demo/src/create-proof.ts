@@ -1,24 +1,47 @@
-import { Transaction } from "@ckb-ccc/core";
+import { OutPoint, Transaction } from "@ckb-ccc/core";
 import { ProofData } from "./type";
-import { logTx, generateTypeId, hashStringToByte32 } from "./utils";
+import {
+  logTx,
+  generateTypeId,
+  hashProofCommit,
+  hashStringToByte32,
+  toVersioned,
+} from "./utils";
 import { getMyScript } from "./ccc-client";
 import { subscriberSigner } from "./dependencies";
 import { data } from "./info";
 
-export async function createProof(): Promise<Transaction> {
+export async function createProof(
+  entityOutPoint: OutPoint
+): Promise<Transaction> {
   const lockScript = (await subscriberSigner.getRecommendedAddressObj()).script;
 
   const proofContract = getMyScript("proof-type");
+  const entityContract = getMyScript("entity-type");
 
-  const entityId = hashStringToByte32(data.entityId);
+  const entityCell = await subscriberSigner.client.getCellLive(
+    entityOutPoint,
+    false
+  );
+  if (!entityCell) {
+    throw new Error("Entity cell not found");
+  }
+  // the entity id is the Type ID of its registry cell
+  const entityId = entityCell.cellOutput.type?.args;
+  if (!entityId) {
+    throw new Error("Entity cell has no type script");
+  }
   const campaignId = hashStringToByte32(data.campaignId);
   const proof = hashStringToByte32(data.proof);
+  const salt = hashStringToByte32(data.proofSalt);
 
   const proofData = ProofData.encode({
     entity_id: entityId,
-    campaign_id: campaignId,
-    proof: proof,
+    campaign_ids: [campaignId],
+    proof_commit: hashProofCommit(proof, lockScript.hash(), salt),
     subscriber_lock_hash: lockScript.hash(),
+    claimed_campaigns: "0x00",
+    expiry: 0, // never expires
   });
 
   const tx = Transaction.from({
@@ -32,13 +55,17 @@ export async function createProof(): Promise<Transaction> {
         lock: lockScript,
         type: {
           codeHash: proofContract.codeHash,
-          hashType: "data1",
-          // placeholder for type id will be replaced later
-          args: "0x" + Buffer.from("".padEnd(32, "\0")).toString("hex"),
+          hashType: proofContract.hashType,
+          // placeholder for type id will be replaced later,
+          // followed by the code hash of the entity registry
+          args:
+            "0x" +
+            Buffer.from("".padEnd(32, "\0")).toString("hex") +
+            entityContract.codeHash.slice(2),
         },
       },
     ],
-    outputsData: [proofData],
+    outputsData: [toVersioned(proofData)],
     cellDeps: [
       // no need to add secp256k1_blake160_sighash_all cell dep
       // because it is automatically added by @ckb-ccc/core
@@ -46,6 +73,11 @@ export async function createProof(): Promise<Transaction> {
         outPoint: proofContract.cellDeps[0]!.cellDep.outPoint,
         depType: proofContract.cellDeps[0]!.cellDep.depType,
       },
+      // Entity registry cell
+      {
+        outPoint: entityOutPoint,
+        depType: "code",
+      },
     ],
     witnesses: ["0x"],
   });
@@ -58,7 +90,7 @@ export async function createProof(): Promise<Transaction> {
   }
 
   const typeId = generateTypeId(cellInput, 0);
-  tx.outputs[0]!.type!.args = typeId;
+  tx.outputs[0]!.type!.args = typeId + entityContract.codeHash.slice(2);
 
   logTx(tx);
 
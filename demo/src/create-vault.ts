@@ -1,7 +1,7 @@
-import { Transaction } from "@ckb-ccc/core";
+import { hashTypeToBytes, Transaction } from "@ckb-ccc/core";
 import { VaultData } from "./type";
 import { getMyScript } from "./ccc-client";
-import { hashStringToByte32, logTx } from "./utils";
+import { hashStringToByte32, logTx, toScriptRef, toVersioned } from "./utils";
 import { adminSigner, creatorSigner } from "./dependencies";
 import { data } from "./info";
 
@@ -23,11 +23,19 @@ export async function createVault(amount: bigint) {
     campaign_id: campaignId,
     fee_percentage: feePercentage,
     proof_script_code_hash: proofContract.codeHash,
+    proof_script_hash_type: hashTypeToBytes(proofContract.hashType)[0]!,
+    funders: [],
+    fee_schedule: [],
+    fee_model: { kind: 0, flat_fee: 0, min_fee: 0, max_fee: 0 },
+    vesting: { lock_code_hash: "0x" + "00".repeat(32), start_epoch: 0, end_epoch: 0 },
+    // no entity targeting, proofs for any entity can claim
+    entity_root: "0x" + "00".repeat(32),
+    entity_ids: [],
   });
 
   const vaultLockArgs = creatorLock.hash() + adminLock.hash().slice(2);
   const vaultTypeArgs =
-    distLockContract.codeHash + distTypeContract.codeHash.slice(2);
+    toScriptRef(distLockContract) + toScriptRef(distTypeContract).slice(2);
 
   const tx = Transaction.from({
     cellDeps: [
@@ -61,7 +69,7 @@ export async function createVault(amount: bigint) {
         },
       },
     ],
-    outputsData: [vaultData],
+    outputsData: [toVersioned(vaultData)],
   });
 
   await tx.completeFeeBy(creatorSigner);
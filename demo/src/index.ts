@@ -3,6 +3,7 @@ import { adminSigner, creatorSigner, subscriberSigner } from "./dependencies";
 
 import { meltCell } from "./melt-cell";
 import { createProof } from "./create-proof";
+import { registerEntity } from "./register-entity";
 import { createVault } from "./create-vault";
 import { CKB_UNIT, getOutpoint } from "./utils";
 import { refundVault } from "./refund-vault";
@@ -21,11 +22,21 @@ import { reclaimDistribution } from "./reclaim-distribution";
       console.log("Transaction sent:", result);
     });
 
+  program
+    .command("register-entity")
+    .description("Register the entity in the registry (as creator)")
+    .action(async () => {
+      const tx = await registerEntity();
+      const result = await creatorSigner.sendTransaction(tx);
+      console.log("Transaction sent:", result);
+    });
+
   program
     .command("create-proof")
     .description("Submit a proof cell (as subscriber)")
-    .action(async () => {
-      const tx = await createProof();
+    .argument("<entity>", "entity registry cell outpoint (e.g., 0x...:0)")
+    .action(async (entityStr) => {
+      const tx = await createProof(getOutpoint(entityStr));
       const result = await subscriberSigner.sendTransaction(tx);
       console.log("Transaction sent:", result);
     });
@@ -81,11 +81,16 @@ export function buildMerkleProof(
 }
 
 export function hashLeaf(
-  outPoint: Uint8Array,
-  lockHash: Uint8Array
+  proofTypeId: Uint8Array,
+  lockHash: Uint8Array,
+  referral?: Uint8Array
 ): Uint8Array {
-  const combined = new Uint8Array(outPoint.length + lockHash.length);
-  combined.set(outPoint, 0);
-  combined.set(lockHash, outPoint.length);
+  const referralBytes = referral ?? new Uint8Array(0);
+  const combined = new Uint8Array(
+    proofTypeId.length + lockHash.length + referralBytes.length
+  );
+  combined.set(proofTypeId, 0);
+  combined.set(lockHash, proofTypeId.length);
+  combined.set(referralBytes, proofTypeId.length + lockHash.length);
   return hash(combined);
 }
@@ -1,7 +1,7 @@
 import { OutPoint, Since, Transaction } from "@ckb-ccc/core";
 import { getMyScript } from "./ccc-client";
 import { adminSigner } from "./dependencies";
-import { logTx } from "./utils";
+import { fromVersioned, logTx } from "./utils";
 import { DistributionData } from "./type";
 
 export async function reclaimDistribution(outPoint: OutPoint) {
@@ -15,7 +15,7 @@ export async function reclaimDistribution(outPoint: OutPoint) {
     throw new Error("Distribution cell not found");
   }
 
-  const distData = DistributionData.decode(distCell.outputData);
+  const distData = DistributionData.decode(fromVersioned(distCell.outputData));
   const deadline = BigInt(distData.deadline);
 
   const tx = Transaction.from({
@@ -0,0 +1,68 @@
+import { Transaction } from "@ckb-ccc/core";
+import { EntityData } from "./type";
+import { logTx, generateTypeId, hashStringToByte32 } from "./utils";
+import { getMyScript } from "./ccc-client";
+import { creatorSigner } from "./dependencies";
+import { data } from "./info";
+
+export async function registerEntity(): Promise<Transaction> {
+  const lockScript = (await creatorSigner.getRecommendedAddressObj()).script;
+
+  const entityContract = getMyScript("entity-type");
+
+  const tx = Transaction.from({
+    version: "0x0",
+    headerDeps: [],
+    outputs: [
+      // Entity registry cell
+      {
+        // no need to add capacity
+        // because it is automatically calculated by @ckb-ccc/core
+        lock: lockScript,
+        type: {
+          codeHash: entityContract.codeHash,
+          hashType: "data1",
+          // placeholder for type id will be replaced later
+          args: "0x" + Buffer.from("".padEnd(32, "\0")).toString("hex"),
+        },
+      },
+    ],
+    // placeholder for entity data will be replaced later
+    outputsData: [
+      EntityData.encode({
+        creator_lock_hash: lockScript.hash(),
+        entity_id: "0x" + "00".repeat(32),
+        metadata_hash: hashStringToByte32(data.entityId),
+      }),
+    ],
+    cellDeps: [
+      // no need to add secp256k1_blake160_sighash_all cell dep
+      // because it is automatically added by @ckb-ccc/core
+      {
+        outPoint: entityContract.cellDeps[0]!.cellDep.outPoint,
+        depType: entityContract.cellDeps[0]!.cellDep.depType,
+      },
+    ],
+    witnesses: ["0x"],
+  });
+
+  await tx.completeFeeBy(creatorSigner);
+
+  const cellInput = tx.inputs[0];
+  if (!cellInput) {
+    throw new Error("No input found");
+  }
+
+  // the entity id is the Type ID of the registry cell
+  const typeId = generateTypeId(cellInput, 0);
+  tx.outputs[0]!.type!.args = typeId;
+  tx.outputsData[0] = EntityData.encode({
+    creator_lock_hash: lockScript.hash(),
+    entity_id: typeId,
+    metadata_hash: hashStringToByte32(data.entityId),
+  });
+
+  logTx(tx);
+
+  return tx;
+}
@@ -1,34 +1,98 @@
 import { mol } from "@ckb-ccc/core";
 
+export const VestingConfig = mol.struct({
+  lock_code_hash: mol.Byte32,
+  start_epoch: mol.Uint64,
+  end_epoch: mol.Uint64,
+});
+
 export const DistributionData = mol.struct({
   campaign_id: mol.Byte32,
   admin_lock_hash: mol.Byte32,
   merkle_root: mol.Byte32,
   proof_script_code_hash: mol.Byte32,
+  proof_script_hash_type: mol.Uint8,
   uniform_reward_amount: mol.Uint64,
   deadline: mol.Uint64,
+  remaining_claims: mol.Uint32,
+  vesting: VestingConfig,
+  referral_budget: mol.Uint64,
+  entity_root: mol.Byte32,
+});
+
+export const Referral = mol.struct({
+  referrer_lock_hash: mol.Byte32,
+  bonus_amount: mol.Uint64,
+});
+
+export const PrivateClaim = mol.struct({
+  secret: mol.Byte32,
+  destination_lock_hash: mol.Byte32,
 });
 
-export const OutPoint = mol.struct({
-  tx_hash: mol.Byte32,
-  index: mol.Uint32,
+export const ProofReveal = mol.struct({
+  proof: mol.Byte32,
+  salt: mol.Byte32,
 });
 
 export const ClaimWitness = mol.table({
   merkle_proof: mol.Byte32Vec,
   subscriber_lock_hash: mol.Byte32,
-  proof_cell_out_point: OutPoint,
+  proof_type_id: mol.Byte32,
+  referral: mol.option(Referral),
+  private_claim: mol.option(PrivateClaim),
+  proof_reveal: ProofReveal,
+  entity_proof: mol.Byte32Vec,
 });
 
-export const ProofData = mol.struct({
+export const ProofData = mol.table({
   entity_id: mol.Byte32,
-  campaign_id: mol.Byte32,
-  proof: mol.Byte32,
+  campaign_ids: mol.Byte32Vec,
+  proof_commit: mol.Byte32,
   subscriber_lock_hash: mol.Byte32,
+  claimed_campaigns: mol.Bytes,
+  expiry: mol.Uint64,
+});
+
+export const EntityData = mol.struct({
+  creator_lock_hash: mol.Byte32,
+  entity_id: mol.Byte32,
+  metadata_hash: mol.Byte32,
+});
+
+export const Funder = mol.struct({
+  lock_hash: mol.Byte32,
+  amount: mol.Uint64,
+});
+
+export const FeeBeneficiary = mol.struct({
+  lock_hash: mol.Byte32,
+  basis_points: mol.Uint16,
+});
+
+export const FeeModel = mol.struct({
+  kind: mol.Uint8,
+  flat_fee: mol.Uint64,
+  min_fee: mol.Uint64,
+  max_fee: mol.Uint64,
 });
 
-export const VaultData = mol.struct({
+export const VaultData = mol.table({
   campaign_id: mol.Byte32,
   fee_percentage: mol.Uint16,
   proof_script_code_hash: mol.Byte32,
+  proof_script_hash_type: mol.Uint8,
+  funders: mol.vector(Funder),
+  fee_schedule: mol.vector(FeeBeneficiary),
+  fee_model: FeeModel,
+  vesting: VestingConfig,
+  entity_root: mol.Byte32,
+  entity_ids: mol.Byte32Vec,
+});
+
+export const VestingLockArgs = mol.struct({
+  beneficiary_lock_hash: mol.Byte32,
+  locked_amount: mol.Uint64,
+  start_epoch: mol.Uint64,
+  end_epoch: mol.Uint64,
 });
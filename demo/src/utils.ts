@@ -1,5 +1,12 @@
 import {
   mol,
+  bytesConcat,
+  bytesFrom,
+  hexFrom,
+  hashTypeToBytes,
+  HashTypeLike,
+  Bytes,
+  BytesLike,
   CellInput,
   HasherCkb,
   Hex,
@@ -9,6 +16,21 @@ import {
 
 export const CKB_UNIT = 100_000_000n;
 
+// The data of vault, distribution shard and proof cells starts with a version byte
+export const DATA_VERSION = 1;
+
+export function toVersioned(data: BytesLike): Hex {
+  return hexFrom(bytesConcat([DATA_VERSION], data));
+}
+
+export function fromVersioned(data: BytesLike): Bytes {
+  const bytes = bytesFrom(data);
+  if (bytes[0] !== DATA_VERSION) {
+    throw new Error(`Unsupported data version ${bytes[0]}`);
+  }
+  return bytes.slice(1);
+}
+
 export function logTx(tx: Transaction) {
   console.log(
     "tx",
@@ -23,6 +45,18 @@ export function hashStringToByte32(input: string): Hex {
   return hash;
 }
 
+export function hashProofCommit(
+  proof: Hex,
+  subscriberLockHash: Hex,
+  salt: Hex
+): Hex {
+  const hasher = new HasherCkb();
+  hasher.update(proof);
+  hasher.update(subscriberLockHash);
+  hasher.update(salt);
+  return hasher.digest();
+}
+
 export function generateTypeId(cellInput: CellInput, index: number): Hex {
   const hash = new HasherCkb();
   hash.update(cellInput.toBytes());
@@ -31,6 +65,11 @@ export function generateTypeId(cellInput: CellInput, index: number): Hex {
   return result;
 }
 
+// A script reference is its code hash followed by its hash type byte
+export function toScriptRef(script: { codeHash: Hex; hashType: HashTypeLike }): Hex {
+  return hexFrom(bytesConcat(script.codeHash, hashTypeToBytes(script.hashType)));
+}
+
 export function getOutpoint(str: string) {
   const [txHash, index] = str.split(":");
   if (!txHash || !index) {
@@ -1,58 +1,240 @@
-use ckb_testtool::ckb_types::prelude::*;
+use ckb_testtool::ckb_types::{core::ScriptHashType, prelude::*};
 use common::{
-    base::Byte32,
+    base::{Byte32, Byte32Vec, Bytes, ScriptRef, VestingConfig},
     schema::{
-        distribution::{Byte32Vec, ClaimWitness, DistributionCellData, OutPoint},
-        proof::ProofCellData,
-        vault::VaultCellData,
+        distribution::{ClaimWitness, DistributionCellData, PrivateClaim, ProofReveal, Referral},
+        entity::EntityCellData,
+        proof::{ProofCellData, ProofTypeArgs},
+        vault::{FeeBeneficiary, FeeBeneficiaryVec, FeeModel, Funder, FunderVec, VaultCellData},
+        vesting::VestingLockArgs,
     },
+    version::{to_versioned_bytes, DATA_VERSION_V1},
 };
-use molecule::prelude::{Builder, Entity};
+use molecule::prelude::{Builder, Byte, Entity};
+
+use crate::util::blake2b_256;
+
+// The off-chain proof and salt behind the commitment of every test proof cell.
+const PROOF: [u8; 32] = [3; 32];
+const PROOF_SALT: [u8; 32] = [5; 32];
+
+/// Serializes cell data the way the contracts read it, behind the latest data
+/// version byte.
+pub fn to_versioned<T: Entity>(data: &T) -> ckb_testtool::ckb_types::bytes::Bytes {
+    to_versioned_bytes(data).into()
+}
+
+/// Serializes `data` with the version 1 byte in front of it, the way cells
+/// created before data version 2 store it.
+pub fn to_versioned_v1<T: Entity>(data: &T) -> ckb_testtool::ckb_types::bytes::Bytes {
+    let mut versioned = vec![DATA_VERSION_V1];
+    versioned.extend_from_slice(data.as_slice());
+    versioned.into()
+}
 
 pub fn populate_proof_data(subscriber_lock_hash: &Byte32, campaign_id: &Byte32) -> ProofCellData {
+    populate_proof_data_with_campaigns(subscriber_lock_hash, std::slice::from_ref(campaign_id))
+}
+
+pub fn populate_proof_data_with_campaigns(
+    subscriber_lock_hash: &Byte32,
+    campaign_ids: &[Byte32],
+) -> ProofCellData {
     let entity_id = Byte32::from_slice(&[1; 32]).unwrap();
-    let proof = Byte32::from_slice(&[3; 32]).unwrap();
+    let proof_commit = populate_proof_commit(&populate_proof_reveal(), subscriber_lock_hash);
 
     ProofCellData::new_builder()
         .entity_id(entity_id)
-        .campaign_id(campaign_id.clone())
-        .proof(proof)
+        .campaign_ids(
+            Byte32Vec::new_builder()
+                .extend(campaign_ids.iter().cloned())
+                .build(),
+        )
+        .proof_commit(proof_commit)
         .subscriber_lock_hash(subscriber_lock_hash.clone())
+        .claimed_campaigns(populate_claimed_campaigns(campaign_ids.len(), &[]))
+        .build()
+}
+
+pub fn populate_proof_type_args(
+    type_id: &Byte32,
+    entity_registry: &ScriptRef,
+    distribution_type: &ScriptRef,
+) -> ckb_testtool::ckb_types::bytes::Bytes {
+    populate_proof_type_args_with_proof_lock(
+        type_id,
+        entity_registry,
+        distribution_type,
+        &ScriptRef::default(),
+    )
+}
+
+pub fn populate_proof_type_args_with_proof_lock(
+    type_id: &Byte32,
+    entity_registry: &ScriptRef,
+    distribution_type: &ScriptRef,
+    proof_lock: &ScriptRef,
+) -> ckb_testtool::ckb_types::bytes::Bytes {
+    let args = ProofTypeArgs::new_builder()
+        .type_id(type_id.clone())
+        .entity_registry(entity_registry.clone())
+        .distribution_type(distribution_type.clone())
+        .proof_lock(proof_lock.clone())
+        .build();
+
+    args.as_slice().to_vec().into()
+}
+
+pub fn populate_claimed_campaigns(campaigns_count: usize, claimed: &[usize]) -> Bytes {
+    let mut bitmap = vec![0u8; campaigns_count.div_ceil(8)];
+    for index in claimed {
+        bitmap[index / 8] |= 1 << (index % 8);
+    }
+
+    Bytes::new_builder()
+        .extend(bitmap.into_iter().map(Byte::new))
+        .build()
+}
+
+pub fn populate_entity_data(
+    creator_lock_hash: &Byte32,
+    entity_id: &Byte32,
+    metadata_hash: &Byte32,
+) -> EntityCellData {
+    EntityCellData::new_builder()
+        .creator_lock_hash(creator_lock_hash.clone())
+        .entity_id(entity_id.clone())
+        .metadata_hash(metadata_hash.clone())
+        .build()
+}
+
+pub fn populate_script_ref(code_hash: &Byte32) -> ScriptRef {
+    ScriptRef::new_builder()
+        .code_hash(code_hash.clone())
+        .hash_type(Byte::new(ScriptHashType::Type.into()))
         .build()
 }
 
 pub fn populate_vault_data(
     campaign_id: &Byte32,
     proof_script_code_hash: &Byte32,
+    entity_registry: &ScriptRef,
+    fee_percentage: u16,
+) -> VaultCellData {
+    populate_vault_data_with_funders(
+        campaign_id,
+        proof_script_code_hash,
+        entity_registry,
+        fee_percentage,
+        &[],
+    )
+}
+
+pub fn populate_vault_data_with_funders(
+    campaign_id: &Byte32,
+    proof_script_code_hash: &Byte32,
+    entity_registry: &ScriptRef,
     fee_percentage: u16,
+    funders: &[(Byte32, u64)],
 ) -> VaultCellData {
+    let funders: Vec<Funder> = funders
+        .iter()
+        .map(|(lock_hash, amount)| {
+            Funder::new_builder()
+                .lock_hash(lock_hash.clone())
+                .amount(amount.pack())
+                .build()
+        })
+        .collect();
+
     VaultCellData::new_builder()
         .campaign_id(campaign_id.clone())
         .proof_script_code_hash(proof_script_code_hash.clone())
+        .proof_script_hash_type(Byte::new(ScriptHashType::Type.into()))
         .fee_percentage(fee_percentage.pack())
+        .funders(FunderVec::new_builder().extend(funders).build())
+        .entity_registry(entity_registry.clone())
+        .build()
+}
+
+pub fn populate_fee_schedule(beneficiaries: &[(Byte32, u16)]) -> FeeBeneficiaryVec {
+    let beneficiaries: Vec<FeeBeneficiary> = beneficiaries
+        .iter()
+        .map(|(lock_hash, basis_points)| {
+            FeeBeneficiary::new_builder()
+                .lock_hash(lock_hash.clone())
+                .basis_points(basis_points.pack())
+                .build()
+        })
+        .collect();
+
+    FeeBeneficiaryVec::new_builder()
+        .extend(beneficiaries)
+        .build()
+}
+
+pub fn populate_fee_model(kind: u8, flat_fee: u64, min_fee: u64, max_fee: u64) -> FeeModel {
+    FeeModel::new_builder()
+        .kind(Byte::new(kind))
+        .flat_fee(flat_fee.pack())
+        .min_fee(min_fee.pack())
+        .max_fee(max_fee.pack())
+        .build()
+}
+
+pub fn populate_vesting_config(
+    lock_code_hash: &Byte32,
+    start_epoch: u64,
+    end_epoch: u64,
+) -> VestingConfig {
+    VestingConfig::new_builder()
+        .lock_code_hash(lock_code_hash.clone())
+        .lock_hash_type(Byte::new(ScriptHashType::Type.into()))
+        .start_epoch(start_epoch.pack())
+        .end_epoch(end_epoch.pack())
+        .build()
+}
+
+pub fn populate_vesting_lock_args(
+    beneficiary_lock_hash: &Byte32,
+    locked_amount: u64,
+    start_epoch: u64,
+    end_epoch: u64,
+) -> VestingLockArgs {
+    VestingLockArgs::new_builder()
+        .beneficiary_lock_hash(beneficiary_lock_hash.clone())
+        .locked_amount(locked_amount.pack())
+        .start_epoch(start_epoch.pack())
+        .end_epoch(end_epoch.pack())
         .build()
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn populate_distribution_data(
     campaign_id: &Byte32,
     admin_lock_hash: &Byte32,
     proof_script_code_hash: &Byte32,
+    entity_registry: &ScriptRef,
     merkle_root: &[u8; 32],
     reward_amount: u64,
     deadline: u64,
+    remaining_claims: u32,
 ) -> DistributionCellData {
     DistributionCellData::new_builder()
         .campaign_id(campaign_id.clone())
         .admin_lock_hash(admin_lock_hash.clone())
         .proof_script_code_hash(proof_script_code_hash.clone())
+        .proof_script_hash_type(Byte::new(ScriptHashType::Type.into()))
         .merkle_root(Byte32::from_slice(merkle_root).unwrap())
+        .entity_registry(entity_registry.clone())
         .uniform_reward_amount(reward_amount.pack())
         .deadline(deadline.pack())
+        .remaining_claims(remaining_claims.pack())
         .build()
 }
 
 pub fn populate_claim_witness(
-    proof_cell_out_point: &OutPoint,
+    proof_type_id: &Byte32,
     subscriber_lock_hash: &Byte32,
     merkle_proof: &[[u8; 32]],
 ) -> ClaimWitness {
@@ -62,8 +244,39 @@ pub fn populate_claim_witness(
         .collect();
 
     ClaimWitness::new_builder()
-        .proof_cell_out_point(proof_cell_out_point.clone())
+        .proof_type_id(proof_type_id.clone())
         .subscriber_lock_hash(subscriber_lock_hash.clone())
         .merkle_proof(Byte32Vec::new_builder().extend(proof_vec).build())
+        .proof_reveal(populate_proof_reveal())
+        .build()
+}
+
+/// Commits to the proof and salt of `reveal` for `subscriber_lock_hash`.
+pub fn populate_proof_commit(reveal: &ProofReveal, subscriber_lock_hash: &Byte32) -> Byte32 {
+    let mut commit_data = vec![];
+    commit_data.extend_from_slice(reveal.proof().as_slice());
+    commit_data.extend_from_slice(subscriber_lock_hash.as_slice());
+    commit_data.extend_from_slice(reveal.salt().as_slice());
+    Byte32::from_slice(&blake2b_256(commit_data)).unwrap()
+}
+
+pub fn populate_proof_reveal() -> ProofReveal {
+    ProofReveal::new_builder()
+        .proof(Byte32::from_slice(&PROOF).unwrap())
+        .salt(Byte32::from_slice(&PROOF_SALT).unwrap())
+        .build()
+}
+
+pub fn populate_referral(referrer_lock_hash: &Byte32, bonus_amount: u64) -> Referral {
+    Referral::new_builder()
+        .referrer_lock_hash(referrer_lock_hash.clone())
+        .bonus_amount(bonus_amount.pack())
+        .build()
+}
+
+pub fn populate_private_claim(secret: &Byte32, destination_lock_hash: &Byte32) -> PrivateClaim {
+    PrivateClaim::new_builder()
+        .secret(secret.clone())
+        .destination_lock_hash(destination_lock_hash.clone())
         .build()
 }
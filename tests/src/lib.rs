@@ -8,6 +8,7 @@ use ckb_testtool::{
     },
     context::Context,
 };
+use common::error::{decode_error, ContractScript};
 use std::env;
 use std::fs;
 use std::path::PathBuf;
@@ -15,6 +16,7 @@ use std::str::FromStr;
 
 pub mod data;
 pub mod hash;
+pub mod sdk;
 pub mod util;
 
 #[cfg(test)]
@@ -24,6 +26,11 @@ mod tests;
 // now we use MODE as the environment variable
 const TEST_ENV_VAR: &str = "MODE";
 
+// Points at the build directory of another checkout, e.g. `build/release` of
+// the parent commit, whose contracts the cycle comparisons measure against.
+// The checkout must share the cell formats of this one.
+const BASELINE_ENV_VAR: &str = "BASELINE_BUILD_DIR";
+
 pub enum TestEnv {
     Debug,
     Release,
@@ -85,6 +92,14 @@ impl Loader {
         Loader(base_path)
     }
 
+    /// Returns the loader of the baseline contracts, when `BASELINE_BUILD_DIR`
+    /// is set.
+    pub fn baseline() -> Option<Self> {
+        env::var(BASELINE_ENV_VAR)
+            .ok()
+            .map(|build_dir| Loader(build_dir.into()))
+    }
+
     pub fn load_binary(&self, name: &str) -> Bytes {
         let mut path = self.0.clone();
         path.push(name);
@@ -116,3 +131,28 @@ pub fn verify_and_dump_failed_tx(
     }
     result
 }
+
+/// Returns the exit code of the script that failed verification, if a script
+/// failed.
+pub fn script_error_code(err: &Error) -> Option<i8> {
+    let message = err.to_string();
+    let (_, code) = message.split_once("error code ")?;
+    let code: String = code
+        .chars()
+        .take_while(|c| *c == '-' || c.is_ascii_digit())
+        .collect();
+    code.parse().ok()
+}
+
+/// Asserts that verification failed because `script` exited with the error
+/// named `expected` in the registry of `common::error`.
+pub fn assert_script_error(err: &Error, script: ContractScript, expected: &str) {
+    let code = script_error_code(err).unwrap_or_else(|| panic!("not a script error: {}", err));
+    assert_eq!(
+        decode_error(script, code),
+        expected,
+        "unexpected error code {}: {}",
+        code,
+        err
+    );
+}
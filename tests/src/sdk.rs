@@ -0,0 +1,60 @@
+use ckb_testtool::{
+    ckb_types::{
+        bytes::Bytes,
+        core::{ScriptHashType, TransactionView},
+        packed::{CellDep, CellOutput, OutPoint},
+        prelude::*,
+    },
+    context::Context,
+};
+use polymeer_indexer::Indexer;
+use polymeer_sdk::{DeployedScript, Deployment, LiveCell};
+
+use crate::{hash::get_code_hash, Loader};
+
+/// Deploys every contract the SDK builders use, referenced by Type ID the way
+/// `Context::build_script` does.
+pub fn deploy_contracts(context: &mut Context) -> Deployment {
+    deploy_contracts_from(context, &Loader::default())
+}
+
+/// Deploys the contracts `loader` finds, as `deploy_contracts` does.
+pub fn deploy_contracts_from(context: &mut Context, loader: &Loader) -> Deployment {
+    let mut deploy = |name: &str| {
+        let bin = loader.load_binary(name);
+        let out_point = context.deploy_cell(bin);
+        let cell_dep = CellDep::new_builder().out_point(out_point.clone()).build();
+        let code_hash = get_code_hash(context, &out_point);
+        DeployedScript::new(code_hash, ScriptHashType::Type, cell_dep)
+    };
+
+    Deployment {
+        vault_lock: deploy("vault-lock"),
+        vault_type: deploy("vault-type"),
+        distribution_lock: deploy("distribution-lock"),
+        distribution_type: deploy("distribution-type"),
+        proof_type: deploy("proof-type"),
+        vesting_lock: deploy("vesting-lock"),
+        entity_type: deploy("entity-type"),
+        proof_lock: deploy("proof-lock"),
+    }
+}
+
+/// Creates a live cell in the context, ready to be consumed by a builder.
+pub fn create_live_cell(context: &mut Context, output: CellOutput, data: Bytes) -> LiveCell {
+    let out_point = context.create_cell(output.clone(), data.clone());
+    LiveCell::new(out_point, output, data)
+}
+
+/// Creates the outputs of a verified transaction in the context, so later
+/// transactions can consume them, and feeds the transaction to the indexer.
+pub fn commit_tx(context: &mut Context, indexer: &mut Indexer, tx: &TransactionView) {
+    for (index, (output, data)) in tx.outputs_with_data_iter().enumerate() {
+        let out_point = OutPoint::new_builder()
+            .tx_hash(tx.hash())
+            .index((index as u32).pack())
+            .build();
+        context.create_cell_with_out_point(out_point, output, data);
+    }
+    indexer.index_transaction(tx);
+}
@@ -0,0 +1,228 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_types::{
+        bytes::Bytes,
+        core::{Cycle, HeaderBuilder},
+        packed::*,
+        prelude::*,
+    },
+    context::Context,
+};
+use common::base::Byte32;
+use polymeer_sdk::{ReclaimBuilder, RefundBuilder, VaultBuilder};
+
+use crate::{
+    data::{populate_distribution_data, to_versioned},
+    sdk::{create_live_cell, deploy_contracts_from},
+    Loader,
+};
+
+// The flows below go through the cell loops of `common::contract`. Each one is
+// measured against the contracts of `Loader::baseline`, when it is set, so a
+// change to the shared helpers shows its cycle cost before and after.
+
+/// Runs `flow` with the contracts under test and, when a baseline build is
+/// set, with the baseline contracts, and fails if the cycles grew by more than
+/// 1%.
+fn compare_with_baseline(name: &str, flow: fn(&Loader) -> Cycle) {
+    let cycles = flow(&Loader::default());
+    println!("consume cycles for {}: {}", name, cycles);
+
+    if let Some(baseline) = Loader::baseline() {
+        let baseline_cycles = flow(&baseline);
+        println!(
+            "consume cycles for {} (baseline): {}, difference: {}",
+            name,
+            baseline_cycles,
+            cycles as i64 - baseline_cycles as i64
+        );
+        assert!(
+            cycles <= baseline_cycles + baseline_cycles / 100,
+            "{} went from {} to {} cycles",
+            name,
+            baseline_cycles,
+            cycles
+        );
+    }
+}
+
+/// Refunds a vault to eight funders, which sums the outputs of every funder
+/// with `sum_capacity_by_lock_hash`.
+fn pro_rata_refund(loader: &Loader) -> Cycle {
+    const FUNDERS: u8 = 8;
+
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts_from(&mut context, loader);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts: the creator is the first funder
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![0]))
+        .unwrap();
+    let admin_lock_hash: [u8; 32] = admin_lock_script.calc_script_hash().unpack();
+
+    let funder_lock_scripts: Vec<Script> = (1..=FUNDERS)
+        .map(|funder| {
+            context
+                .build_script(&always_success_out_point, Bytes::from(vec![funder]))
+                .unwrap()
+        })
+        .collect();
+    let creator_lock_script = funder_lock_scripts[0].clone();
+    let creator_lock_hash: [u8; 32] = creator_lock_script.calc_script_hash().unpack();
+
+    // prepare vault: every funder contributed the same amount
+    let funder_amount = 1000 * 100_000_000u64;
+    let vault_capacity = funder_amount * FUNDERS as u64;
+    let mut vault_builder =
+        VaultBuilder::new(deployment.clone(), creator_lock_hash, admin_lock_hash)
+            .campaign_id([1; 32])
+            .capacity(vault_capacity)
+            .fee_percentage(500);
+    for lock_script in funder_lock_scripts.iter() {
+        vault_builder =
+            vault_builder.funder(lock_script.calc_script_hash().unpack(), funder_amount);
+    }
+    let vault_tx = vault_builder.build().expect("build vault");
+    let vault = create_live_cell(
+        &mut context,
+        vault_tx.outputs().get(0).unwrap(),
+        vault_tx.outputs_data().get(0).unwrap().raw_data(),
+    );
+
+    // Add a cell for the creator to pay for fees and provide signature
+    let creator_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(creator_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let creator_fee_input = CellInput::new_builder()
+        .previous_output(creator_fee_input_out_point)
+        .build();
+
+    // build the refund: every funder gets its share back
+    let mut refund_builder = RefundBuilder::new(deployment, vault).expect("load vault");
+    for lock_script in funder_lock_scripts {
+        refund_builder = refund_builder.funder_lock(lock_script);
+    }
+    let tx = refund_builder.build().expect("build refund");
+
+    // balance the transaction with the creator's input and change
+    let creator_change_output = CellOutput::new_builder().lock(creator_lock_script).build();
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .input(creator_fee_input)
+        .output(creator_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    context
+        .verify_tx(&tx, 10_000_000)
+        .expect("pass verification")
+}
+
+/// Reclaims a shard after its deadline, which looks for the payout to the
+/// admin with `find_unique_output`.
+fn reclaim(loader: &Loader) -> Cycle {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts_from(&mut context, loader);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![0]))
+        .unwrap();
+    let admin_lock_hash =
+        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare distribution shard
+    let reward_amount = 100 * 100_000_000u64;
+    let deadline_s = 1_000_000u64;
+    let dist_data = populate_distribution_data(
+        &Byte32::from_slice(&[1; 32]).unwrap(),
+        &admin_lock_hash,
+        &Byte32::from_slice(deployment.proof_type.code_hash.as_slice()).unwrap(),
+        &deployment.entity_type.packed_script_ref(),
+        &[0u8; 32],
+        reward_amount,
+        deadline_s,
+        10,
+    );
+    let shard = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity((reward_amount * 10).pack())
+            .lock(deployment.distribution_lock.script(Bytes::new()))
+            .type_(Some(deployment.distribution_type.script(Bytes::new())).pack())
+            .build(),
+        to_versioned(&dist_data),
+    );
+
+    // Add a cell for the admin to pay for fees
+    let admin_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(admin_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let admin_fee_input = CellInput::new_builder()
+        .previous_output(admin_fee_input_out_point)
+        .build();
+
+    // build the reclamation 10 seconds after the deadline
+    let since_timestamp_s = deadline_s + 10;
+    let tx = ReclaimBuilder::new(deployment, shard, admin_lock_script.clone())
+        .expect("load shard")
+        .since_timestamp(since_timestamp_s)
+        .build()
+        .expect("build reclamation");
+
+    // prepare header dep for `since`
+    let header = HeaderBuilder::default()
+        .timestamp((since_timestamp_s * 1000).pack())
+        .build();
+    context.insert_header(header.clone());
+
+    // balance the transaction with the admin's input and change
+    let admin_change_output = CellOutput::new_builder().lock(admin_lock_script).build();
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .header_dep(header.hash())
+        .input(admin_fee_input)
+        .output(admin_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    context
+        .verify_tx(&tx, 10_000_000)
+        .expect("pass verification")
+}
+
+#[test]
+fn test_cycles_pro_rata_refund() {
+    compare_with_baseline("vault pro-rata refund to 8 funders", pro_rata_refund);
+}
+
+#[test]
+fn test_cycles_reclaim() {
+    compare_with_baseline("distribution reclaim", reclaim);
+}
@@ -1,26 +1,431 @@
 use ckb_testtool::{
     builtin::ALWAYS_SUCCESS,
+    ckb_error::Error,
     ckb_types::{
         bytes::Bytes,
-        core::{HeaderBuilder, TransactionBuilder},
+        core::{Cycle, HeaderBuilder, ScriptHashType, TransactionBuilder},
         packed::*,
         prelude::*,
     },
     context::Context,
 };
-use common::{base::Byte32, schema::distribution::OutPoint};
+use common::{
+    base::{Byte32, Byte32Vec},
+    error::{ContractScript, DistributionLockError, DistributionTypeError, VaultTypeError},
+    schema::distribution::{DistributionCellDataV1, ProofReveal, ReferralOpt},
+    version::{parse_distribution_data, LATEST_DATA_VERSION},
+};
+use polymeer_sdk::{
+    bundle::{
+        shard_bundles, to_out_point, verify_bundle, BundleClaimant, ClaimBundle, ClaimBundleJson,
+    },
+    merkle::{self, claimant_leaf, private_claimant_leaf},
+    planner::{shard_occupied_capacity, PlannedLeaf, DEFAULT_PAYOUT_OCCUPIED_CAPACITY},
+    proof::proof_type_args,
+    ClaimBuilder, FanOutBuilder, ReclaimBuilder, ShardPlanner, ShardTarget, VaultBuilder,
+};
 
 use crate::{
+    assert_script_error,
     data::{
-        populate_claim_witness, populate_distribution_data, populate_proof_data,
-        populate_vault_data,
+        populate_claim_witness, populate_distribution_data, populate_entity_data,
+        populate_fee_model, populate_fee_schedule, populate_proof_commit, populate_proof_data,
+        populate_proof_data_with_campaigns, populate_proof_reveal, populate_proof_type_args,
+        populate_referral, populate_script_ref, populate_vault_data, populate_vesting_config,
+        populate_vesting_lock_args, to_versioned, to_versioned_v1,
     },
     hash::get_code_hash,
+    sdk::{create_live_cell, deploy_contracts},
     util, Loader,
 };
 
 #[test]
 fn test_create_distribution() {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let admin_lock_hash: [u8; 32] = admin_lock_script.calc_script_hash().unpack();
+
+    let creator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let creator_lock_hash: [u8; 32] = creator_lock_script.calc_script_hash().unpack();
+
+    // prepare vault
+    let vault_capacity = 10000 * 100_000_000u64;
+    let fee_percentage = 500u16; // 5.00%
+    let vault_tx = VaultBuilder::new(deployment.clone(), creator_lock_hash, admin_lock_hash)
+        .campaign_id([1; 32])
+        .capacity(vault_capacity)
+        .fee_percentage(fee_percentage)
+        .build()
+        .expect("build vault");
+    let vault = create_live_cell(
+        &mut context,
+        vault_tx.outputs().get(0).unwrap(),
+        vault_tx.outputs_data().get(0).unwrap().raw_data(),
+    );
+
+    // Add a cell for the admin to pay for fees
+    let admin_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(admin_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let admin_fee_input = CellInput::new_builder()
+        .previous_output(admin_fee_input_out_point)
+        .build();
+
+    // build the distribution: two shards of 50 claimants, and the fee to the admin
+    let uniform_reward_amount = 95 * 100_000_000u64;
+    let merkle_root = [1u8; 32];
+    let deadline = 1_000_000u64;
+    let tx = FanOutBuilder::new(deployment, vault)
+        .expect("load vault")
+        .fee_lock(admin_lock_script.clone())
+        .uniform_reward_amount(uniform_reward_amount)
+        .deadline(deadline)
+        .shard(merkle_root, 50)
+        .shard(merkle_root, 50)
+        .build()
+        .expect("build distribution");
+
+    // balance the transaction with the admin's input and change
+    let admin_change_output = CellOutput::new_builder().lock(admin_lock_script).build();
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .input(admin_fee_input)
+        .output(admin_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, 20_000_000)
+        .expect("pass verification");
+    println!("consume cycles for distribution create: {}", cycles);
+}
+
+#[test]
+fn test_create_distribution_remaining_claims_mismatch() {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let admin_lock_hash: [u8; 32] = admin_lock_script.calc_script_hash().unpack();
+
+    let creator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let creator_lock_hash: [u8; 32] = creator_lock_script.calc_script_hash().unpack();
+
+    // prepare vault
+    let vault_capacity = 10000 * 100_000_000u64;
+    let vault_tx = VaultBuilder::new(deployment.clone(), creator_lock_hash, admin_lock_hash)
+        .campaign_id([1; 32])
+        .capacity(vault_capacity)
+        .fee_percentage(500)
+        .build()
+        .expect("build vault");
+    let vault = create_live_cell(
+        &mut context,
+        vault_tx.outputs().get(0).unwrap(),
+        vault_tx.outputs_data().get(0).unwrap().raw_data(),
+    );
+
+    // Add a cell for the admin to pay for fees
+    let admin_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(admin_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let admin_fee_input = CellInput::new_builder()
+        .previous_output(admin_fee_input_out_point)
+        .build();
+
+    // build the distribution: two shards of 50 claimants, and the fee to the admin
+    let tx = FanOutBuilder::new(deployment, vault)
+        .expect("load vault")
+        .fee_lock(admin_lock_script.clone())
+        .uniform_reward_amount(95 * 100_000_000u64)
+        .deadline(1_000_000)
+        .shard([1; 32], 50)
+        .shard([1; 32], 50)
+        .build()
+        .expect("build distribution");
+
+    // promise one more claim in the second shard than its capacity pays for;
+    // the total capacity is unchanged, so only the shard's own check catches it
+    let mut outputs_data: Vec<Bytes> = tx
+        .outputs_data()
+        .into_iter()
+        .map(|data| data.raw_data())
+        .collect();
+    let shard_data = parse_distribution_data(&outputs_data[1]).unwrap();
+    outputs_data[1] = to_versioned(
+        &shard_data
+            .as_builder()
+            .remaining_claims(51u32.pack())
+            .build(),
+    );
+
+    // balance the transaction with the admin's input and change
+    let admin_change_output = CellOutput::new_builder().lock(admin_lock_script).build();
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs_data(outputs_data.into_iter().map(|data| data.pack()).collect())
+        .cell_dep(always_success_dep)
+        .input(admin_fee_input)
+        .output(admin_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context
+        .verify_tx(&tx, 20_000_000)
+        .expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionType,
+        DistributionTypeError::RemainingClaimsInvalid.name(),
+    );
+}
+
+#[test]
+fn test_create_distribution_other_entity_root() {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let admin_lock_hash: [u8; 32] = admin_lock_script.calc_script_hash().unpack();
+
+    let creator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let creator_lock_hash: [u8; 32] = creator_lock_script.calc_script_hash().unpack();
+
+    // prepare a vault targeting two entities
+    let vault_capacity = 10000 * 100_000_000u64;
+    let vault_tx = VaultBuilder::new(deployment.clone(), creator_lock_hash, admin_lock_hash)
+        .campaign_id([1; 32])
+        .capacity(vault_capacity)
+        .fee_percentage(500)
+        .entity_ids(vec![[7; 32], [8; 32]])
+        .build()
+        .expect("build vault");
+    let vault = create_live_cell(
+        &mut context,
+        vault_tx.outputs().get(0).unwrap(),
+        vault_tx.outputs_data().get(0).unwrap().raw_data(),
+    );
+
+    // Add a cell for the admin to pay for fees
+    let admin_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(admin_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let admin_fee_input = CellInput::new_builder()
+        .previous_output(admin_fee_input_out_point)
+        .build();
+
+    // build the distribution: two shards of 50 claimants, and the fee to the admin
+    let tx = FanOutBuilder::new(deployment, vault)
+        .expect("load vault")
+        .fee_lock(admin_lock_script.clone())
+        .uniform_reward_amount(95 * 100_000_000u64)
+        .deadline(1_000_000)
+        .shard([1; 32], 50)
+        .shard([1; 32], 50)
+        .build()
+        .expect("build distribution");
+
+    // let the shards, consistently with each other, target a third entity too
+    let other_entity_root = merkle::entity_root(&[[7; 32], [8; 32], [9; 32]]);
+    let outputs_data: Vec<Bytes> = tx
+        .outputs_data()
+        .into_iter()
+        .enumerate()
+        .map(|(i, data)| {
+            if i < 2 {
+                let shard_data = parse_distribution_data(&data.raw_data()).unwrap();
+                to_versioned(
+                    &shard_data
+                        .as_builder()
+                        .entity_root(Byte32::from(other_entity_root))
+                        .build(),
+                )
+            } else {
+                data.raw_data()
+            }
+        })
+        .collect();
+
+    // balance the transaction with the admin's input and change
+    let admin_change_output = CellOutput::new_builder().lock(admin_lock_script).build();
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs_data(outputs_data.into_iter().map(|data| data.pack()).collect())
+        .cell_dep(always_success_dep)
+        .input(admin_fee_input)
+        .output(admin_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context
+        .verify_tx(&tx, 20_000_000)
+        .expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::VaultType,
+        VaultTypeError::DistributionDataInvalid.name(),
+    );
+}
+
+#[test]
+fn test_create_distribution_from_plan() {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let admin_lock_hash: [u8; 32] = admin_lock_script.calc_script_hash().unpack();
+
+    let creator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let creator_lock_hash: [u8; 32] = creator_lock_script.calc_script_hash().unpack();
+
+    // prepare vault
+    let vault_capacity = 100000 * 100_000_000u64;
+    let fee_percentage = 500u16; // 5.00%
+    let vault_tx = VaultBuilder::new(deployment.clone(), creator_lock_hash, admin_lock_hash)
+        .campaign_id([1; 32])
+        .capacity(vault_capacity)
+        .fee_percentage(fee_percentage)
+        .build()
+        .expect("build vault");
+    let vault = create_live_cell(
+        &mut context,
+        vault_tx.outputs().get(0).unwrap(),
+        vault_tx.outputs_data().get(0).unwrap().raw_data(),
+    );
+
+    // Add a cell for the admin to pay for fees
+    let admin_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(admin_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let admin_fee_input = CellInput::new_builder()
+        .previous_output(admin_fee_input_out_point)
+        .build();
+
+    // plan 7 claimants, one with a referral, into 3 shards: the rewards do not split evenly
+    let claimants: Vec<PlannedLeaf> = (1..=7u8)
+        .map(|i| PlannedLeaf {
+            leaf: [i; 32],
+            bonus_amount: if i == 3 { 100 * 100_000_000u64 } else { 0 },
+        })
+        .collect();
+    let fan_out = FanOutBuilder::new(deployment.clone(), vault)
+        .expect("load vault")
+        .fee_lock(admin_lock_script.clone());
+    let plan = ShardPlanner::new(
+        vault_capacity,
+        fan_out.fee().unwrap(),
+        ShardTarget::Count(3),
+    )
+    .shard_occupied_capacity(shard_occupied_capacity(&deployment))
+    .reward_occupied_capacity(DEFAULT_PAYOUT_OCCUPIED_CAPACITY)
+    .payout_occupied_capacity(DEFAULT_PAYOUT_OCCUPIED_CAPACITY)
+    .plan(&claimants)
+    .expect("plan shards");
+    assert_eq!(plan.shards.len(), 3);
+    assert!(plan.remainder == 0 || plan.remainder >= DEFAULT_PAYOUT_OCCUPIED_CAPACITY);
+
+    // build the distribution from the plan
+    let mut fan_out = fan_out
+        .uniform_reward_amount(plan.uniform_reward_amount)
+        .deadline(1_000_000u64);
+    for shard in plan.shards.iter() {
+        fan_out = fan_out.shard_with_referral_budget(
+            shard.merkle_root,
+            shard.claims(),
+            shard.referral_budget,
+        );
+    }
+    let tx = fan_out.build().expect("build distribution");
+
+    // balance the transaction with the admin's input and change
+    let admin_change_output = CellOutput::new_builder().lock(admin_lock_script).build();
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .input(admin_fee_input)
+        .output(admin_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, 20_000_000)
+        .expect("pass verification");
+    println!("consume cycles for planned distribution create: {}", cycles);
+}
+
+#[test]
+fn test_create_distribution_zero_fee() {
     // deploy contracts
     let mut context = Context::default();
     let vault_type_bin = Loader::default().load_binary("vault-type");
@@ -58,6 +463,12 @@ fn test_create_distribution() {
     let proof_out_point = context.deploy_cell(proof_bin);
     let proof_code_hash = get_code_hash(&mut context, &proof_out_point);
 
+    let entity_bin = Loader::default().load_binary("entity-type");
+    let entity_out_point = context.deploy_cell(entity_bin);
+    let entity_registry = populate_script_ref(
+        &Byte32::from_slice(get_code_hash(&mut context, &entity_out_point).as_slice()).unwrap(),
+    );
+
     // prepare scripts
     let admin_lock_script = context
         .build_script(&always_success_out_point, Bytes::from(vec![1]))
@@ -81,18 +492,25 @@ fn test_create_distribution() {
 
     // prepare data
     let vault_capacity = 10000 * 100_000_000u64;
-    let fee_percentage = 500u16; // 5.00%
+    let fee_percentage = 0u16;
     let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
 
+    // The zero-fee model requires no fee cell at all.
     let vault_data = populate_vault_data(
         &campaign_id,
         &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
+        &entity_registry,
         fee_percentage,
-    );
+    )
+    .as_builder()
+    .fee_model(populate_fee_model(2, 0, 0, 0))
+    .build();
 
     let mut vault_type_args = vec![];
     vault_type_args.extend_from_slice(dist_lock_code_hash.as_slice());
+    vault_type_args.push(ScriptHashType::Type.into());
     vault_type_args.extend_from_slice(dist_type_code_hash.as_slice());
+    vault_type_args.push(ScriptHashType::Type.into());
     let vault_type_script = context
         .build_script(&vault_type_out_point, Bytes::from(vault_type_args))
         .unwrap();
@@ -103,7 +521,7 @@ fn test_create_distribution() {
             .lock(vault_lock_script.clone())
             .type_(Some(vault_type_script).pack())
             .build(),
-        vault_data.as_bytes(),
+        to_versioned(&vault_data),
     );
     let vault_input = CellInput::new_builder()
         .previous_output(vault_input_out_point)
@@ -128,7 +546,7 @@ fn test_create_distribution() {
     let dist_type_script = context
         .build_script(&dist_type_out_point, Default::default())
         .unwrap();
-    let uniform_reward_amount = 95 * 100_000_000u64;
+    let uniform_reward_amount = 100 * 100_000_000u64;
     let merkle_root = [1u8; 32];
     let deadline = 1_000_000u64;
 
@@ -138,9 +556,11 @@ fn test_create_distribution() {
         &campaign_id,
         &admin_lock_hash,
         &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
+        &entity_registry,
         &merkle_root,
         uniform_reward_amount,
         deadline,
+        50,
     );
     let shard1_output = CellOutput::new_builder()
         .capacity(shard1_capacity.pack())
@@ -154,9 +574,11 @@ fn test_create_distribution() {
         &campaign_id,
         &admin_lock_hash,
         &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
+        &entity_registry,
         &merkle_root,
         uniform_reward_amount,
         deadline,
+        50,
     );
     let shard2_output = CellOutput::new_builder()
         .capacity(shard2_capacity.pack())
@@ -164,19 +586,9 @@ fn test_create_distribution() {
         .type_(Some(dist_type_script).pack())
         .build();
 
-    // Fee Cell
-    let fee_capacity = vault_capacity * (fee_percentage as u64) / 10000;
-    let fee_output = CellOutput::new_builder()
-        .capacity(fee_capacity.pack())
-        .lock(admin_lock_script.clone())
-        .build();
-
     let admin_change_output = CellOutput::new_builder().lock(admin_lock_script).build();
 
-    assert_eq!(
-        vault_capacity,
-        shard1_capacity + shard2_capacity + fee_capacity
-    );
+    assert_eq!(vault_capacity, shard1_capacity + shard2_capacity);
 
     // build transaction
     let tx = TransactionBuilder::default()
@@ -186,17 +598,11 @@ fn test_create_distribution() {
         .cell_dep(vault_type_dep)
         .cell_dep(vault_lock_dep)
         .inputs([vault_input, admin_fee_input])
-        .outputs([
-            shard1_output,
-            shard2_output,
-            fee_output,
-            admin_change_output,
-        ])
+        .outputs([shard1_output, shard2_output, admin_change_output])
         .outputs_data(
             [
-                shard1_data.as_bytes(),
-                shard2_data.as_bytes(),
-                Bytes::new(),
+                to_versioned(&shard1_data),
+                to_versioned(&shard2_data),
                 Bytes::new(),
             ]
             .pack(),
@@ -209,37 +615,2808 @@ fn test_create_distribution() {
     let cycles = context
         .verify_tx(&tx, 20_000_000)
         .expect("pass verification");
-    println!("consume cycles for distribution create: {}", cycles);
+    println!(
+        "consume cycles for distribution create with zero fee: {}",
+        cycles
+    );
+}
+
+#[test]
+fn test_create_distribution_capped_fee() {
+    // deploy contracts
+    let mut context = Context::default();
+    let vault_type_bin = Loader::default().load_binary("vault-type");
+    let vault_type_out_point = context.deploy_cell(vault_type_bin);
+    let vault_type_dep = CellDep::new_builder()
+        .out_point(vault_type_out_point.clone())
+        .build();
+
+    let vault_lock_bin = Loader::default().load_binary("vault-lock");
+    let vault_lock_out_point = context.deploy_cell(vault_lock_bin);
+    let vault_lock_dep = CellDep::new_builder()
+        .out_point(vault_lock_out_point.clone())
+        .build();
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    let dist_lock_bin = Loader::default().load_binary("distribution-lock");
+    let dist_lock_out_point = context.deploy_cell(dist_lock_bin);
+    let dist_lock_dep = CellDep::new_builder()
+        .out_point(dist_lock_out_point.clone())
+        .build();
+    let dist_lock_code_hash = get_code_hash(&mut context, &dist_lock_out_point);
+
+    let dist_type_bin = Loader::default().load_binary("distribution-type");
+    let dist_type_out_point = context.deploy_cell(dist_type_bin);
+    let dist_type_dep = CellDep::new_builder()
+        .out_point(dist_type_out_point.clone())
+        .build();
+    let dist_type_code_hash = get_code_hash(&mut context, &dist_type_out_point);
+
+    let proof_bin = Loader::default().load_binary("proof-type");
+    let proof_out_point = context.deploy_cell(proof_bin);
+    let proof_code_hash = get_code_hash(&mut context, &proof_out_point);
+
+    let entity_bin = Loader::default().load_binary("entity-type");
+    let entity_out_point = context.deploy_cell(entity_bin);
+    let entity_registry = populate_script_ref(
+        &Byte32::from_slice(get_code_hash(&mut context, &entity_out_point).as_slice()).unwrap(),
+    );
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let admin_lock_hash =
+        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let creator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let creator_lock_hash =
+        Byte32::from_slice(creator_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare vault lock script
+    let mut vault_lock_args = vec![];
+    vault_lock_args.extend_from_slice(creator_lock_hash.as_slice());
+    vault_lock_args.extend_from_slice(admin_lock_hash.as_slice());
+    let vault_lock_script = context
+        .build_script(&vault_lock_out_point, Bytes::from(vault_lock_args))
+        .unwrap();
+
+    // prepare data
+    let vault_capacity = 10000 * 100_000_000u64;
+    let fee_percentage = 500u16; // 5.00%
+    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
+
+    // A 5% fee would be 500 CKB, but it is capped at 300 CKB.
+    let max_fee = 300 * 100_000_000u64;
+    let vault_data = populate_vault_data(
+        &campaign_id,
+        &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
+        &entity_registry,
+        fee_percentage,
+    )
+    .as_builder()
+    .fee_model(populate_fee_model(0, 0, 0, max_fee))
+    .build();
+
+    let mut vault_type_args = vec![];
+    vault_type_args.extend_from_slice(dist_lock_code_hash.as_slice());
+    vault_type_args.push(ScriptHashType::Type.into());
+    vault_type_args.extend_from_slice(dist_type_code_hash.as_slice());
+    vault_type_args.push(ScriptHashType::Type.into());
+    let vault_type_script = context
+        .build_script(&vault_type_out_point, Bytes::from(vault_type_args))
+        .unwrap();
+
+    let vault_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(vault_capacity.pack())
+            .lock(vault_lock_script.clone())
+            .type_(Some(vault_type_script).pack())
+            .build(),
+        to_versioned(&vault_data),
+    );
+    let vault_input = CellInput::new_builder()
+        .previous_output(vault_input_out_point)
+        .build();
+
+    // Add a cell for the admin to pay for fees
+    let admin_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(admin_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let admin_fee_input = CellInput::new_builder()
+        .previous_output(admin_fee_input_out_point)
+        .build();
+
+    // prepare outputs
+    let dist_lock_script = context
+        .build_script(&dist_lock_out_point, Default::default())
+        .unwrap();
+    let dist_type_script = context
+        .build_script(&dist_type_out_point, Default::default())
+        .unwrap();
+    // The capacity saved by the fee cap goes to the claimants.
+    let uniform_reward_amount = 97 * 100_000_000u64;
+    let merkle_root = [1u8; 32];
+    let deadline = 1_000_000u64;
+
+    // Shard 1: 50 claimants
+    let shard1_capacity = uniform_reward_amount * 50;
+    let shard1_data = populate_distribution_data(
+        &campaign_id,
+        &admin_lock_hash,
+        &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
+        &entity_registry,
+        &merkle_root,
+        uniform_reward_amount,
+        deadline,
+        50,
+    );
+    let shard1_output = CellOutput::new_builder()
+        .capacity(shard1_capacity.pack())
+        .lock(dist_lock_script.clone())
+        .type_(Some(dist_type_script.clone()).pack())
+        .build();
+
+    // Shard 2: 50 claimants
+    let shard2_capacity = uniform_reward_amount * 50;
+    let shard2_data = populate_distribution_data(
+        &campaign_id,
+        &admin_lock_hash,
+        &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
+        &entity_registry,
+        &merkle_root,
+        uniform_reward_amount,
+        deadline,
+        50,
+    );
+    let shard2_output = CellOutput::new_builder()
+        .capacity(shard2_capacity.pack())
+        .lock(dist_lock_script)
+        .type_(Some(dist_type_script).pack())
+        .build();
+
+    // Fee Cell
+    let fee_capacity = max_fee;
+    let fee_output = CellOutput::new_builder()
+        .capacity(fee_capacity.pack())
+        .lock(admin_lock_script.clone())
+        .build();
+
+    let admin_change_output = CellOutput::new_builder().lock(admin_lock_script).build();
+
+    assert_eq!(
+        vault_capacity,
+        shard1_capacity + shard2_capacity + fee_capacity
+    );
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .cell_dep(always_success_dep)
+        .cell_dep(dist_lock_dep)
+        .cell_dep(dist_type_dep)
+        .cell_dep(vault_type_dep)
+        .cell_dep(vault_lock_dep)
+        .inputs([vault_input, admin_fee_input])
+        .outputs([
+            shard1_output,
+            shard2_output,
+            fee_output,
+            admin_change_output,
+        ])
+        .outputs_data(
+            [
+                to_versioned(&shard1_data),
+                to_versioned(&shard2_data),
+                Bytes::new(),
+                Bytes::new(),
+            ]
+            .pack(),
+        )
+        .witness(WitnessArgs::new_builder().build().as_bytes().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, 20_000_000)
+        .expect("pass verification");
+    println!(
+        "consume cycles for distribution create with capped fee: {}",
+        cycles
+    );
+}
+
+#[test]
+fn test_create_distribution_with_fee_schedule() {
+    // deploy contracts
+    let mut context = Context::default();
+    let vault_type_bin = Loader::default().load_binary("vault-type");
+    let vault_type_out_point = context.deploy_cell(vault_type_bin);
+    let vault_type_dep = CellDep::new_builder()
+        .out_point(vault_type_out_point.clone())
+        .build();
+
+    let vault_lock_bin = Loader::default().load_binary("vault-lock");
+    let vault_lock_out_point = context.deploy_cell(vault_lock_bin);
+    let vault_lock_dep = CellDep::new_builder()
+        .out_point(vault_lock_out_point.clone())
+        .build();
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    let dist_lock_bin = Loader::default().load_binary("distribution-lock");
+    let dist_lock_out_point = context.deploy_cell(dist_lock_bin);
+    let dist_lock_dep = CellDep::new_builder()
+        .out_point(dist_lock_out_point.clone())
+        .build();
+    let dist_lock_code_hash = get_code_hash(&mut context, &dist_lock_out_point);
+
+    let dist_type_bin = Loader::default().load_binary("distribution-type");
+    let dist_type_out_point = context.deploy_cell(dist_type_bin);
+    let dist_type_dep = CellDep::new_builder()
+        .out_point(dist_type_out_point.clone())
+        .build();
+    let dist_type_code_hash = get_code_hash(&mut context, &dist_type_out_point);
+
+    let proof_bin = Loader::default().load_binary("proof-type");
+    let proof_out_point = context.deploy_cell(proof_bin);
+    let proof_code_hash = get_code_hash(&mut context, &proof_out_point);
+
+    let entity_bin = Loader::default().load_binary("entity-type");
+    let entity_out_point = context.deploy_cell(entity_bin);
+    let entity_registry = populate_script_ref(
+        &Byte32::from_slice(get_code_hash(&mut context, &entity_out_point).as_slice()).unwrap(),
+    );
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let admin_lock_hash =
+        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let creator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let creator_lock_hash =
+        Byte32::from_slice(creator_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let referrer_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![3]))
+        .unwrap();
+    let referrer_lock_hash =
+        Byte32::from_slice(referrer_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let dao_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![4]))
+        .unwrap();
+    let dao_lock_hash = Byte32::from_slice(dao_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare vault lock script
+    let mut vault_lock_args = vec![];
+    vault_lock_args.extend_from_slice(creator_lock_hash.as_slice());
+    vault_lock_args.extend_from_slice(admin_lock_hash.as_slice());
+    let vault_lock_script = context
+        .build_script(&vault_lock_out_point, Bytes::from(vault_lock_args))
+        .unwrap();
+
+    // prepare data
+    let vault_capacity = 10000 * 100_000_000u64;
+    let fee_percentage = 500u16; // 5.00%
+    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
+
+    // The fee is split 50% / 30% / 20% between the platform, a referrer and the creator's DAO.
+    let vault_data = populate_vault_data(
+        &campaign_id,
+        &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
+        &entity_registry,
+        fee_percentage,
+    )
+    .as_builder()
+    .fee_schedule(populate_fee_schedule(&[
+        (admin_lock_hash.clone(), 5000),
+        (referrer_lock_hash, 3000),
+        (dao_lock_hash, 2000),
+    ]))
+    .build();
+
+    let mut vault_type_args = vec![];
+    vault_type_args.extend_from_slice(dist_lock_code_hash.as_slice());
+    vault_type_args.push(ScriptHashType::Type.into());
+    vault_type_args.extend_from_slice(dist_type_code_hash.as_slice());
+    vault_type_args.push(ScriptHashType::Type.into());
+    let vault_type_script = context
+        .build_script(&vault_type_out_point, Bytes::from(vault_type_args))
+        .unwrap();
+
+    let vault_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(vault_capacity.pack())
+            .lock(vault_lock_script.clone())
+            .type_(Some(vault_type_script).pack())
+            .build(),
+        to_versioned(&vault_data),
+    );
+    let vault_input = CellInput::new_builder()
+        .previous_output(vault_input_out_point)
+        .build();
+
+    // Add a cell for the admin to pay for fees
+    let admin_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(admin_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let admin_fee_input = CellInput::new_builder()
+        .previous_output(admin_fee_input_out_point)
+        .build();
+
+    // prepare outputs
+    let dist_lock_script = context
+        .build_script(&dist_lock_out_point, Default::default())
+        .unwrap();
+    let dist_type_script = context
+        .build_script(&dist_type_out_point, Default::default())
+        .unwrap();
+    let uniform_reward_amount = 95 * 100_000_000u64;
+    let merkle_root = [1u8; 32];
+    let deadline = 1_000_000u64;
+
+    // Shard 1: 50 claimants
+    let shard1_capacity = uniform_reward_amount * 50;
+    let shard1_data = populate_distribution_data(
+        &campaign_id,
+        &admin_lock_hash,
+        &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
+        &entity_registry,
+        &merkle_root,
+        uniform_reward_amount,
+        deadline,
+        50,
+    );
+    let shard1_output = CellOutput::new_builder()
+        .capacity(shard1_capacity.pack())
+        .lock(dist_lock_script.clone())
+        .type_(Some(dist_type_script.clone()).pack())
+        .build();
+
+    // Shard 2: 50 claimants
+    let shard2_capacity = uniform_reward_amount * 50;
+    let shard2_data = populate_distribution_data(
+        &campaign_id,
+        &admin_lock_hash,
+        &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
+        &entity_registry,
+        &merkle_root,
+        uniform_reward_amount,
+        deadline,
+        50,
+    );
+    let shard2_output = CellOutput::new_builder()
+        .capacity(shard2_capacity.pack())
+        .lock(dist_lock_script)
+        .type_(Some(dist_type_script).pack())
+        .build();
+
+    // Fee Cells
+    let fee_capacity = vault_capacity * (fee_percentage as u64) / 10000;
+    let platform_fee_capacity = fee_capacity * 5000 / 10000;
+    let referrer_fee_capacity = fee_capacity * 3000 / 10000;
+    let dao_fee_capacity = fee_capacity * 2000 / 10000;
+    let platform_fee_output = CellOutput::new_builder()
+        .capacity(platform_fee_capacity.pack())
+        .lock(admin_lock_script.clone())
+        .build();
+    let referrer_fee_output = CellOutput::new_builder()
+        .capacity(referrer_fee_capacity.pack())
+        .lock(referrer_lock_script)
+        .build();
+    let dao_fee_output = CellOutput::new_builder()
+        .capacity(dao_fee_capacity.pack())
+        .lock(dao_lock_script)
+        .build();
+
+    let admin_change_output = CellOutput::new_builder().lock(admin_lock_script).build();
+
+    assert_eq!(
+        fee_capacity,
+        platform_fee_capacity + referrer_fee_capacity + dao_fee_capacity
+    );
+    assert_eq!(
+        vault_capacity,
+        shard1_capacity + shard2_capacity + fee_capacity
+    );
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .cell_dep(always_success_dep)
+        .cell_dep(dist_lock_dep)
+        .cell_dep(dist_type_dep)
+        .cell_dep(vault_type_dep)
+        .cell_dep(vault_lock_dep)
+        .inputs([vault_input, admin_fee_input])
+        .outputs([
+            shard1_output,
+            shard2_output,
+            platform_fee_output,
+            referrer_fee_output,
+            dao_fee_output,
+            admin_change_output,
+        ])
+        .outputs_data(
+            [
+                to_versioned(&shard1_data),
+                to_versioned(&shard2_data),
+                Bytes::new(),
+                Bytes::new(),
+                Bytes::new(),
+                Bytes::new(),
+            ]
+            .pack(),
+        )
+        .witness(WitnessArgs::new_builder().build().as_bytes().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, 20_000_000)
+        .expect("pass verification");
+    println!(
+        "consume cycles for distribution create with fee schedule: {}",
+        cycles
+    );
+}
+
+#[test]
+fn test_claim_distribution() {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![0]))
+        .unwrap();
+    let admin_lock_hash =
+        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let subscriber_lock_hash =
+        Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let other_subscriber_lock = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let other_subscriber_lock_hash =
+        Byte32::from_slice(other_subscriber_lock.calc_script_hash().as_slice()).unwrap();
+
+    // prepare data
+    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
+    let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id);
+    let proof_type_id = [4u8; 32]; // dummy type id
+    let proof_cell_capacity = 254 * 100_000_000u64;
+    let proof = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity(proof_cell_capacity.pack())
+            .lock(subscriber_lock_script.clone())
+            .type_(
+                Some(
+                    deployment
+                        .proof_type
+                        .script(proof_type_args(&deployment, &proof_type_id)),
+                )
+                .pack(),
+            )
+            .build(),
+        to_versioned(&proof_data),
+    );
+
+    // Add a cell for the subscriber to pay for fees
+    let subscriber_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(subscriber_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let subscriber_fee_input = CellInput::new_builder()
+        .previous_output(subscriber_fee_input_out_point)
+        .build();
+
+    // prepare Merkle Tree
+    let leaf0 = claimant_leaf(&proof_type_id, &subscriber_lock_hash.into(), None);
+    let leaf1 = claimant_leaf(&[5; 32], &other_subscriber_lock_hash.into(), None);
+
+    let leaves = vec![leaf0, leaf1];
+    let merkle_root = merkle::merkle_root(&leaves);
+    let merkle_proof = merkle::merkle_proof(&leaves, 0);
+
+    // prepare distribution shard
+    let reward_amount = 100 * 100_000_000u64;
+    let dist_capacity = reward_amount * leaves.len() as u64;
+    let deadline = 1_000_000u64;
+    let dist_data = populate_distribution_data(
+        &campaign_id,
+        &admin_lock_hash,
+        &Byte32::from_slice(deployment.proof_type.code_hash.as_slice()).unwrap(),
+        &deployment.entity_type.packed_script_ref(),
+        &merkle_root,
+        reward_amount,
+        deadline,
+        leaves.len() as u32,
+    );
+    let shard = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity(dist_capacity.pack())
+            .lock(deployment.distribution_lock.script(Bytes::new()))
+            .type_(Some(deployment.distribution_type.script(Bytes::new())).pack())
+            .build(),
+        to_versioned(&dist_data),
+    );
+
+    // build the claim
+    let proof_reveal = populate_proof_reveal();
+    let tx = ClaimBuilder::new(deployment, shard, proof)
+        .expect("load shard and proof")
+        .merkle_proof(merkle_proof)
+        .proof_reveal(proof_reveal.proof().into(), proof_reveal.salt().into())
+        .build()
+        .expect("build claim");
+
+    // balance the transaction with the subscriber's input and change
+    let subscriber_change_output = CellOutput::new_builder()
+        .lock(subscriber_lock_script)
+        .build();
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .input(subscriber_fee_input)
+        .output(subscriber_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, 10_000_000)
+        .expect("pass verification");
+    println!("consume cycles for distribution claim: {}", cycles);
+}
+
+#[test]
+fn test_claim_distribution_invalid_merkle_proof() {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![0]))
+        .unwrap();
+    let admin_lock_hash =
+        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let subscriber_lock_hash =
+        Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let other_subscriber_lock = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let other_subscriber_lock_hash =
+        Byte32::from_slice(other_subscriber_lock.calc_script_hash().as_slice()).unwrap();
+
+    // prepare data
+    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
+    let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id);
+    let proof_type_id = [4u8; 32]; // dummy type id
+    let proof_cell_capacity = 254 * 100_000_000u64;
+    let proof = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity(proof_cell_capacity.pack())
+            .lock(subscriber_lock_script.clone())
+            .type_(
+                Some(
+                    deployment
+                        .proof_type
+                        .script(proof_type_args(&deployment, &proof_type_id)),
+                )
+                .pack(),
+            )
+            .build(),
+        to_versioned(&proof_data),
+    );
+
+    // Add a cell for the subscriber to pay for fees
+    let subscriber_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(subscriber_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let subscriber_fee_input = CellInput::new_builder()
+        .previous_output(subscriber_fee_input_out_point)
+        .build();
+
+    // prepare Merkle Tree
+    let leaf0 = claimant_leaf(&proof_type_id, &subscriber_lock_hash.into(), None);
+    let leaf1 = claimant_leaf(&[5; 32], &other_subscriber_lock_hash.into(), None);
+
+    let leaves = vec![leaf0, leaf1];
+    let merkle_root = merkle::merkle_root(&leaves);
+    // the proof of the other claimant does not lead from the subscriber's leaf to the root
+    let merkle_proof = merkle::merkle_proof(&leaves, 1);
+
+    // prepare distribution shard
+    let reward_amount = 100 * 100_000_000u64;
+    let dist_capacity = reward_amount * leaves.len() as u64;
+    let deadline = 1_000_000u64;
+    let dist_data = populate_distribution_data(
+        &campaign_id,
+        &admin_lock_hash,
+        &Byte32::from_slice(deployment.proof_type.code_hash.as_slice()).unwrap(),
+        &deployment.entity_type.packed_script_ref(),
+        &merkle_root,
+        reward_amount,
+        deadline,
+        leaves.len() as u32,
+    );
+    let shard = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity(dist_capacity.pack())
+            .lock(deployment.distribution_lock.script(Bytes::new()))
+            .type_(Some(deployment.distribution_type.script(Bytes::new())).pack())
+            .build(),
+        to_versioned(&dist_data),
+    );
+
+    // build the claim
+    let proof_reveal = populate_proof_reveal();
+    let tx = ClaimBuilder::new(deployment, shard, proof)
+        .expect("load shard and proof")
+        .merkle_proof(merkle_proof)
+        .proof_reveal(proof_reveal.proof().into(), proof_reveal.salt().into())
+        .build()
+        .expect("build claim");
+
+    // balance the transaction with the subscriber's input and change
+    let subscriber_change_output = CellOutput::new_builder()
+        .lock(subscriber_lock_script)
+        .build();
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .input(subscriber_fee_input)
+        .output(subscriber_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context
+        .verify_tx(&tx, 10_000_000)
+        .expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionLock,
+        DistributionLockError::MerkleProofInvalid.name(),
+    );
+}
+
+#[test]
+fn test_claim_distribution_wrong_reveal_salt() {
+    let revealed = populate_proof_reveal()
+        .as_builder()
+        .salt(Byte32::from_slice(&[6; 32]).unwrap())
+        .build();
+    let err = verify_revealed_claim(&populate_proof_reveal(), None, &revealed)
+        .expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionType,
+        DistributionTypeError::ProofRevealMismatch.name(),
+    );
+}
+
+#[test]
+fn test_claim_distribution_wrong_reveal_proof() {
+    let revealed = populate_proof_reveal()
+        .as_builder()
+        .proof(Byte32::from_slice(&[6; 32]).unwrap())
+        .build();
+    let err = verify_revealed_claim(&populate_proof_reveal(), None, &revealed)
+        .expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionType,
+        DistributionTypeError::ProofRevealMismatch.name(),
+    );
+}
+
+#[test]
+fn test_claim_distribution_reveal_of_another_subscriber() {
+    // The commitment was made for another subscriber, e.g. copied from its
+    // proof cell, so the reveal does not open it for this one.
+    let other_subscriber_lock_hash = Byte32::from_slice(&[9; 32]).unwrap();
+    let err = verify_revealed_claim(
+        &populate_proof_reveal(),
+        Some(&other_subscriber_lock_hash),
+        &populate_proof_reveal(),
+    )
+    .expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionType,
+        DistributionTypeError::ProofRevealMismatch.name(),
+    );
+}
+
+#[test]
+fn test_claim_distribution_null_reveal_proof() {
+    // The commitment opens, but a null proof is never accepted.
+    let null_reveal = populate_proof_reveal()
+        .as_builder()
+        .proof(Byte32::default())
+        .build();
+    let err =
+        verify_revealed_claim(&null_reveal, None, &null_reveal).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionType,
+        DistributionTypeError::ProofRevealMismatch.name(),
+    );
+}
+
+/// Claims with a proof committing to `committed` for the subscriber, or for
+/// `commit_subscriber_lock_hash` when given, and a witness revealing
+/// `revealed`.
+fn verify_revealed_claim(
+    committed: &ProofReveal,
+    commit_subscriber_lock_hash: Option<&Byte32>,
+    revealed: &ProofReveal,
+) -> Result<Cycle, Error> {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![0]))
+        .unwrap();
+    let admin_lock_hash =
+        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let subscriber_lock_hash =
+        Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare data
+    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
+    let proof_commit = populate_proof_commit(
+        committed,
+        commit_subscriber_lock_hash.unwrap_or(&subscriber_lock_hash),
+    );
+    let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id)
+        .as_builder()
+        .proof_commit(proof_commit)
+        .build();
+    let proof_type_id = [4u8; 32]; // dummy type id
+    let proof = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity((254 * 100_000_000u64).pack())
+            .lock(subscriber_lock_script.clone())
+            .type_(
+                Some(
+                    deployment
+                        .proof_type
+                        .script(proof_type_args(&deployment, &proof_type_id)),
+                )
+                .pack(),
+            )
+            .build(),
+        to_versioned(&proof_data),
+    );
+
+    // Add a cell for the subscriber to pay for fees
+    let subscriber_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(subscriber_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let subscriber_fee_input = CellInput::new_builder()
+        .previous_output(subscriber_fee_input_out_point)
+        .build();
+
+    // prepare Merkle Tree
+    let leaf0 = claimant_leaf(&proof_type_id, &subscriber_lock_hash.into(), None);
+    let leaf1 = claimant_leaf(&[5; 32], &[6; 32], None);
+
+    let leaves = vec![leaf0, leaf1];
+    let merkle_root = merkle::merkle_root(&leaves);
+    let merkle_proof = merkle::merkle_proof(&leaves, 0);
+
+    // prepare distribution shard
+    let reward_amount = 100 * 100_000_000u64;
+    let dist_capacity = reward_amount * leaves.len() as u64;
+    let dist_data = populate_distribution_data(
+        &campaign_id,
+        &admin_lock_hash,
+        &Byte32::from_slice(deployment.proof_type.code_hash.as_slice()).unwrap(),
+        &deployment.entity_type.packed_script_ref(),
+        &merkle_root,
+        reward_amount,
+        1_000_000,
+        leaves.len() as u32,
+    );
+    let shard = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity(dist_capacity.pack())
+            .lock(deployment.distribution_lock.script(Bytes::new()))
+            .type_(Some(deployment.distribution_type.script(Bytes::new())).pack())
+            .build(),
+        to_versioned(&dist_data),
+    );
+
+    // build the claim
+    let tx = ClaimBuilder::new(deployment, shard, proof)
+        .expect("load shard and proof")
+        .merkle_proof(merkle_proof)
+        .proof_reveal(revealed.proof().into(), revealed.salt().into())
+        .build()
+        .expect("build claim");
+
+    // balance the transaction with the subscriber's input and change
+    let subscriber_change_output = CellOutput::new_builder()
+        .lock(subscriber_lock_script)
+        .build();
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .input(subscriber_fee_input)
+        .output(subscriber_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    context.verify_tx(&tx, 10_000_000)
+}
+
+#[test]
+fn test_claim_distribution_remaining_claims_not_decremented() {
+    let err = verify_claim_counter(2, 2, Some(2)).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionType,
+        DistributionTypeError::ShardDataImmutable.name(),
+    );
+}
+
+#[test]
+fn test_claim_distribution_remaining_claims_decremented_twice() {
+    let err = verify_claim_counter(3, 3, Some(1)).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionType,
+        DistributionTypeError::ShardDataImmutable.name(),
+    );
+}
+
+#[test]
+fn test_final_claim_distribution_remaining_claims_invalid() {
+    // The shard's capacity pays for a single reward, but it still counts two
+    // claims, so it cannot be destroyed by a final claim.
+    let err = verify_claim_counter(1, 2, None).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionType,
+        DistributionTypeError::RemainingClaimsInvalid.name(),
+    );
+}
+
+/// Claims from a shard funded for `claims` rewards. The SDK builds the claim
+/// as if the shard counted `claims`, then the spent shard is swapped for one
+/// counting `spent_remaining_claims`, and the updated shard, if any, is
+/// rewritten to count `claimed_remaining_claims`.
+fn verify_claim_counter(
+    claims: u32,
+    spent_remaining_claims: u32,
+    claimed_remaining_claims: Option<u32>,
+) -> Result<Cycle, Error> {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![0]))
+        .unwrap();
+    let admin_lock_hash =
+        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let subscriber_lock_hash =
+        Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare data
+    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
+    let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id);
+    let proof_type_id = [4u8; 32]; // dummy type id
+    let proof = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity((254 * 100_000_000u64).pack())
+            .lock(subscriber_lock_script.clone())
+            .type_(
+                Some(
+                    deployment
+                        .proof_type
+                        .script(proof_type_args(&deployment, &proof_type_id)),
+                )
+                .pack(),
+            )
+            .build(),
+        to_versioned(&proof_data),
+    );
+
+    // Add a cell for the subscriber to pay for fees
+    let subscriber_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(subscriber_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let subscriber_fee_input = CellInput::new_builder()
+        .previous_output(subscriber_fee_input_out_point)
+        .build();
+
+    // prepare Merkle Tree
+    let leaf0 = claimant_leaf(&proof_type_id, &subscriber_lock_hash.into(), None);
+    let leaf1 = claimant_leaf(&[5; 32], &[6; 32], None);
+
+    let leaves = vec![leaf0, leaf1];
+    let merkle_root = merkle::merkle_root(&leaves);
+    let merkle_proof = merkle::merkle_proof(&leaves, 0);
+
+    // prepare distribution shard
+    let reward_amount = 100 * 100_000_000u64;
+    let dist_data = populate_distribution_data(
+        &campaign_id,
+        &admin_lock_hash,
+        &Byte32::from_slice(deployment.proof_type.code_hash.as_slice()).unwrap(),
+        &deployment.entity_type.packed_script_ref(),
+        &merkle_root,
+        reward_amount,
+        1_000_000,
+        claims,
+    );
+    let dist_output = CellOutput::new_builder()
+        .capacity((reward_amount * claims as u64).pack())
+        .lock(deployment.distribution_lock.script(Bytes::new()))
+        .type_(Some(deployment.distribution_type.script(Bytes::new())).pack())
+        .build();
+    let shard = create_live_cell(&mut context, dist_output.clone(), to_versioned(&dist_data));
+
+    // build the claim
+    let proof_reveal = populate_proof_reveal();
+    let tx = ClaimBuilder::new(deployment, shard.clone(), proof)
+        .expect("load shard and proof")
+        .merkle_proof(merkle_proof)
+        .proof_reveal(proof_reveal.proof().into(), proof_reveal.salt().into())
+        .build()
+        .expect("build claim");
+
+    // The SDK refuses to build an inconsistent counter, so tamper with it afterwards.
+    let tx = if spent_remaining_claims != claims {
+        let spent_shard = create_live_cell(
+            &mut context,
+            dist_output,
+            to_versioned(
+                &dist_data
+                    .as_builder()
+                    .remaining_claims(spent_remaining_claims.pack())
+                    .build(),
+            ),
+        );
+        let inputs: Vec<CellInput> = tx
+            .inputs()
+            .into_iter()
+            .map(|input| {
+                if input.previous_output().as_slice() == shard.out_point.as_slice() {
+                    spent_shard.input(0)
+                } else {
+                    input
+                }
+            })
+            .collect();
+        tx.as_advanced_builder().set_inputs(inputs).build()
+    } else {
+        tx
+    };
+    let tx = if let Some(claimed_remaining_claims) = claimed_remaining_claims {
+        // the updated shard is the first output
+        let mut outputs_data: Vec<Bytes> = tx
+            .outputs_data()
+            .into_iter()
+            .map(|data| data.raw_data())
+            .collect();
+        let shard_data = parse_distribution_data(&outputs_data[0]).unwrap();
+        outputs_data[0] = to_versioned(
+            &shard_data
+                .as_builder()
+                .remaining_claims(claimed_remaining_claims.pack())
+                .build(),
+        );
+        tx.as_advanced_builder()
+            .set_outputs_data(outputs_data.into_iter().map(|data| data.pack()).collect())
+            .build()
+    } else {
+        tx
+    };
+
+    // balance the transaction with the subscriber's input and change
+    let subscriber_change_output = CellOutput::new_builder()
+        .lock(subscriber_lock_script)
+        .build();
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .input(subscriber_fee_input)
+        .output(subscriber_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    context.verify_tx(&tx, 10_000_000)
+}
+
+#[test]
+fn test_claim_distribution_creator_scoped() {
+    let cycles = verify_creator_scoped_claim(2, false).expect("pass verification");
+    println!(
+        "consume cycles for distribution claim scoped to a creator: {}",
+        cycles
+    );
+}
+
+#[test]
+fn test_claim_distribution_wrong_entity_creator() {
+    // The proof's entity is published by another creator than the campaign's.
+    let err = verify_creator_scoped_claim(3, false).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionType,
+        DistributionTypeError::EntityCreatorMismatch.name(),
+    );
+}
+
+#[test]
+fn test_claim_distribution_wrong_entity_registry() {
+    // The proof names a registry its subscriber picked instead of the campaign's.
+    let err = verify_creator_scoped_claim(2, true).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionType,
+        DistributionTypeError::EntityRegistryMismatch.name(),
+    );
+}
+
+/// Claims from a campaign scoped to the creator whose lock args are `[2]`, with
+/// a proof for an entity published by the creator whose lock args are
+/// `[entity_creator]`. With `other_registry`, the proof names another registry
+/// than the campaign's.
+fn verify_creator_scoped_claim(entity_creator: u8, other_registry: bool) -> Result<Cycle, Error> {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![0]))
+        .unwrap();
+    let admin_lock_hash =
+        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let subscriber_lock_hash =
+        Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let campaign_creator_lock_hash = Byte32::from_slice(
+        context
+            .build_script(&always_success_out_point, Bytes::from(vec![2]))
+            .unwrap()
+            .calc_script_hash()
+            .as_slice(),
+    )
+    .unwrap();
+    let entity_creator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![entity_creator]))
+        .unwrap();
+    let entity_creator_lock_hash =
+        Byte32::from_slice(entity_creator_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare the registry cell of the proof's entity
+    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
+    let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id);
+    let entity_data = populate_entity_data(
+        &entity_creator_lock_hash,
+        &proof_data.entity_id(),
+        &Byte32::from_slice(&[6; 32]).unwrap(),
+    );
+    let entity_cell = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(entity_creator_lock_script)
+            .type_(
+                Some(
+                    deployment
+                        .entity_type
+                        .script(proof_data.entity_id().as_bytes()),
+                )
+                .pack(),
+            )
+            .build(),
+        entity_data.as_bytes(),
+    );
+
+    // prepare the proof cell
+    let proof_type_id = [4u8; 32]; // dummy type id
+    let proof_cell_capacity = 254 * 100_000_000u64;
+    let proof_output = CellOutput::new_builder()
+        .capacity(proof_cell_capacity.pack())
+        .lock(subscriber_lock_script.clone())
+        .type_(
+            Some(
+                deployment
+                    .proof_type
+                    .script(proof_type_args(&deployment, &proof_type_id)),
+            )
+            .pack(),
+        )
+        .build();
+    let proof = create_live_cell(
+        &mut context,
+        proof_output.clone(),
+        to_versioned(&proof_data),
+    );
+
+    // Add a cell for the subscriber to pay for fees
+    let subscriber_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(subscriber_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let subscriber_fee_input = CellInput::new_builder()
+        .previous_output(subscriber_fee_input_out_point)
+        .build();
+
+    // prepare Merkle Tree
+    let leaf0 = claimant_leaf(&proof_type_id, &subscriber_lock_hash.into(), None);
+    let leaf1 = claimant_leaf(&[5; 32], &[6; 32], None);
+
+    let leaves = vec![leaf0, leaf1];
+    let merkle_root = merkle::merkle_root(&leaves);
+    let merkle_proof = merkle::merkle_proof(&leaves, 0);
+
+    // prepare distribution shard, scoped to the campaign creator's entities
+    let reward_amount = 100 * 100_000_000u64;
+    let dist_capacity = reward_amount * leaves.len() as u64;
+    let deadline = 1_000_000u64;
+    let dist_data = populate_distribution_data(
+        &campaign_id,
+        &admin_lock_hash,
+        &Byte32::from_slice(deployment.proof_type.code_hash.as_slice()).unwrap(),
+        &deployment.entity_type.packed_script_ref(),
+        &merkle_root,
+        reward_amount,
+        deadline,
+        leaves.len() as u32,
+    )
+    .as_builder()
+    .entity_creator_lock_hash(campaign_creator_lock_hash)
+    .build();
+    let shard = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity(dist_capacity.pack())
+            .lock(deployment.distribution_lock.script(Bytes::new()))
+            .type_(Some(deployment.distribution_type.script(Bytes::new())).pack())
+            .build(),
+        to_versioned(&dist_data),
+    );
+
+    // build the claim
+    let proof_reveal = populate_proof_reveal();
+    let tx = ClaimBuilder::new(deployment.clone(), shard, proof.clone())
+        .expect("load shard and proof")
+        .merkle_proof(merkle_proof)
+        .proof_reveal(proof_reveal.proof().into(), proof_reveal.salt().into())
+        .entity_cell_dep(
+            CellDep::new_builder()
+                .out_point(entity_cell.out_point)
+                .build(),
+        )
+        .build()
+        .expect("build claim");
+
+    // The SDK refuses a proof naming another registry, so swap it in afterwards.
+    let tx = if other_registry {
+        let other_registry = populate_script_ref(&Byte32::from_slice(&[7; 32]).unwrap());
+        let other_proof = create_live_cell(
+            &mut context,
+            proof_output
+                .as_builder()
+                .type_(
+                    Some(deployment.proof_type.script(populate_proof_type_args(
+                        &Byte32::from_slice(&proof_type_id).unwrap(),
+                        &other_registry,
+                        &deployment.distribution_type.packed_script_ref(),
+                    )))
+                    .pack(),
+                )
+                .build(),
+            to_versioned(&proof_data),
+        );
+        let inputs: Vec<CellInput> = tx
+            .inputs()
+            .into_iter()
+            .map(|input| {
+                if input.previous_output().as_slice() == proof.out_point.as_slice() {
+                    CellInput::new_builder()
+                        .previous_output(other_proof.out_point.clone())
+                        .build()
+                } else {
+                    input
+                }
+            })
+            .collect();
+        tx.as_advanced_builder().set_inputs(inputs).build()
+    } else {
+        tx
+    };
+
+    // balance the transaction with the subscriber's input and change
+    let subscriber_change_output = CellOutput::new_builder()
+        .lock(subscriber_lock_script)
+        .build();
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .input(subscriber_fee_input)
+        .output(subscriber_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    context.verify_tx(&tx, 10_000_000)
+}
+
+#[test]
+fn test_claim_distribution_legacy_shard() {
+    let cycles = verify_sdk_claim(true, false).expect("pass verification");
+    println!("consume cycles for a claim from a legacy shard: {}", cycles);
+}
+
+#[test]
+fn test_claim_distribution_with_proof_lock() {
+    let cycles = verify_sdk_claim(false, true).expect("pass verification");
+    println!("consume cycles for a claim with a proof lock: {}", cycles);
+}
+
+/// Claims with the SDK from a shard created with data version 1 when
+/// `legacy_shard`, and with a proof guarded by the proof lock when
+/// `proof_lock`. The transaction is not completed by the context, so it only
+/// passes with the cell deps the builder adds.
+fn verify_sdk_claim(legacy_shard: bool, proof_lock: bool) -> Result<Cycle, Error> {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![0]))
+        .unwrap();
+    let admin_lock_hash =
+        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let subscriber_lock_hash =
+        Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare the proof cell
+    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
+    let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id);
+    let proof_type_id = [4u8; 32]; // dummy type id
+    let proof_lock_script = if proof_lock {
+        polymeer_sdk::proof::proof_lock(&deployment, &subscriber_lock_hash.clone().into())
+    } else {
+        subscriber_lock_script.clone()
+    };
+    let proof = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity((254 * 100_000_000u64).pack())
+            .lock(proof_lock_script)
+            .type_(
+                Some(
+                    deployment
+                        .proof_type
+                        .script(proof_type_args(&deployment, &proof_type_id)),
+                )
+                .pack(),
+            )
+            .build(),
+        to_versioned(&proof_data),
+    );
+
+    // Add a cell for the subscriber to pay for fees
+    let subscriber_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(subscriber_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let subscriber_fee_input = CellInput::new_builder()
+        .previous_output(subscriber_fee_input_out_point)
+        .build();
+
+    // prepare Merkle Tree
+    let leaf0 = claimant_leaf(&proof_type_id, &subscriber_lock_hash.into(), None);
+    let leaf1 = claimant_leaf(&[5; 32], &[6; 32], None);
+
+    let leaves = vec![leaf0, leaf1];
+    let merkle_root = merkle::merkle_root(&leaves);
+    let merkle_proof = merkle::merkle_proof(&leaves, 0);
+
+    // prepare distribution shard. One created with data version 1 pins
+    // neither the proof script hash type nor an entity registry.
+    let reward_amount = 100 * 100_000_000u64;
+    let dist_capacity = reward_amount * leaves.len() as u64;
+    let proof_script_code_hash =
+        Byte32::from_slice(deployment.proof_type.code_hash.as_slice()).unwrap();
+    let deadline = 1_000_000u64;
+    let dist_data = if legacy_shard {
+        to_versioned_v1(
+            &DistributionCellDataV1::new_builder()
+                .campaign_id(campaign_id)
+                .admin_lock_hash(admin_lock_hash)
+                .merkle_root(Byte32::from_slice(&merkle_root).unwrap())
+                .proof_script_code_hash(proof_script_code_hash)
+                .uniform_reward_amount(reward_amount.pack())
+                .deadline(deadline.pack())
+                .remaining_claims((leaves.len() as u32).pack())
+                .build(),
+        )
+    } else {
+        to_versioned(&populate_distribution_data(
+            &campaign_id,
+            &admin_lock_hash,
+            &proof_script_code_hash,
+            &deployment.entity_type.packed_script_ref(),
+            &merkle_root,
+            reward_amount,
+            deadline,
+            leaves.len() as u32,
+        ))
+    };
+    let shard = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity(dist_capacity.pack())
+            .lock(deployment.distribution_lock.script(Bytes::new()))
+            .type_(Some(deployment.distribution_type.script(Bytes::new())).pack())
+            .build(),
+        dist_data,
+    );
+
+    // build the claim, which rewrites the shard with the latest data version
+    // and pays the reward to the subscriber's lock
+    let proof_reveal = populate_proof_reveal();
+    let tx = ClaimBuilder::new(deployment, shard, proof)
+        .expect("load shard and proof")
+        .merkle_proof(merkle_proof)
+        .proof_reveal(proof_reveal.proof().into(), proof_reveal.salt().into())
+        .subscriber_lock(subscriber_lock_script.clone())
+        .build()
+        .expect("build claim");
+    assert_eq!(
+        tx.outputs_data().get(0).unwrap().raw_data()[0],
+        LATEST_DATA_VERSION
+    );
+
+    // balance the transaction with the subscriber's input and change
+    let subscriber_change_output = CellOutput::new_builder()
+        .lock(subscriber_lock_script)
+        .build();
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .input(subscriber_fee_input)
+        .output(subscriber_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+
+    // run, with the cell deps the builder added and no others
+    context.verify_tx(&tx, 10_000_000)
+}
+
+#[test]
+fn test_claim_distribution_from_bundle() {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![0]))
+        .unwrap();
+    let admin_lock_hash =
+        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let subscriber_lock_hash =
+        Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let other_subscriber_lock = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let other_subscriber_lock_hash =
+        Byte32::from_slice(other_subscriber_lock.calc_script_hash().as_slice()).unwrap();
+
+    // prepare data
+    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
+    let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id);
+    let proof_type_id = [4u8; 32]; // dummy type id
+    let proof_cell_capacity = 254 * 100_000_000u64;
+    let proof = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity(proof_cell_capacity.pack())
+            .lock(subscriber_lock_script.clone())
+            .type_(
+                Some(
+                    deployment
+                        .proof_type
+                        .script(proof_type_args(&deployment, &proof_type_id)),
+                )
+                .pack(),
+            )
+            .build(),
+        to_versioned(&proof_data),
+    );
+
+    // Add a cell for the subscriber to pay for fees
+    let subscriber_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(subscriber_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let subscriber_fee_input = CellInput::new_builder()
+        .previous_output(subscriber_fee_input_out_point)
+        .build();
+
+    // prepare claimants
+    let claimants = vec![
+        BundleClaimant {
+            proof_type_id,
+            subscriber_lock_hash: subscriber_lock_hash.clone().into(),
+            referral: None,
+            proof_out_point: proof.out_point.clone(),
+        },
+        BundleClaimant {
+            proof_type_id: [5; 32],
+            subscriber_lock_hash: other_subscriber_lock_hash.into(),
+            referral: None,
+            proof_out_point: OutPoint::default(),
+        },
+    ];
+    let leaves: Vec<[u8; 32]> = claimants.iter().map(BundleClaimant::leaf).collect();
+    let merkle_root = merkle::merkle_root(&leaves);
+
+    // prepare distribution shard
+    let reward_amount = 100 * 100_000_000u64;
+    let dist_capacity = reward_amount * leaves.len() as u64;
+    let deadline = 1_000_000u64;
+    let dist_data = populate_distribution_data(
+        &campaign_id,
+        &admin_lock_hash,
+        &Byte32::from_slice(deployment.proof_type.code_hash.as_slice()).unwrap(),
+        &deployment.entity_type.packed_script_ref(),
+        &merkle_root,
+        reward_amount,
+        deadline,
+        leaves.len() as u32,
+    );
+    let shard = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity(dist_capacity.pack())
+            .lock(deployment.distribution_lock.script(Bytes::new()))
+            .type_(Some(deployment.distribution_type.script(Bytes::new())).pack())
+            .build(),
+        to_versioned(&dist_data),
+    );
+
+    // generate the bundles and check the subscriber's against the shard
+    let bundles = shard_bundles(&campaign_id.clone().into(), &shard.out_point, &claimants);
+    assert_eq!(bundles.len(), 2);
+    let bundle =
+        ClaimBundle::try_from(&ClaimBundleJson::from(&bundles[0])).expect("parse bundle from json");
+    assert_eq!(bundle.as_slice(), bundles[0].as_slice());
+    verify_bundle(&bundle, &dist_data).expect("verify bundle");
+    assert_eq!(to_out_point(&bundle.proof_out_point()), proof.out_point);
+    assert_eq!(to_out_point(&bundle.shard_out_point()), shard.out_point);
+
+    // build the claim from the bundle
+    let proof_reveal = populate_proof_reveal();
+    let tx = ClaimBuilder::new(deployment, shard, proof)
+        .expect("load shard and proof")
+        .merkle_proof(bundle.merkle_proof().into_iter().map(Into::into).collect())
+        .proof_reveal(proof_reveal.proof().into(), proof_reveal.salt().into())
+        .build()
+        .expect("build claim");
+
+    // balance the transaction with the subscriber's input and change
+    let subscriber_change_output = CellOutput::new_builder()
+        .lock(subscriber_lock_script)
+        .build();
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .input(subscriber_fee_input)
+        .output(subscriber_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, 10_000_000)
+        .expect("pass verification");
+    println!(
+        "consume cycles for distribution claim from bundle: {}",
+        cycles
+    );
+}
+
+#[test]
+fn test_claim_distribution_targeted_entity() {
+    // deploy contracts
+    let mut context = Context::default();
+    let dist_lock_bin = Loader::default().load_binary("distribution-lock");
+    let dist_lock_out_point = context.deploy_cell(dist_lock_bin);
+    let dist_lock_dep = CellDep::new_builder()
+        .out_point(dist_lock_out_point.clone())
+        .build();
+
+    let dist_type_bin = Loader::default().load_binary("distribution-type");
+    let dist_type_out_point = context.deploy_cell(dist_type_bin);
+    let dist_type_dep = CellDep::new_builder()
+        .out_point(dist_type_out_point.clone())
+        .build();
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    let proof_bin = Loader::default().load_binary("proof-type");
+    let proof_out_point = context.deploy_cell(proof_bin.clone());
+    let proof_script_dep = CellDep::new_builder()
+        .out_point(proof_out_point.clone())
+        .build();
+    let proof_code_hash = get_code_hash(&mut context, &proof_out_point);
+
+    let entity_bin = Loader::default().load_binary("entity-type");
+    let entity_out_point = context.deploy_cell(entity_bin);
+    let entity_registry = populate_script_ref(
+        &Byte32::from_slice(get_code_hash(&mut context, &entity_out_point).as_slice()).unwrap(),
+    );
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![0]))
+        .unwrap();
+    let admin_lock_hash =
+        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let subscriber_lock_hash =
+        Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let other_subscriber_lock = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let other_subscriber_lock_hash =
+        Byte32::from_slice(other_subscriber_lock.calc_script_hash().as_slice()).unwrap();
+
+    // prepare data
+    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
+    let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id);
+    let proof_type_id = Byte32::from_slice(&[4; 32]).unwrap(); // dummy type id
+    let distribution_type = populate_script_ref(
+        &Byte32::from_slice(get_code_hash(&mut context, &dist_type_out_point).as_slice()).unwrap(),
+    );
+    let proof_type_script = context
+        .build_script(
+            &proof_out_point,
+            populate_proof_type_args(&proof_type_id, &entity_registry, &distribution_type),
+        )
+        .unwrap();
+    let proof_cell_capacity = 254 * 100_000_000u64;
+    let proof_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(proof_cell_capacity.pack())
+            .lock(subscriber_lock_script.clone())
+            .type_(Some(proof_type_script).pack())
+            .build(),
+        to_versioned(&proof_data),
+    );
+    let proof_input = CellInput::new_builder()
+        .previous_output(proof_input_out_point.clone())
+        .build();
+
+    // Add a cell for the subscriber to pay for fees
+    let subscriber_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(subscriber_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let subscriber_fee_input = CellInput::new_builder()
+        .previous_output(subscriber_fee_input_out_point)
+        .build();
+
+    // prepare Merkle Tree
+    let mut leaf_data_1 = vec![];
+    leaf_data_1.extend_from_slice(proof_type_id.as_slice());
+    leaf_data_1.extend_from_slice(subscriber_lock_hash.as_slice());
+    let leaf0 = util::blake2b_256(leaf_data_1);
+
+    let other_proof_type_id = Byte32::from_slice(&[5; 32]).unwrap();
+    let mut leaf_data_2 = vec![];
+    leaf_data_2.extend_from_slice(other_proof_type_id.as_slice());
+    leaf_data_2.extend_from_slice(other_subscriber_lock_hash.as_slice());
+    let leaf1 = util::blake2b_256(leaf_data_2);
+
+    let leaves = vec![leaf0, leaf1];
+    let merkle_root = util::build_merkle_root(&leaves);
+    let merkle_proof = util::build_merkle_proof(&leaves, 0);
+
+    // prepare the entities targeted by the campaign, the proof's one included
+    let entity_leaves = vec![
+        proof_data.entity_id().into(),
+        [7u8; 32], // another entity of the campaign
+    ];
+    let entity_root = util::build_merkle_root(&entity_leaves);
+    let entity_proof = util::build_merkle_proof(&entity_leaves, 0);
+
+    // prepare distribution shard
+    let reward_amount = 100 * 100_000_000u64;
+    let dist_capacity = reward_amount * leaves.len() as u64;
+    let dist_lock_script = context
+        .build_script(&dist_lock_out_point, Default::default())
+        .unwrap();
+    let dist_type_script = context
+        .build_script(&dist_type_out_point, Default::default())
+        .unwrap();
+    let deadline = 1_000_000u64;
+    let dist_data = populate_distribution_data(
+        &campaign_id,
+        &admin_lock_hash,
+        &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
+        &entity_registry,
+        &merkle_root,
+        reward_amount,
+        deadline,
+        leaves.len() as u32,
+    )
+    .as_builder()
+    .entity_root(Byte32::from_slice(&entity_root).unwrap())
+    .build();
+    let dist_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(dist_capacity.pack())
+            .lock(dist_lock_script.clone())
+            .type_(Some(dist_type_script.clone()).pack())
+            .build(),
+        to_versioned(&dist_data),
+    );
+    let dist_input = CellInput::new_builder()
+        .previous_output(dist_input_out_point)
+        .since(0.pack())
+        .build();
+
+    // prepare outputs
+    let new_dist_capacity = dist_capacity - reward_amount;
+    let new_dist_data = dist_data
+        .clone()
+        .as_builder()
+        .remaining_claims((leaves.len() as u32 - 1).pack())
+        .build();
+    let dist_output = CellOutput::new_builder()
+        .capacity(new_dist_capacity.pack())
+        .lock(dist_lock_script)
+        .type_(Some(dist_type_script).pack())
+        .build();
+
+    let reward_output = CellOutput::new_builder()
+        .capacity((reward_amount + proof_cell_capacity).pack())
+        .lock(subscriber_lock_script.clone())
+        .build();
+
+    let subscriber_change_output = CellOutput::new_builder()
+        .lock(subscriber_lock_script)
+        .build();
+
+    // prepare witness
+    let claim_witness =
+        populate_claim_witness(&proof_type_id, &subscriber_lock_hash, &merkle_proof)
+            .as_builder()
+            .entity_proof(
+                Byte32Vec::new_builder()
+                    .extend(
+                        entity_proof
+                            .iter()
+                            .map(|hash| Byte32::from_slice(hash).unwrap()),
+                    )
+                    .build(),
+            )
+            .build();
+    let witness_for_dist = WitnessArgs::new_builder()
+        .lock(Some(claim_witness.as_bytes()).pack())
+        .build();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .cell_dep(always_success_dep)
+        .cell_dep(dist_lock_dep)
+        .cell_dep(dist_type_dep)
+        .cell_dep(proof_script_dep)
+        .inputs([dist_input, proof_input, subscriber_fee_input])
+        .outputs([dist_output, reward_output, subscriber_change_output])
+        .outputs_data(
+            [
+                to_versioned(&new_dist_data),
+                Bytes::from(""),
+                Bytes::from(""),
+            ]
+            .pack(),
+        )
+        .witness(witness_for_dist.as_bytes().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, 10_000_000)
+        .expect("pass verification");
+    println!(
+        "consume cycles for distribution claim of a targeted entity: {}",
+        cycles
+    );
+}
+
+#[test]
+fn test_claim_distribution_among_targeted_entities() {
+    verify_entity_targeted_claim(true).expect("pass verification");
+}
+
+#[test]
+fn test_claim_distribution_entity_not_targeted() {
+    let err = verify_entity_targeted_claim(false).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionType,
+        DistributionTypeError::EntityNotTargeted.name(),
+    );
+}
+
+/// Claims from a shard targeting three entities, with a proof whose entity is
+/// the second of them when `targeted`, or none of them otherwise.
+fn verify_entity_targeted_claim(targeted: bool) -> Result<Cycle, Error> {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![0]))
+        .unwrap();
+    let admin_lock_hash =
+        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let subscriber_lock_hash =
+        Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare data
+    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
+    let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id);
+    let proof_type_id = [4u8; 32]; // dummy type id
+    let proof = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity((254 * 100_000_000u64).pack())
+            .lock(subscriber_lock_script.clone())
+            .type_(
+                Some(
+                    deployment
+                        .proof_type
+                        .script(proof_type_args(&deployment, &proof_type_id)),
+                )
+                .pack(),
+            )
+            .build(),
+        to_versioned(&proof_data),
+    );
+
+    // Add a cell for the subscriber to pay for fees
+    let subscriber_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(subscriber_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let subscriber_fee_input = CellInput::new_builder()
+        .previous_output(subscriber_fee_input_out_point)
+        .build();
+
+    // prepare Merkle Tree
+    let leaf0 = claimant_leaf(&proof_type_id, &subscriber_lock_hash.into(), None);
+    let leaf1 = claimant_leaf(&[5; 32], &[6; 32], None);
+
+    let leaves = vec![leaf0, leaf1];
+    let merkle_root = merkle::merkle_root(&leaves);
+    let merkle_proof = merkle::merkle_proof(&leaves, 0);
+
+    // prepare the entities targeted by the campaign
+    let proof_entity_id: [u8; 32] = proof_data.entity_id().into();
+    let entity_ids = vec![
+        [7u8; 32],
+        if targeted { proof_entity_id } else { [8u8; 32] },
+        [9u8; 32],
+    ];
+    let entity_root = merkle::entity_root(&entity_ids);
+    let entity_proof = merkle::merkle_proof(&entity_ids, 1);
+
+    // prepare distribution shard
+    let reward_amount = 100 * 100_000_000u64;
+    let dist_capacity = reward_amount * leaves.len() as u64;
+    let dist_data = populate_distribution_data(
+        &campaign_id,
+        &admin_lock_hash,
+        &Byte32::from_slice(deployment.proof_type.code_hash.as_slice()).unwrap(),
+        &deployment.entity_type.packed_script_ref(),
+        &merkle_root,
+        reward_amount,
+        1_000_000,
+        leaves.len() as u32,
+    )
+    .as_builder()
+    .entity_root(Byte32::from(entity_root))
+    .build();
+    let shard = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity(dist_capacity.pack())
+            .lock(deployment.distribution_lock.script(Bytes::new()))
+            .type_(Some(deployment.distribution_type.script(Bytes::new())).pack())
+            .build(),
+        to_versioned(&dist_data),
+    );
+
+    // build the claim
+    let proof_reveal = populate_proof_reveal();
+    let tx = ClaimBuilder::new(deployment, shard, proof)
+        .expect("load shard and proof")
+        .merkle_proof(merkle_proof)
+        .proof_reveal(proof_reveal.proof().into(), proof_reveal.salt().into())
+        .entity_proof(entity_proof)
+        .build()
+        .expect("build claim");
+
+    // balance the transaction with the subscriber's input and change
+    let subscriber_change_output = CellOutput::new_builder()
+        .lock(subscriber_lock_script)
+        .build();
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .input(subscriber_fee_input)
+        .output(subscriber_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    context.verify_tx(&tx, 10_000_000)
+}
+
+#[test]
+fn test_claim_distribution_with_referral() {
+    // deploy contracts
+    let mut context = Context::default();
+    let dist_lock_bin = Loader::default().load_binary("distribution-lock");
+    let dist_lock_out_point = context.deploy_cell(dist_lock_bin);
+    let dist_lock_dep = CellDep::new_builder()
+        .out_point(dist_lock_out_point.clone())
+        .build();
+
+    let dist_type_bin = Loader::default().load_binary("distribution-type");
+    let dist_type_out_point = context.deploy_cell(dist_type_bin);
+    let dist_type_dep = CellDep::new_builder()
+        .out_point(dist_type_out_point.clone())
+        .build();
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    let proof_bin = Loader::default().load_binary("proof-type");
+    let proof_out_point = context.deploy_cell(proof_bin.clone());
+    let proof_script_dep = CellDep::new_builder()
+        .out_point(proof_out_point.clone())
+        .build();
+    let proof_code_hash = get_code_hash(&mut context, &proof_out_point);
+
+    let entity_bin = Loader::default().load_binary("entity-type");
+    let entity_out_point = context.deploy_cell(entity_bin);
+    let entity_registry = populate_script_ref(
+        &Byte32::from_slice(get_code_hash(&mut context, &entity_out_point).as_slice()).unwrap(),
+    );
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![0]))
+        .unwrap();
+    let admin_lock_hash =
+        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let subscriber_lock_hash =
+        Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let other_subscriber_lock = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let other_subscriber_lock_hash =
+        Byte32::from_slice(other_subscriber_lock.calc_script_hash().as_slice()).unwrap();
+
+    // prepare data
+    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
+    let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id);
+    let proof_type_id = Byte32::from_slice(&[4; 32]).unwrap(); // dummy type id
+    let distribution_type = populate_script_ref(
+        &Byte32::from_slice(get_code_hash(&mut context, &dist_type_out_point).as_slice()).unwrap(),
+    );
+    let proof_type_script = context
+        .build_script(
+            &proof_out_point,
+            populate_proof_type_args(&proof_type_id, &entity_registry, &distribution_type),
+        )
+        .unwrap();
+    let proof_cell_capacity = 254 * 100_000_000u64;
+    let proof_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(proof_cell_capacity.pack())
+            .lock(subscriber_lock_script.clone())
+            .type_(Some(proof_type_script).pack())
+            .build(),
+        to_versioned(&proof_data),
+    );
+    let proof_input = CellInput::new_builder()
+        .previous_output(proof_input_out_point.clone())
+        .build();
+
+    // Add a cell for the subscriber to pay for fees
+    let subscriber_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(subscriber_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let subscriber_fee_input = CellInput::new_builder()
+        .previous_output(subscriber_fee_input_out_point)
+        .build();
+
+    // prepare referral
+    let referrer_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![3]))
+        .unwrap();
+    let referrer_lock_hash =
+        Byte32::from_slice(referrer_lock_script.calc_script_hash().as_slice()).unwrap();
+    let bonus_amount = 10 * 100_000_000u64;
+    let referral = populate_referral(&referrer_lock_hash, bonus_amount);
+
+    // prepare Merkle Tree: the referred claimant's leaf commits the referral
+    let mut leaf_data_1 = vec![];
+    leaf_data_1.extend_from_slice(proof_type_id.as_slice());
+    leaf_data_1.extend_from_slice(subscriber_lock_hash.as_slice());
+    leaf_data_1.extend_from_slice(referral.as_slice());
+    let leaf0 = util::blake2b_256(leaf_data_1);
+
+    let other_proof_type_id = Byte32::from_slice(&[5; 32]).unwrap();
+    let mut leaf_data_2 = vec![];
+    leaf_data_2.extend_from_slice(other_proof_type_id.as_slice());
+    leaf_data_2.extend_from_slice(other_subscriber_lock_hash.as_slice());
+    let leaf1 = util::blake2b_256(leaf_data_2);
+
+    let leaves = vec![leaf0, leaf1];
+    let merkle_root = util::build_merkle_root(&leaves);
+    let merkle_proof = util::build_merkle_proof(&leaves, 0);
+
+    // prepare distribution shard
+    let reward_amount = 100 * 100_000_000u64;
+    let referral_budget = 2 * bonus_amount;
+    let dist_capacity = reward_amount * leaves.len() as u64 + referral_budget;
+    let dist_lock_script = context
+        .build_script(&dist_lock_out_point, Default::default())
+        .unwrap();
+    let dist_type_script = context
+        .build_script(&dist_type_out_point, Default::default())
+        .unwrap();
+    let deadline = 1_000_000u64;
+    let dist_data = populate_distribution_data(
+        &campaign_id,
+        &admin_lock_hash,
+        &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
+        &entity_registry,
+        &merkle_root,
+        reward_amount,
+        deadline,
+        leaves.len() as u32,
+    )
+    .as_builder()
+    .referral_budget(referral_budget.pack())
+    .build();
+    let dist_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(dist_capacity.pack())
+            .lock(dist_lock_script.clone())
+            .type_(Some(dist_type_script.clone()).pack())
+            .build(),
+        to_versioned(&dist_data),
+    );
+    let dist_input = CellInput::new_builder()
+        .previous_output(dist_input_out_point)
+        .since(0.pack())
+        .build();
+
+    // prepare outputs
+    let new_dist_capacity = dist_capacity - reward_amount - bonus_amount;
+    let new_dist_data = dist_data
+        .clone()
+        .as_builder()
+        .remaining_claims((leaves.len() as u32 - 1).pack())
+        .referral_budget((referral_budget - bonus_amount).pack())
+        .build();
+    let dist_output = CellOutput::new_builder()
+        .capacity(new_dist_capacity.pack())
+        .lock(dist_lock_script)
+        .type_(Some(dist_type_script).pack())
+        .build();
+
+    let reward_output = CellOutput::new_builder()
+        .capacity((reward_amount + proof_cell_capacity).pack())
+        .lock(subscriber_lock_script.clone())
+        .build();
+
+    let referral_output = CellOutput::new_builder()
+        .capacity(bonus_amount.pack())
+        .lock(referrer_lock_script)
+        .build();
+
+    let subscriber_change_output = CellOutput::new_builder()
+        .lock(subscriber_lock_script)
+        .build();
+
+    // prepare witness
+    let claim_witness =
+        populate_claim_witness(&proof_type_id, &subscriber_lock_hash, &merkle_proof)
+            .as_builder()
+            .referral(ReferralOpt::new_builder().set(Some(referral)).build())
+            .build();
+    let witness_for_dist = WitnessArgs::new_builder()
+        .lock(Some(claim_witness.as_bytes()).pack())
+        .build();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .cell_dep(always_success_dep)
+        .cell_dep(dist_lock_dep)
+        .cell_dep(dist_type_dep)
+        .cell_dep(proof_script_dep)
+        .inputs([dist_input, proof_input, subscriber_fee_input])
+        .outputs([
+            dist_output,
+            reward_output,
+            referral_output,
+            subscriber_change_output,
+        ])
+        .outputs_data(
+            [
+                to_versioned(&new_dist_data),
+                Bytes::from(""),
+                Bytes::from(""),
+                Bytes::from(""),
+            ]
+            .pack(),
+        )
+        .witness(witness_for_dist.as_bytes().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, 10_000_000)
+        .expect("pass verification");
+    println!(
+        "consume cycles for distribution claim with referral: {}",
+        cycles
+    );
+}
+
+#[test]
+fn test_claim_distribution_referral_payout_missing() {
+    let err = verify_referral_claim(3, 20 * 100_000_000, false).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionType,
+        DistributionTypeError::ReferralPayoutMissing.name(),
+    );
+}
+
+#[test]
+fn test_claim_distribution_referral_budget_exceeded() {
+    // the 10 CKB bonus is more than what is left of the budget
+    let err = verify_referral_claim(3, 5 * 100_000_000, true).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionType,
+        DistributionTypeError::ReferralBudgetExceeded.name(),
+    );
+}
+
+#[test]
+fn test_claim_distribution_self_referral() {
+    // the subscriber refers itself, so the bonus would go to its reward's lock
+    let err = verify_referral_claim(1, 20 * 100_000_000, true).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionType,
+        DistributionTypeError::ReferralInvalid.name(),
+    );
+}
+
+/// Claims with a 10 CKB referral bonus to the lock with args `referrer`, from a
+/// shard left with `referral_budget`, and pays the referrer when
+/// `pay_referrer`. The subscriber's lock has args 1.
+fn verify_referral_claim(
+    referrer: u8,
+    referral_budget: u64,
+    pay_referrer: bool,
+) -> Result<Cycle, Error> {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![0]))
+        .unwrap();
+    let admin_lock_hash =
+        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let subscriber_lock_hash =
+        Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare data
+    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
+    let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id);
+    let proof_type_id = [4u8; 32]; // dummy type id
+    let proof = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity((254 * 100_000_000u64).pack())
+            .lock(subscriber_lock_script.clone())
+            .type_(
+                Some(
+                    deployment
+                        .proof_type
+                        .script(proof_type_args(&deployment, &proof_type_id)),
+                )
+                .pack(),
+            )
+            .build(),
+        to_versioned(&proof_data),
+    );
+
+    // Add a cell for the subscriber to pay for fees
+    let subscriber_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(subscriber_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let subscriber_fee_input = CellInput::new_builder()
+        .previous_output(subscriber_fee_input_out_point)
+        .build();
+
+    // prepare referral
+    let referrer_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![referrer]))
+        .unwrap();
+    let referrer_lock_hash: [u8; 32] = referrer_lock_script.calc_script_hash().unpack();
+    let bonus_amount = 10 * 100_000_000u64;
+
+    // prepare Merkle Tree: the referred claimant's leaf commits the referral
+    let leaf0 = claimant_leaf(
+        &proof_type_id,
+        &subscriber_lock_hash.clone().into(),
+        Some(&merkle::referral(&referrer_lock_hash, bonus_amount)),
+    );
+    let leaf1 = claimant_leaf(&[5; 32], &[6; 32], None);
+
+    let leaves = vec![leaf0, leaf1];
+    let merkle_root = merkle::merkle_root(&leaves);
+    let merkle_proof = merkle::merkle_proof(&leaves, 0);
+
+    // prepare distribution shard, with enough budget for the SDK to build the claim
+    let reward_amount = 100 * 100_000_000u64;
+    let dist_data = populate_distribution_data(
+        &campaign_id,
+        &admin_lock_hash,
+        &Byte32::from_slice(deployment.proof_type.code_hash.as_slice()).unwrap(),
+        &deployment.entity_type.packed_script_ref(),
+        &merkle_root,
+        reward_amount,
+        1_000_000,
+        leaves.len() as u32,
+    );
+    let shard_output = |referral_budget: u64| {
+        CellOutput::new_builder()
+            .capacity((reward_amount * leaves.len() as u64 + referral_budget).pack())
+            .lock(deployment.distribution_lock.script(Bytes::new()))
+            .type_(Some(deployment.distribution_type.script(Bytes::new())).pack())
+            .build()
+    };
+    let sdk_referral_budget = referral_budget.max(bonus_amount);
+    let shard = create_live_cell(
+        &mut context,
+        shard_output(sdk_referral_budget),
+        to_versioned(
+            &dist_data
+                .clone()
+                .as_builder()
+                .referral_budget(sdk_referral_budget.pack())
+                .build(),
+        ),
+    );
+
+    // build the claim
+    let proof_reveal = populate_proof_reveal();
+    let tx = ClaimBuilder::new(deployment.clone(), shard.clone(), proof)
+        .expect("load shard and proof")
+        .merkle_proof(merkle_proof)
+        .proof_reveal(proof_reveal.proof().into(), proof_reveal.salt().into())
+        .referral(referrer_lock_script, bonus_amount)
+        .build()
+        .expect("build claim");
+
+    // The SDK refuses to overrun the budget, so swap in a poorer shard afterwards.
+    let tx = if referral_budget != sdk_referral_budget {
+        let poorer_shard = create_live_cell(
+            &mut context,
+            shard_output(referral_budget),
+            to_versioned(
+                &dist_data
+                    .as_builder()
+                    .referral_budget(referral_budget.pack())
+                    .build(),
+            ),
+        );
+        let inputs: Vec<CellInput> = tx
+            .inputs()
+            .into_iter()
+            .map(|input| {
+                if input.previous_output().as_slice() == shard.out_point.as_slice() {
+                    poorer_shard.input(0)
+                } else {
+                    input
+                }
+            })
+            .collect();
+        tx.as_advanced_builder().set_inputs(inputs).build()
+    } else {
+        tx
+    };
+
+    // drop the referrer's bonus cell, the third output after the shard and the reward
+    let tx = if pay_referrer {
+        tx
+    } else {
+        let outputs_with_data: Vec<(CellOutput, Bytes)> = tx
+            .outputs_with_data_iter()
+            .enumerate()
+            .filter(|(i, _)| *i != 2)
+            .map(|(_, output_with_data)| output_with_data)
+            .collect();
+        tx.as_advanced_builder()
+            .set_outputs(
+                outputs_with_data
+                    .iter()
+                    .map(|(output, _)| output.clone())
+                    .collect(),
+            )
+            .set_outputs_data(
+                outputs_with_data
+                    .iter()
+                    .map(|(_, data)| data.pack())
+                    .collect(),
+            )
+            .build()
+    };
+
+    // balance the transaction with the subscriber's input and change
+    let subscriber_change_output = CellOutput::new_builder()
+        .lock(subscriber_lock_script)
+        .build();
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .input(subscriber_fee_input)
+        .output(subscriber_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    context.verify_tx(&tx, 10_000_000)
+}
+
+#[test]
+fn test_final_claim_distribution_no_dust() {
+    // deploy contracts
+    let mut context = Context::default();
+    let dist_lock_bin = Loader::default().load_binary("distribution-lock");
+    let dist_lock_out_point = context.deploy_cell(dist_lock_bin);
+    let dist_lock_dep = CellDep::new_builder()
+        .out_point(dist_lock_out_point.clone())
+        .build();
+
+    let dist_type_bin = Loader::default().load_binary("distribution-type");
+    let dist_type_out_point = context.deploy_cell(dist_type_bin);
+    let dist_type_dep = CellDep::new_builder()
+        .out_point(dist_type_out_point.clone())
+        .build();
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    let proof_bin = Loader::default().load_binary("proof-type");
+    let proof_out_point = context.deploy_cell(proof_bin.clone());
+    let proof_script_dep = CellDep::new_builder()
+        .out_point(proof_out_point.clone())
+        .build();
+    let proof_code_hash = get_code_hash(&mut context, &proof_out_point);
+
+    let entity_bin = Loader::default().load_binary("entity-type");
+    let entity_out_point = context.deploy_cell(entity_bin);
+    let entity_registry = populate_script_ref(
+        &Byte32::from_slice(get_code_hash(&mut context, &entity_out_point).as_slice()).unwrap(),
+    );
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![0]))
+        .unwrap();
+    let admin_lock_hash =
+        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let subscriber_lock_hash =
+        Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare data
+    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
+    let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id);
+    let proof_type_id = Byte32::from_slice(&[4; 32]).unwrap(); // dummy type id
+    let distribution_type = populate_script_ref(
+        &Byte32::from_slice(get_code_hash(&mut context, &dist_type_out_point).as_slice()).unwrap(),
+    );
+    let proof_type_script = context
+        .build_script(
+            &proof_out_point,
+            populate_proof_type_args(&proof_type_id, &entity_registry, &distribution_type),
+        )
+        .unwrap();
+    let proof_cell_capacity = 254 * 100_000_000u64;
+    let proof_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(proof_cell_capacity.pack())
+            .lock(subscriber_lock_script.clone())
+            .type_(Some(proof_type_script).pack())
+            .build(),
+        to_versioned(&proof_data),
+    );
+    let proof_input = CellInput::new_builder()
+        .previous_output(proof_input_out_point.clone())
+        .build();
+
+    // Add a cell for the subscriber to pay for fees
+    let subscriber_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(subscriber_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let subscriber_fee_input = CellInput::new_builder()
+        .previous_output(subscriber_fee_input_out_point)
+        .build();
+
+    // prepare Merkle Tree (only one leaf for final claim)
+    let mut leaf_data = vec![];
+    leaf_data.extend_from_slice(proof_type_id.as_slice());
+    leaf_data.extend_from_slice(subscriber_lock_hash.as_slice());
+    let leaf0 = util::blake2b_256(leaf_data);
+
+    let leaves = vec![leaf0];
+    let merkle_root = util::build_merkle_root(&leaves);
+    let merkle_proof = util::build_merkle_proof(&leaves, 0);
+
+    // prepare distribution shard
+    let reward_amount = 100 * 100_000_000u64;
+    let dist_capacity = reward_amount; // No dust
+    let dist_lock_script = context
+        .build_script(&dist_lock_out_point, Default::default())
+        .unwrap();
+    let dist_type_script = context
+        .build_script(&dist_type_out_point, Default::default())
+        .unwrap();
+    let deadline = 1_000_000u64;
+    let dist_data = populate_distribution_data(
+        &campaign_id,
+        &admin_lock_hash,
+        &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
+        &entity_registry,
+        &merkle_root,
+        reward_amount,
+        deadline,
+        1,
+    );
+    let dist_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(dist_capacity.pack())
+            .lock(dist_lock_script.clone())
+            .type_(Some(dist_type_script).pack())
+            .build(),
+        to_versioned(&dist_data),
+    );
+    let dist_input = CellInput::new_builder()
+        .previous_output(dist_input_out_point)
+        .since(0.pack())
+        .build();
+
+    // prepare outputs
+    let reward_output = CellOutput::new_builder()
+        .capacity((reward_amount + proof_cell_capacity).pack())
+        .lock(subscriber_lock_script.clone())
+        .build();
+
+    let subscriber_change_output = CellOutput::new_builder()
+        .lock(subscriber_lock_script)
+        .build();
+
+    // prepare witness
+    let claim_witness =
+        populate_claim_witness(&proof_type_id, &subscriber_lock_hash, &merkle_proof);
+    let witness_for_dist = WitnessArgs::new_builder()
+        .lock(Some(claim_witness.as_bytes()).pack())
+        .build();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .cell_dep(always_success_dep)
+        .cell_dep(dist_lock_dep)
+        .cell_dep(dist_type_dep)
+        .cell_dep(proof_script_dep)
+        .inputs([dist_input, proof_input, subscriber_fee_input])
+        .outputs([reward_output, subscriber_change_output])
+        .outputs_data([Bytes::new(), Bytes::new()].pack())
+        .witness(witness_for_dist.as_bytes().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, 10_000_000)
+        .expect("pass verification");
+    println!(
+        "consume cycles for distribution final claim no dust: {}",
+        cycles
+    );
+}
+
+#[test]
+fn test_final_claim_distribution_keeping_proof() {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![0]))
+        .unwrap();
+    let admin_lock_hash =
+        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let subscriber_lock_hash =
+        Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare data
+    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
+    let other_campaign_id = Byte32::from_slice(&[2; 32]).unwrap();
+    let proof_data = populate_proof_data_with_campaigns(
+        &subscriber_lock_hash,
+        &[campaign_id.clone(), other_campaign_id],
+    );
+    let proof_type_id = [4u8; 32]; // dummy type id
+    let proof_cell_capacity = 254 * 100_000_000u64;
+    let proof = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity(proof_cell_capacity.pack())
+            .lock(subscriber_lock_script.clone())
+            .type_(
+                Some(
+                    deployment
+                        .proof_type
+                        .script(proof_type_args(&deployment, &proof_type_id)),
+                )
+                .pack(),
+            )
+            .build(),
+        to_versioned(&proof_data),
+    );
+
+    // Add a cell for the subscriber to pay for fees
+    let subscriber_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(subscriber_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let subscriber_fee_input = CellInput::new_builder()
+        .previous_output(subscriber_fee_input_out_point)
+        .build();
+
+    // prepare Merkle Tree (only one leaf for final claim)
+    let leaf0 = claimant_leaf(&proof_type_id, &subscriber_lock_hash.into(), None);
+
+    let leaves = vec![leaf0];
+    let merkle_root = merkle::merkle_root(&leaves);
+    let merkle_proof = merkle::merkle_proof(&leaves, 0);
+
+    // prepare distribution shard
+    let reward_amount = 100 * 100_000_000u64;
+    let dist_capacity = reward_amount; // No dust
+    let deadline = 1_000_000u64;
+    let dist_data = populate_distribution_data(
+        &campaign_id,
+        &admin_lock_hash,
+        &Byte32::from_slice(deployment.proof_type.code_hash.as_slice()).unwrap(),
+        &deployment.entity_type.packed_script_ref(),
+        &merkle_root,
+        reward_amount,
+        deadline,
+        1,
+    );
+    let shard = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity(dist_capacity.pack())
+            .lock(deployment.distribution_lock.script(Bytes::new()))
+            .type_(Some(deployment.distribution_type.script(Bytes::new())).pack())
+            .build(),
+        to_versioned(&dist_data),
+    );
+
+    // build the claim: the proof is kept for the other campaign, with this one marked as claimed
+    let proof_reveal = populate_proof_reveal();
+    let tx = ClaimBuilder::new(deployment, shard, proof)
+        .expect("load shard and proof")
+        .merkle_proof(merkle_proof)
+        .proof_reveal(proof_reveal.proof().into(), proof_reveal.salt().into())
+        .keep_proof(true)
+        .build()
+        .expect("build claim");
+
+    // balance the transaction with the subscriber's input and change
+    let subscriber_change_output = CellOutput::new_builder()
+        .lock(subscriber_lock_script)
+        .build();
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .input(subscriber_fee_input)
+        .output(subscriber_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, 10_000_000)
+        .expect("pass verification");
+    println!(
+        "consume cycles for distribution final claim keeping proof: {}",
+        cycles
+    );
 }
 
 #[test]
-fn test_claim_distribution() {
+fn test_final_claim_distribution_private() {
+    let cycles = verify_private_final_claim([7; 32], 9).expect("pass verification");
+    println!(
+        "consume cycles for distribution private final claim: {}",
+        cycles
+    );
+}
+
+#[test]
+fn test_final_claim_distribution_private_wrong_secret() {
+    let err = verify_private_final_claim([8; 32], 9).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionLock,
+        DistributionLockError::MerkleProofInvalid.name(),
+    );
+}
+
+#[test]
+fn test_final_claim_distribution_private_wrong_destination() {
+    let err = verify_private_final_claim([7; 32], 8).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionType,
+        DistributionTypeError::RewardLockHashMismatch.name(),
+    );
+}
+
+/// Makes the final private claim of a leaf committing to the secret `[7; 32]`,
+/// revealing `secret` in the witness with a destination lock of args 9, and
+/// paying the reward to the lock with args `reward_lock_args` instead.
+fn verify_private_final_claim(secret: [u8; 32], reward_lock_args: u8) -> Result<Cycle, Error> {
     // deploy contracts
     let mut context = Context::default();
-    let dist_lock_bin = Loader::default().load_binary("distribution-lock");
-    let dist_lock_out_point = context.deploy_cell(dist_lock_bin);
-    let dist_lock_dep = CellDep::new_builder()
-        .out_point(dist_lock_out_point.clone())
-        .build();
-
-    let dist_type_bin = Loader::default().load_binary("distribution-type");
-    let dist_type_out_point = context.deploy_cell(dist_type_bin);
-    let dist_type_dep = CellDep::new_builder()
-        .out_point(dist_type_out_point.clone())
-        .build();
+    let deployment = deploy_contracts(&mut context);
 
     let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
     let always_success_dep = CellDep::new_builder()
         .out_point(always_success_out_point.clone())
         .build();
 
-    let proof_bin = Loader::default().load_binary("proof-type");
-    let proof_out_point = context.deploy_cell(proof_bin.clone());
-    let proof_script_dep = CellDep::new_builder()
-        .out_point(proof_out_point.clone())
-        .build();
-    let proof_code_hash = get_code_hash(&mut context, &proof_out_point);
-
     // prepare scripts
     let admin_lock_script = context
         .build_script(&always_success_out_point, Bytes::from(vec![0]))
@@ -253,30 +3430,27 @@ fn test_claim_distribution() {
     let subscriber_lock_hash =
         Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
 
-    let other_subscriber_lock = context
-        .build_script(&always_success_out_point, Bytes::from(vec![2]))
-        .unwrap();
-    let other_subscriber_lock_hash =
-        Byte32::from_slice(other_subscriber_lock.calc_script_hash().as_slice()).unwrap();
-
     // prepare data
     let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
     let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id);
-    let proof_type_script = context
-        .build_script(&proof_out_point, Bytes::from(vec![0; 32])) // dummy type id
-        .unwrap();
+    let proof_type_id = [4u8; 32]; // dummy type id
     let proof_cell_capacity = 254 * 100_000_000u64;
-    let proof_input_out_point = context.create_cell(
+    let proof = create_live_cell(
+        &mut context,
         CellOutput::new_builder()
             .capacity(proof_cell_capacity.pack())
             .lock(subscriber_lock_script.clone())
-            .type_(Some(proof_type_script).pack())
+            .type_(
+                Some(
+                    deployment
+                        .proof_type
+                        .script(proof_type_args(&deployment, &proof_type_id)),
+                )
+                .pack(),
+            )
             .build(),
-        proof_data.as_bytes(),
+        to_versioned(&proof_data),
     );
-    let proof_input = CellInput::new_builder()
-        .previous_output(proof_input_out_point.clone())
-        .build();
 
     // Add a cell for the subscriber to pay for fees
     let subscriber_fee_input_out_point = context.create_cell(
@@ -290,110 +3464,111 @@ fn test_claim_distribution() {
         .previous_output(subscriber_fee_input_out_point)
         .build();
 
-    // prepare Merkle Tree
-    let mut leaf_data_1 = vec![];
-    leaf_data_1.extend_from_slice(proof_input_out_point.as_slice());
-    leaf_data_1.extend_from_slice(subscriber_lock_hash.as_slice());
-    let leaf0 = util::blake2b_256(leaf_data_1);
+    // prepare private claim: the reward goes to a destination other than the proof owner
+    let destination_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![9]))
+        .unwrap();
 
-    let other_proof_out_point = context.create_cell(
-        CellOutput::new_builder()
-            .capacity(1000u64.pack())
-            .lock(other_subscriber_lock)
-            .build(),
-        Bytes::new(),
-    );
-    let mut leaf_data_2 = vec![];
-    leaf_data_2.extend_from_slice(other_proof_out_point.as_slice());
-    leaf_data_2.extend_from_slice(other_subscriber_lock_hash.as_slice());
-    let leaf1 = util::blake2b_256(leaf_data_2);
+    // prepare Merkle Tree (only one leaf for final claim): the leaf commits hash(secret)
+    let leaf0 = private_claimant_leaf(&proof_type_id, &[7; 32], None);
 
-    let leaves = vec![leaf0, leaf1];
-    let merkle_root = util::build_merkle_root(&leaves);
-    let merkle_proof = util::build_merkle_proof(&leaves, 0);
+    let leaves = vec![leaf0];
+    let merkle_root = merkle::merkle_root(&leaves);
+    let merkle_proof = merkle::merkle_proof(&leaves, 0);
 
     // prepare distribution shard
     let reward_amount = 100 * 100_000_000u64;
-    let dist_capacity = reward_amount * leaves.len() as u64;
-    let dist_lock_script = context
-        .build_script(&dist_lock_out_point, Default::default())
-        .unwrap();
-    let dist_type_script = context
-        .build_script(&dist_type_out_point, Default::default())
-        .unwrap();
+    let dist_capacity = reward_amount; // No dust
     let deadline = 1_000_000u64;
     let dist_data = populate_distribution_data(
         &campaign_id,
         &admin_lock_hash,
-        &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
+        &Byte32::from_slice(deployment.proof_type.code_hash.as_slice()).unwrap(),
+        &deployment.entity_type.packed_script_ref(),
         &merkle_root,
         reward_amount,
         deadline,
+        1,
     );
-    let dist_input_out_point = context.create_cell(
+    let shard = create_live_cell(
+        &mut context,
         CellOutput::new_builder()
             .capacity(dist_capacity.pack())
-            .lock(dist_lock_script.clone())
-            .type_(Some(dist_type_script.clone()).pack())
+            .lock(deployment.distribution_lock.script(Bytes::new()))
+            .type_(Some(deployment.distribution_type.script(Bytes::new())).pack())
             .build(),
-        dist_data.as_bytes(),
+        to_versioned(&dist_data),
     );
-    let dist_input = CellInput::new_builder()
-        .previous_output(dist_input_out_point)
-        .since(0.pack())
-        .build();
-
-    // prepare outputs
-    let new_dist_capacity = dist_capacity - reward_amount;
-    let dist_output = CellOutput::new_builder()
-        .capacity(new_dist_capacity.pack())
-        .lock(dist_lock_script)
-        .type_(Some(dist_type_script).pack())
-        .build();
-
-    let reward_output = CellOutput::new_builder()
-        .capacity((reward_amount + proof_cell_capacity).pack())
-        .lock(subscriber_lock_script.clone())
-        .build();
 
+    // build the claim
+    let proof_reveal = populate_proof_reveal();
+    let tx = ClaimBuilder::new(deployment, shard, proof)
+        .expect("load shard and proof")
+        .merkle_proof(merkle_proof)
+        .proof_reveal(proof_reveal.proof().into(), proof_reveal.salt().into())
+        .private_claim(secret, destination_lock_script)
+        .build()
+        .expect("build claim");
+
+    // pay the reward, the first output, to another lock than the destination
+    let tx = if reward_lock_args != 9 {
+        let other_lock_script = context
+            .build_script(
+                &always_success_out_point,
+                Bytes::from(vec![reward_lock_args]),
+            )
+            .unwrap();
+        let mut outputs: Vec<CellOutput> = tx.outputs().into_iter().collect();
+        outputs[0] = outputs[0]
+            .clone()
+            .as_builder()
+            .lock(other_lock_script)
+            .build();
+        tx.as_advanced_builder().set_outputs(outputs).build()
+    } else {
+        tx
+    };
+
+    // balance the transaction with the subscriber's input and change
     let subscriber_change_output = CellOutput::new_builder()
         .lock(subscriber_lock_script)
         .build();
-
-    // prepare witness
-    let proof_cell_out_point_for_witness =
-        OutPoint::from_slice(proof_input_out_point.as_slice()).unwrap();
-    let claim_witness = populate_claim_witness(
-        &proof_cell_out_point_for_witness,
-        &subscriber_lock_hash,
-        &merkle_proof,
-    );
-    let witness_for_dist = WitnessArgs::new_builder()
-        .lock(Some(claim_witness.as_bytes()).pack())
-        .build();
-
-    // build transaction
-    let tx = TransactionBuilder::default()
+    let tx = tx
+        .as_advanced_builder()
         .cell_dep(always_success_dep)
-        .cell_dep(dist_lock_dep)
-        .cell_dep(dist_type_dep)
-        .cell_dep(proof_script_dep)
-        .inputs([dist_input, proof_input, subscriber_fee_input])
-        .outputs([dist_output, reward_output, subscriber_change_output])
-        .outputs_data([dist_data.as_bytes(), Bytes::from(""), Bytes::from("")].pack())
-        .witness(witness_for_dist.as_bytes().pack())
+        .input(subscriber_fee_input)
+        .output(subscriber_change_output)
+        .output_data(Bytes::new().pack())
         .build();
     let tx = context.complete_tx(tx);
 
     // run
-    let cycles = context
-        .verify_tx(&tx, 10_000_000)
-        .expect("pass verification");
-    println!("consume cycles for distribution claim: {}", cycles);
+    context.verify_tx(&tx, 10_000_000)
 }
 
 #[test]
-fn test_final_claim_distribution_no_dust() {
+fn test_final_claim_distribution_vested() {
+    let cycles = verify_vested_final_claim(ScriptHashType::Type).expect("pass verification");
+    println!(
+        "consume cycles for distribution final claim vested: {}",
+        cycles
+    );
+}
+
+#[test]
+fn test_final_claim_distribution_vested_wrong_hash_type() {
+    // The reward lock has the vesting lock's code hash, but runs other code.
+    let err = verify_vested_final_claim(ScriptHashType::Data1).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionType,
+        DistributionTypeError::RewardLockHashMismatch.name(),
+    );
+}
+
+/// Makes the final claim of a shard vesting its rewards, with the reward cell
+/// locked by the vesting lock's code hash under `reward_lock_hash_type`.
+fn verify_vested_final_claim(reward_lock_hash_type: ScriptHashType) -> Result<Cycle, Error> {
     // deploy contracts
     let mut context = Context::default();
     let dist_lock_bin = Loader::default().load_binary("distribution-lock");
@@ -420,6 +3595,16 @@ fn test_final_claim_distribution_no_dust() {
         .build();
     let proof_code_hash = get_code_hash(&mut context, &proof_out_point);
 
+    let entity_bin = Loader::default().load_binary("entity-type");
+    let entity_out_point = context.deploy_cell(entity_bin);
+    let entity_registry = populate_script_ref(
+        &Byte32::from_slice(get_code_hash(&mut context, &entity_out_point).as_slice()).unwrap(),
+    );
+
+    let vesting_lock_bin = Loader::default().load_binary("vesting-lock");
+    let vesting_lock_out_point = context.deploy_cell(vesting_lock_bin);
+    let vesting_lock_code_hash = get_code_hash(&mut context, &vesting_lock_out_point);
+
     // prepare scripts
     let admin_lock_script = context
         .build_script(&always_success_out_point, Bytes::from(vec![0]))
@@ -436,8 +3621,15 @@ fn test_final_claim_distribution_no_dust() {
     // prepare data
     let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
     let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id);
+    let proof_type_id = Byte32::from_slice(&[4; 32]).unwrap(); // dummy type id
+    let distribution_type = populate_script_ref(
+        &Byte32::from_slice(get_code_hash(&mut context, &dist_type_out_point).as_slice()).unwrap(),
+    );
     let proof_type_script = context
-        .build_script(&proof_out_point, Bytes::from(vec![0; 32])) // dummy type id
+        .build_script(
+            &proof_out_point,
+            populate_proof_type_args(&proof_type_id, &entity_registry, &distribution_type),
+        )
         .unwrap();
     let proof_cell_capacity = 254 * 100_000_000u64;
     let proof_input_out_point = context.create_cell(
@@ -446,7 +3638,7 @@ fn test_final_claim_distribution_no_dust() {
             .lock(subscriber_lock_script.clone())
             .type_(Some(proof_type_script).pack())
             .build(),
-        proof_data.as_bytes(),
+        to_versioned(&proof_data),
     );
     let proof_input = CellInput::new_builder()
         .previous_output(proof_input_out_point.clone())
@@ -466,7 +3658,7 @@ fn test_final_claim_distribution_no_dust() {
 
     // prepare Merkle Tree (only one leaf for final claim)
     let mut leaf_data = vec![];
-    leaf_data.extend_from_slice(proof_input_out_point.as_slice());
+    leaf_data.extend_from_slice(proof_type_id.as_slice());
     leaf_data.extend_from_slice(subscriber_lock_hash.as_slice());
     let leaf0 = util::blake2b_256(leaf_data);
 
@@ -484,31 +3676,54 @@ fn test_final_claim_distribution_no_dust() {
         .build_script(&dist_type_out_point, Default::default())
         .unwrap();
     let deadline = 1_000_000u64;
+    let vesting_start_epoch = 10u64;
+    let vesting_end_epoch = 20u64;
     let dist_data = populate_distribution_data(
         &campaign_id,
         &admin_lock_hash,
         &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
+        &entity_registry,
         &merkle_root,
         reward_amount,
         deadline,
-    );
+        1,
+    )
+    .as_builder()
+    .vesting(populate_vesting_config(
+        &Byte32::from_slice(vesting_lock_code_hash.as_slice()).unwrap(),
+        vesting_start_epoch,
+        vesting_end_epoch,
+    ))
+    .build();
     let dist_input_out_point = context.create_cell(
         CellOutput::new_builder()
             .capacity(dist_capacity.pack())
             .lock(dist_lock_script.clone())
             .type_(Some(dist_type_script).pack())
             .build(),
-        dist_data.as_bytes(),
+        to_versioned(&dist_data),
     );
     let dist_input = CellInput::new_builder()
         .previous_output(dist_input_out_point)
         .since(0.pack())
         .build();
 
-    // prepare outputs
+    // prepare outputs: the reward is locked by the vesting lock, releasing to the subscriber
+    let vesting_lock_args = populate_vesting_lock_args(
+        &subscriber_lock_hash,
+        reward_amount,
+        vesting_start_epoch,
+        vesting_end_epoch,
+    );
+    let vesting_lock_script = context
+        .build_script(&vesting_lock_out_point, vesting_lock_args.as_bytes())
+        .unwrap()
+        .as_builder()
+        .hash_type(reward_lock_hash_type.into())
+        .build();
     let reward_output = CellOutput::new_builder()
         .capacity((reward_amount + proof_cell_capacity).pack())
-        .lock(subscriber_lock_script.clone())
+        .lock(vesting_lock_script)
         .build();
 
     let subscriber_change_output = CellOutput::new_builder()
@@ -516,13 +3731,8 @@ fn test_final_claim_distribution_no_dust() {
         .build();
 
     // prepare witness
-    let proof_cell_out_point_for_witness =
-        OutPoint::from_slice(proof_input_out_point.as_slice()).unwrap();
-    let claim_witness = populate_claim_witness(
-        &proof_cell_out_point_for_witness,
-        &subscriber_lock_hash,
-        &merkle_proof,
-    );
+    let claim_witness =
+        populate_claim_witness(&proof_type_id, &subscriber_lock_hash, &merkle_proof);
     let witness_for_dist = WitnessArgs::new_builder()
         .lock(Some(claim_witness.as_bytes()).pack())
         .build();
@@ -540,41 +3750,20 @@ fn test_final_claim_distribution_no_dust() {
         .build();
     let tx = context.complete_tx(tx);
 
-    // run
-    let cycles = context
-        .verify_tx(&tx, 10_000_000)
-        .expect("pass verification");
-    println!(
-        "consume cycles for distribution final claim no dust: {}",
-        cycles
-    );
+    context.verify_tx(&tx, 10_000_000)
 }
 
 #[test]
 fn test_reclaim_distribution() {
     // deploy contracts
     let mut context = Context::default();
-    let dist_lock_bin = Loader::default().load_binary("distribution-lock");
-    let dist_lock_out_point = context.deploy_cell(dist_lock_bin);
-    let dist_lock_dep = CellDep::new_builder()
-        .out_point(dist_lock_out_point.clone())
-        .build();
-
-    let dist_type_bin = Loader::default().load_binary("distribution-type");
-    let dist_type_out_point = context.deploy_cell(dist_type_bin);
-    let dist_type_dep = CellDep::new_builder()
-        .out_point(dist_type_out_point.clone())
-        .build();
+    let deployment = deploy_contracts(&mut context);
 
     let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
     let always_success_dep = CellDep::new_builder()
         .out_point(always_success_out_point.clone())
         .build();
 
-    let proof_bin = Loader::default().load_binary("proof-type");
-    let proof_out_point = context.deploy_cell(proof_bin.clone());
-    let proof_code_hash = get_code_hash(&mut context, &proof_out_point);
-
     // prepare scripts
     let admin_lock_script = context
         .build_script(&always_success_out_point, Bytes::from(vec![0]))
@@ -590,27 +3779,24 @@ fn test_reclaim_distribution() {
 
     // prepare distribution shard
     let dist_capacity = reward_amount * 10;
-    let dist_lock_script = context
-        .build_script(&dist_lock_out_point, Default::default())
-        .unwrap();
-    let dist_type_script = context
-        .build_script(&dist_type_out_point, Default::default())
-        .unwrap();
     let dist_data = populate_distribution_data(
         &campaign_id,
         &admin_lock_hash,
-        &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
+        &Byte32::from_slice(deployment.proof_type.code_hash.as_slice()).unwrap(),
+        &deployment.entity_type.packed_script_ref(),
         &merkle_root,
         reward_amount,
         deadline_s,
+        10,
     );
-    let dist_input_out_point = context.create_cell(
+    let shard = create_live_cell(
+        &mut context,
         CellOutput::new_builder()
             .capacity(dist_capacity.pack())
-            .lock(dist_lock_script.clone())
-            .type_(Some(dist_type_script.clone()).pack())
+            .lock(deployment.distribution_lock.script(Bytes::new()))
+            .type_(Some(deployment.distribution_type.script(Bytes::new())).pack())
             .build(),
-        dist_data.as_bytes(),
+        to_versioned(&dist_data),
     );
 
     // Add a cell for the admin to pay for fees
@@ -625,23 +3811,111 @@ fn test_reclaim_distribution() {
         .previous_output(admin_fee_input_out_point)
         .build();
 
-    // prepare input with `since`
+    // build the reclamation with `since`
     // The `since` value must be >= the deadline in the cell data.
     // The on-chain median timestamp must be >= the `since` value.
     let since_timestamp_s = deadline_s + 10; // Reclaim 10 seconds after deadline.
-    let since = 0x4000_0000_0000_0000u64 | since_timestamp_s;
-    let dist_input = CellInput::new_builder()
-        .previous_output(dist_input_out_point)
-        .since(since.pack())
-        .build();
+    let tx = ReclaimBuilder::new(deployment, shard, admin_lock_script.clone())
+        .expect("load shard")
+        .since_timestamp(since_timestamp_s)
+        .build()
+        .expect("build reclamation");
 
-    // prepare output (reclamation to admin)
-    let reclaim_output = CellOutput::new_builder()
-        .capacity(dist_capacity.pack())
-        .lock(admin_lock_script.clone())
+    // prepare header dep for `since` and script validation
+    // The header timestamp must be in milliseconds and its value in seconds must be >= the since value.
+    let header_timestamp_ms = since_timestamp_s * 1000;
+    let header = HeaderBuilder::default()
+        .timestamp(header_timestamp_ms.pack())
         .build();
+    context.insert_header(header.clone());
+    let header_dep = header.hash();
 
+    // balance the transaction with the admin's input and change (NO witness for the dist cell group)
     let admin_change_output = CellOutput::new_builder().lock(admin_lock_script).build();
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .header_dep(header_dep)
+        .input(admin_fee_input)
+        .output(admin_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, 10_000_000)
+        .expect("pass verification");
+    println!("consume cycles for distribution reclaim: {}", cycles);
+}
+
+#[test]
+fn test_reclaim_distribution_duplicate_payout() {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![0]))
+        .unwrap();
+    let admin_lock_hash =
+        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare data
+    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
+    let merkle_root = [0u8; 32];
+    let reward_amount = 100 * 100_000_000u64;
+    let deadline_s = 1_000_000u64; // Deadline in seconds.
+
+    // prepare distribution shard
+    let dist_capacity = reward_amount * 10;
+    let dist_data = populate_distribution_data(
+        &campaign_id,
+        &admin_lock_hash,
+        &Byte32::from_slice(deployment.proof_type.code_hash.as_slice()).unwrap(),
+        &deployment.entity_type.packed_script_ref(),
+        &merkle_root,
+        reward_amount,
+        deadline_s,
+        10,
+    );
+    let shard = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity(dist_capacity.pack())
+            .lock(deployment.distribution_lock.script(Bytes::new()))
+            .type_(Some(deployment.distribution_type.script(Bytes::new())).pack())
+            .build(),
+        to_versioned(&dist_data),
+    );
+
+    // Add a cell for the admin to pay for fees and for the second payout
+    let admin_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((dist_capacity + 200 * 100_000_000u64).pack())
+            .lock(admin_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let admin_fee_input = CellInput::new_builder()
+        .previous_output(admin_fee_input_out_point)
+        .build();
+
+    // build the reclamation with `since`
+    // The `since` value must be >= the deadline in the cell data.
+    // The on-chain median timestamp must be >= the `since` value.
+    let since_timestamp_s = deadline_s + 10; // Reclaim 10 seconds after deadline.
+    let tx = ReclaimBuilder::new(deployment, shard, admin_lock_script.clone())
+        .expect("load shard")
+        .since_timestamp(since_timestamp_s)
+        .build()
+        .expect("build reclamation");
 
     // prepare header dep for `since` and script validation
     // The header timestamp must be in milliseconds and its value in seconds must be >= the since value.
@@ -652,21 +3926,31 @@ fn test_reclaim_distribution() {
     context.insert_header(header.clone());
     let header_dep = header.hash();
 
-    // build transaction (NO witness for the dist cell group)
-    let tx = TransactionBuilder::default()
+    // pay the shard's capacity to the admin a second time, so two outputs qualify as the reclamation cell
+    let second_payout_output = CellOutput::new_builder()
+        .capacity(dist_capacity.pack())
+        .lock(admin_lock_script.clone())
+        .build();
+    let admin_change_output = CellOutput::new_builder().lock(admin_lock_script).build();
+    let tx = tx
+        .as_advanced_builder()
         .cell_dep(always_success_dep)
-        .cell_dep(dist_lock_dep)
-        .cell_dep(dist_type_dep)
         .header_dep(header_dep)
-        .inputs([dist_input, admin_fee_input])
-        .outputs([reclaim_output, admin_change_output])
-        .outputs_data([Bytes::new().pack(), Bytes::new().pack()])
+        .input(admin_fee_input)
+        .output(second_payout_output)
+        .output_data(Bytes::new().pack())
+        .output(admin_change_output)
+        .output_data(Bytes::new().pack())
         .build();
     let tx = context.complete_tx(tx);
 
     // run
-    let cycles = context
+    let err = context
         .verify_tx(&tx, 10_000_000)
-        .expect("pass verification");
-    println!("consume cycles for distribution reclaim: {}", cycles);
+        .expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::DistributionType,
+        DistributionTypeError::DistributionTransactionInvalid.name(),
+    );
 }
@@ -0,0 +1,80 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*},
+    context::Context,
+};
+use common::base::Byte32;
+
+use crate::{data::populate_entity_data, util::calculate_type_id, Loader};
+
+#[test]
+fn test_register_entity() {
+    // deploy contracts
+    let mut context = Context::default();
+    let entity_bin: Bytes = Loader::default().load_binary("entity-type");
+    let entity_out_point = context.deploy_cell(entity_bin);
+    let entity_cell_dep = CellDep::new_builder()
+        .out_point(entity_out_point.clone())
+        .build();
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let creator_lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .unwrap();
+    let creator_lock_hash =
+        Byte32::from_slice(creator_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare inputs
+    let capacity = 1000 * 100_000_000u64;
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(capacity.pack())
+            .lock(creator_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+
+    // prepare script: the entity id is the Type ID
+    let type_id = calculate_type_id(&input, 0);
+    let entity_type_script = context
+        .build_script(&entity_out_point, Bytes::copy_from_slice(&type_id))
+        .unwrap();
+
+    // prepare outputs data
+    let entity_id = Byte32::from_slice(&type_id).unwrap();
+    let metadata_hash = Byte32::from_slice(&[6; 32]).unwrap();
+    let entity_data = populate_entity_data(&creator_lock_hash, &entity_id, &metadata_hash);
+
+    // prepare output
+    let entity_output = CellOutput::new_builder()
+        .lock(creator_lock_script.clone())
+        .type_(Some(entity_type_script).pack())
+        .build();
+    let change_output = CellOutput::new_builder()
+        .lock(creator_lock_script.clone())
+        .build();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .cell_dep(entity_cell_dep)
+        .cell_dep(always_success_dep)
+        .input(input)
+        .outputs([entity_output, change_output])
+        .outputs_data([entity_data.as_bytes(), Bytes::from("")].pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, 10_000_000)
+        .expect("pass verification");
+    println!("consume cycles for entity registration: {}", cycles);
+}
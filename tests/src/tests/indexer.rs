@@ -0,0 +1,414 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_types::{
+        bytes::Bytes,
+        core::HeaderBuilder,
+        packed::{self, *},
+        prelude::*,
+    },
+    context::Context,
+};
+use common::base::Byte32;
+use polymeer_indexer::{Inconsistency, Indexer};
+use polymeer_sdk::{
+    merkle::{self, claimant_leaf},
+    proof::proof_type_args,
+    ClaimBuilder, FanOutBuilder, ReclaimBuilder, VaultBuilder,
+};
+
+use crate::{
+    data::{populate_distribution_data, populate_proof_data, populate_proof_reveal, to_versioned},
+    sdk::{commit_tx, create_live_cell, deploy_contracts},
+};
+
+#[test]
+fn test_index_campaign_lifecycle() {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+    let mut indexer = Indexer::new(deployment.clone());
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let admin_lock_hash: [u8; 32] = admin_lock_script.calc_script_hash().unpack();
+
+    let creator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let creator_lock_hash: [u8; 32] = creator_lock_script.calc_script_hash().unpack();
+
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![3]))
+        .unwrap();
+    let subscriber_lock_hash: [u8; 32] = subscriber_lock_script.calc_script_hash().unpack();
+
+    // prepare vault
+    let campaign_id = [1u8; 32];
+    let vault_capacity = 10000 * 100_000_000u64;
+    let fee_percentage = 500u16; // 5.00%
+    let vault_tx = VaultBuilder::new(deployment.clone(), creator_lock_hash, admin_lock_hash)
+        .campaign_id(campaign_id)
+        .capacity(vault_capacity)
+        .fee_percentage(fee_percentage)
+        .build()
+        .expect("build vault");
+    let vault = create_live_cell(
+        &mut context,
+        vault_tx.outputs().get(0).unwrap(),
+        vault_tx.outputs_data().get(0).unwrap().raw_data(),
+    );
+    indexer.index_cell(vault.clone());
+
+    // prepare the subscriber's proof
+    let proof_data = populate_proof_data(
+        &Byte32::from(subscriber_lock_hash),
+        &Byte32::from(campaign_id),
+    );
+    let proof_type_id = [4u8; 32]; // dummy type id
+    let proof_cell_capacity = 254 * 100_000_000u64;
+    let proof = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity(proof_cell_capacity.pack())
+            .lock(subscriber_lock_script.clone())
+            .type_(
+                Some(
+                    deployment
+                        .proof_type
+                        .script(proof_type_args(&deployment, &proof_type_id)),
+                )
+                .pack(),
+            )
+            .build(),
+        to_versioned(&proof_data),
+    );
+    indexer.index_cell(proof.clone());
+    assert_eq!(
+        indexer.live_vault(&campaign_id).unwrap().out_point(),
+        &vault.out_point
+    );
+    assert_eq!(indexer.live_proofs(&subscriber_lock_hash).len(), 1);
+
+    // Add cells for the admin and the subscriber to pay for fees
+    let fee_input = |context: &mut Context, lock: &Script| {
+        let out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity((200 * 100_000_000u64).pack())
+                .lock(lock.clone())
+                .build(),
+            Bytes::new(),
+        );
+        CellInput::new_builder().previous_output(out_point).build()
+    };
+
+    // fan out into two shards of two claimants: the first one holds the subscriber
+    let leaves = vec![
+        claimant_leaf(&proof_type_id, &subscriber_lock_hash, None),
+        claimant_leaf(&[5; 32], &[6; 32], None),
+    ];
+    let uniform_reward_amount = 2375 * 100_000_000u64;
+    let deadline_s = 1_000_000u64;
+    let tx = FanOutBuilder::new(deployment.clone(), vault)
+        .expect("load vault")
+        .fee_lock(admin_lock_script.clone())
+        .uniform_reward_amount(uniform_reward_amount)
+        .deadline(deadline_s)
+        .shard(merkle::merkle_root(&leaves), 2)
+        .shard([9u8; 32], 2)
+        .build()
+        .expect("build distribution");
+    let admin_fee_input = fee_input(&mut context, &admin_lock_script);
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep.clone())
+        .input(admin_fee_input)
+        .output(
+            CellOutput::new_builder()
+                .lock(admin_lock_script.clone())
+                .build(),
+        )
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+    context
+        .verify_tx(&tx, 20_000_000)
+        .expect("pass verification");
+    commit_tx(&mut context, &mut indexer, &tx);
+
+    assert!(indexer.live_vault(&campaign_id).is_none());
+    let shards = indexer.live_shards(&campaign_id);
+    assert_eq!(shards.len(), 2);
+    assert!(shards.iter().all(|shard| shard.claims_made == 0));
+    let first_shard = shards[0].out_point().clone();
+    let second_shard = shards[1].shard.cell.clone();
+
+    // claim from the first shard, found through the indexer
+    let shard = indexer
+        .live_descendant(&first_shard)
+        .unwrap()
+        .shard
+        .cell
+        .clone();
+    let proof = indexer.live_proof(&proof_type_id).unwrap().cell.clone();
+    let proof_reveal = populate_proof_reveal();
+    let tx = ClaimBuilder::new(deployment.clone(), shard, proof)
+        .expect("load shard and proof")
+        .merkle_proof(merkle::merkle_proof(&leaves, 0))
+        .proof_reveal(proof_reveal.proof().into(), proof_reveal.salt().into())
+        .build()
+        .expect("build claim");
+    let subscriber_fee_input = fee_input(&mut context, &subscriber_lock_script);
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep.clone())
+        .input(subscriber_fee_input)
+        .output(
+            CellOutput::new_builder()
+                .lock(subscriber_lock_script)
+                .build(),
+        )
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+    context
+        .verify_tx(&tx, 10_000_000)
+        .expect("pass verification");
+    commit_tx(&mut context, &mut indexer, &tx);
+
+    let claimed_shard = indexer.live_descendant(&first_shard).unwrap();
+    assert_eq!(claimed_shard.previous.as_ref(), Some(&first_shard));
+    assert_eq!(claimed_shard.claims_made, 1);
+    assert_eq!(claimed_shard.remaining_claims(), 1);
+    assert_eq!(claimed_shard.remaining_capacity(), uniform_reward_amount);
+    assert!(indexer.live_proofs(&subscriber_lock_hash).is_empty());
+
+    // reclaim the second shard after its deadline
+    let since_timestamp_s = deadline_s + 10;
+    let tx = ReclaimBuilder::new(deployment, second_shard.clone(), admin_lock_script.clone())
+        .expect("load shard")
+        .since_timestamp(since_timestamp_s)
+        .build()
+        .expect("build reclamation");
+    let header = HeaderBuilder::default()
+        .timestamp((since_timestamp_s * 1000).pack())
+        .build();
+    context.insert_header(header.clone());
+    let admin_fee_input = fee_input(&mut context, &admin_lock_script);
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .header_dep(header.hash())
+        .input(admin_fee_input)
+        .output(CellOutput::new_builder().lock(admin_lock_script).build())
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+    context
+        .verify_tx(&tx, 10_000_000)
+        .expect("pass verification");
+    commit_tx(&mut context, &mut indexer, &tx);
+
+    assert_eq!(indexer.shards(&campaign_id).len(), 3);
+    assert_eq!(indexer.live_shards(&campaign_id).len(), 1);
+    let reclamations = indexer.reclamations(&campaign_id);
+    assert_eq!(reclamations.len(), 1);
+    assert_eq!(reclamations[0].shard, second_shard.out_point);
+    assert_eq!(reclamations[0].capacity, uniform_reward_amount * 2);
+    assert_eq!(reclamations[0].unclaimed, 2);
+    assert!(indexer.shard(&second_shard.out_point).unwrap().reclaimed);
+
+    // replay the claims of both shards
+    let status = indexer
+        .claim_status(&first_shard, &leaves)
+        .expect("first shard status");
+    assert!(status.is_consistent());
+    assert_eq!(status.claimed.len(), 1);
+    assert_eq!(status.claimed[0].leaf, leaves[0]);
+    assert_eq!(status.claimed[0].proof_type_id, proof_type_id);
+    assert_eq!(status.claimed[0].shard, first_shard);
+    assert_eq!(status.unclaimed, vec![leaves[1]]);
+    assert_eq!(status.remaining_claims, 1);
+    assert_eq!(status.capacity, status.expected_capacity);
+
+    let status = indexer
+        .claim_status(&second_shard.out_point, &[[7u8; 32], [8u8; 32]])
+        .expect("second shard status");
+    assert!(status.is_consistent());
+    assert!(status.reclaimed);
+    assert!(status.live_shard.is_none());
+    assert!(status.claimed.is_empty());
+    assert_eq!(status.unclaimed.len(), 2);
+    assert_eq!(status.remaining_claims, 2);
+}
+
+#[test]
+fn test_claim_status_leaf_unknown() {
+    let (indexer, shard, leaves, claim_tx_hashes) = replay_claims(2, 0, &[0]);
+
+    // the claimed leaf is not among the leaves the shard was planned with
+    let status = indexer
+        .claim_status(&shard, &[leaves[1], [7u8; 32]])
+        .expect("shard status");
+    assert_eq!(
+        status.inconsistencies,
+        vec![Inconsistency::LeafUnknown {
+            tx_hash: claim_tx_hashes[0].clone(),
+            leaf: leaves[0],
+        }]
+    );
+}
+
+#[test]
+fn test_claim_status_leaf_claimed_twice() {
+    let (indexer, shard, leaves, claim_tx_hashes) = replay_claims(3, 0, &[0, 0]);
+
+    let status = indexer.claim_status(&shard, &leaves).expect("shard status");
+    assert_eq!(
+        status.inconsistencies,
+        vec![Inconsistency::LeafClaimedTwice {
+            tx_hash: claim_tx_hashes[1].clone(),
+            leaf: leaves[0],
+        }]
+    );
+    assert_eq!(status.unclaimed, vec![leaves[1], leaves[2]]);
+}
+
+#[test]
+fn test_claim_status_capacity_mismatch() {
+    let extra_capacity = 100_000_000u64;
+    let (indexer, shard, leaves, _) = replay_claims(2, extra_capacity, &[]);
+
+    let status = indexer.claim_status(&shard, &leaves).expect("shard status");
+    assert_eq!(
+        status.inconsistencies,
+        vec![Inconsistency::CapacityMismatch {
+            shard: shard.clone(),
+            expected: status.expected_capacity,
+            actual: status.expected_capacity + extra_capacity,
+        }]
+    );
+}
+
+#[test]
+fn test_claim_status_leaf_count_mismatch() {
+    let (indexer, shard, mut leaves, _) = replay_claims(2, 0, &[0]);
+
+    // one claim made and one left do not account for three planned leaves
+    leaves.push([7u8; 32]);
+    let status = indexer.claim_status(&shard, &leaves).expect("shard status");
+    assert_eq!(
+        status.inconsistencies,
+        vec![Inconsistency::LeafCountMismatch {
+            leaves: 3,
+            accounted: 2,
+        }]
+    );
+}
+
+/// Indexes a shard of `claims` leaves, holding `extra_capacity` on top of its
+/// rewards, then the claims of the leaves at `claimed`, in order.
+///
+/// The claims are indexed without being verified, as the replay has to report
+/// what the contracts would have refused. Returns the indexer, the shard, its
+/// leaves and the hashes of the claim transactions.
+fn replay_claims(
+    claims: u32,
+    extra_capacity: u64,
+    claimed: &[usize],
+) -> (Indexer, OutPoint, Vec<[u8; 32]>, Vec<packed::Byte32>) {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+    let mut indexer = Indexer::new(deployment.clone());
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let admin_lock_hash: [u8; 32] = admin_lock_script.calc_script_hash().unpack();
+
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![3]))
+        .unwrap();
+    let subscriber_lock_hash: [u8; 32] = subscriber_lock_script.calc_script_hash().unpack();
+
+    // prepare the shard: one leaf per claim, all for the subscriber
+    let campaign_id = [1u8; 32];
+    let proof_type_ids: Vec<[u8; 32]> = (0..claims).map(|i| [10 + i as u8; 32]).collect();
+    let leaves: Vec<[u8; 32]> = proof_type_ids
+        .iter()
+        .map(|proof_type_id| claimant_leaf(proof_type_id, &subscriber_lock_hash, None))
+        .collect();
+    let reward_amount = 100 * 100_000_000u64;
+    let dist_data = populate_distribution_data(
+        &Byte32::from(campaign_id),
+        &Byte32::from(admin_lock_hash),
+        &Byte32::from_slice(deployment.proof_type.code_hash.as_slice()).unwrap(),
+        &deployment.entity_type.packed_script_ref(),
+        &merkle::merkle_root(&leaves),
+        reward_amount,
+        1_000_000,
+        claims,
+    );
+    let shard = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity((reward_amount * claims as u64 + extra_capacity).pack())
+            .lock(deployment.distribution_lock.script(Bytes::new()))
+            .type_(Some(deployment.distribution_type.script(Bytes::new())).pack())
+            .build(),
+        to_versioned(&dist_data),
+    );
+    indexer.index_cell(shard.clone());
+
+    // claim the leaves, each with its own proof
+    let proof_data = populate_proof_data(
+        &Byte32::from(subscriber_lock_hash),
+        &Byte32::from(campaign_id),
+    );
+    let proof_reveal = populate_proof_reveal();
+    let mut claim_tx_hashes = Vec::new();
+    for &position in claimed {
+        let proof = create_live_cell(
+            &mut context,
+            CellOutput::new_builder()
+                .capacity((254 * 100_000_000u64).pack())
+                .lock(subscriber_lock_script.clone())
+                .type_(
+                    Some(
+                        deployment
+                            .proof_type
+                            .script(proof_type_args(&deployment, &proof_type_ids[position])),
+                    )
+                    .pack(),
+                )
+                .build(),
+            to_versioned(&proof_data),
+        );
+        let live_shard = indexer
+            .live_descendant(&shard.out_point)
+            .unwrap()
+            .shard
+            .cell
+            .clone();
+        let tx = ClaimBuilder::new(deployment.clone(), live_shard, proof)
+            .expect("load shard and proof")
+            .merkle_proof(merkle::merkle_proof(&leaves, position))
+            .proof_reveal(proof_reveal.proof().into(), proof_reveal.salt().into())
+            .build()
+            .expect("build claim");
+        commit_tx(&mut context, &mut indexer, &tx);
+        claim_tx_hashes.push(tx.hash());
+    }
+
+    (indexer, shard.out_point, leaves, claim_tx_hashes)
+}
@@ -1,3 +1,7 @@
+mod cycles;
 mod distribution;
+mod entity;
+mod indexer;
 mod proof;
 mod vault;
+mod vesting;
@@ -1,14 +1,59 @@
 use ckb_testtool::{
     builtin::ALWAYS_SUCCESS,
-    ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*},
+    ckb_error::Error,
+    ckb_types::{
+        bytes::Bytes,
+        core::{Cycle, TransactionBuilder},
+        packed::*,
+        prelude::*,
+    },
     context::Context,
 };
-use common::base::Byte32;
+use common::{
+    base::Byte32,
+    error::{ContractScript, ProofLockError, ProofTypeError},
+};
 
-use crate::{data::populate_proof_data, util::calculate_type_id, Loader};
+use crate::{
+    assert_script_error,
+    data::{
+        populate_distribution_data, populate_entity_data, populate_proof_data,
+        populate_proof_type_args, populate_proof_type_args_with_proof_lock, populate_script_ref,
+        to_versioned,
+    },
+    hash::get_code_hash,
+    util::calculate_type_id,
+    Loader,
+};
 
 #[test]
 fn test_create_proof() {
+    let cycles = verify_proof_creation(false, false).expect("pass verification");
+    println!("consume cycles for proof create: {}", cycles);
+}
+
+#[test]
+fn test_create_proof_guarded_by_proof_lock() {
+    let cycles = verify_proof_creation(true, false).expect("pass verification");
+    println!(
+        "consume cycles for proof create with proof lock: {}",
+        cycles
+    );
+}
+
+#[test]
+fn test_create_proof_guarded_for_another_subscriber() {
+    let err = verify_proof_creation(true, true).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::ProofType,
+        ProofTypeError::SubscriberLockHashMismatch.name(),
+    );
+}
+
+/// Creates a proof guarded by the subscriber's lock, or by the proof lock on
+/// behalf of the subscriber, or of another account when `other_subscriber`.
+fn verify_proof_creation(proof_lock: bool, other_subscriber: bool) -> Result<Cycle, Error> {
     // deploy contracts
     let mut context = Context::default();
     let proof_bin: Bytes = Loader::default().load_binary("proof-type");
@@ -22,12 +67,59 @@ fn test_create_proof() {
         .out_point(always_success_out_point.clone())
         .build();
 
+    let entity_bin: Bytes = Loader::default().load_binary("entity-type");
+    let entity_out_point = context.deploy_cell(entity_bin);
+    let entity_code_hash = get_code_hash(&mut context, &entity_out_point);
+
+    let proof_lock_bin: Bytes = Loader::default().load_binary("proof-lock");
+    let proof_lock_out_point = context.deploy_cell(proof_lock_bin);
+    let proof_lock_code_hash = get_code_hash(&mut context, &proof_lock_out_point);
+
     // prepare scripts
     let subscriber_lock_script = context
         .build_script(&always_success_out_point, Default::default())
         .unwrap();
     let subscriber_lock_hash =
         Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+    let proof_lock_script = if proof_lock {
+        let proof_lock_args = if other_subscriber {
+            Bytes::from(vec![9; 32])
+        } else {
+            subscriber_lock_hash.as_bytes()
+        };
+        context
+            .build_script(&proof_lock_out_point, proof_lock_args)
+            .unwrap()
+    } else {
+        subscriber_lock_script.clone()
+    };
+
+    // prepare the registry cell of the proof's entity
+    let creator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let creator_lock_hash =
+        Byte32::from_slice(creator_lock_script.calc_script_hash().as_slice()).unwrap();
+    let entity_id = Byte32::from_slice(&[1; 32]).unwrap();
+    let entity_data = populate_entity_data(
+        &creator_lock_hash,
+        &entity_id,
+        &Byte32::from_slice(&[6; 32]).unwrap(),
+    );
+    let entity_type_script = context
+        .build_script(&entity_out_point, entity_id.as_bytes())
+        .unwrap();
+    let entity_cell_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(creator_lock_script)
+            .type_(Some(entity_type_script).pack())
+            .build(),
+        entity_data.as_bytes(),
+    );
+    let entity_cell_dep = CellDep::new_builder()
+        .out_point(entity_cell_out_point)
+        .build();
 
     // prepare inputs
     let capacity = 1000 * 100_000_000u64;
@@ -42,11 +134,20 @@ fn test_create_proof() {
         .previous_output(input_out_point)
         .build();
 
-    // prepare script
+    // prepare script: the Type ID followed by the entity registry, the
+    // distribution type and the proof lock
+    let proof_type_id = Byte32::from_slice(&calculate_type_id(&input, 0)).unwrap();
+    let entity_registry =
+        populate_script_ref(&Byte32::from_slice(entity_code_hash.as_slice()).unwrap());
     let proof_type_script = context
         .build_script(
             &proof_out_point,
-            Bytes::copy_from_slice(&calculate_type_id(&input, 0)),
+            populate_proof_type_args_with_proof_lock(
+                &proof_type_id,
+                &entity_registry,
+                &populate_script_ref(&Byte32::from_slice(&[8; 32]).unwrap()), // dummy distribution type
+                &populate_script_ref(&Byte32::from_slice(proof_lock_code_hash.as_slice()).unwrap()),
+            ),
         )
         .unwrap();
 
@@ -56,7 +157,7 @@ fn test_create_proof() {
 
     // prepare output
     let proof_output = CellOutput::new_builder()
-        .lock(subscriber_lock_script.clone())
+        .lock(proof_lock_script)
         .type_(Some(proof_type_script).pack())
         .build();
     let change_output = CellOutput::new_builder()
@@ -67,9 +168,80 @@ fn test_create_proof() {
     let tx = TransactionBuilder::default()
         .cell_dep(proof_cell_dep)
         .cell_dep(always_success_dep)
+        .cell_dep(entity_cell_dep)
         .input(input)
         .outputs([proof_output, change_output])
-        .outputs_data([proof_data.as_bytes(), Bytes::from("")].pack())
+        .outputs_data([to_versioned(&proof_data), Bytes::from("")].pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    context.verify_tx(&tx, 10_000_000)
+}
+
+#[test]
+fn test_withdraw_proof() {
+    // deploy contracts
+    let mut context = Context::default();
+    let proof_bin: Bytes = Loader::default().load_binary("proof-type");
+    let proof_out_point = context.deploy_cell(proof_bin);
+    let proof_cell_dep = CellDep::new_builder()
+        .out_point(proof_out_point.clone())
+        .build();
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .unwrap();
+    let subscriber_lock_hash =
+        Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare data
+    let campaign_id = Byte32::from_slice(&[2; 32]).unwrap();
+    let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id);
+
+    // prepare inputs: the proof cell, withdrawn by its owner
+    let proof_type_script = context
+        .build_script(
+            &proof_out_point,
+            populate_proof_type_args(
+                &Byte32::from_slice(&[0; 32]).unwrap(), // dummy type id
+                &populate_script_ref(&Byte32::from_slice(&[7; 32]).unwrap()),
+                &populate_script_ref(&Byte32::from_slice(&[8; 32]).unwrap()),
+            ),
+        )
+        .unwrap();
+    let proof_cell_capacity = 254 * 100_000_000u64;
+    let proof_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(proof_cell_capacity.pack())
+            .lock(subscriber_lock_script.clone())
+            .type_(Some(proof_type_script).pack())
+            .build(),
+        to_versioned(&proof_data),
+    );
+    let proof_input = CellInput::new_builder()
+        .previous_output(proof_input_out_point)
+        .build();
+
+    // prepare outputs: the capacity goes back to the subscriber
+    let refund_output = CellOutput::new_builder()
+        .capacity(proof_cell_capacity.pack())
+        .lock(subscriber_lock_script)
+        .build();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .cell_dep(proof_cell_dep)
+        .cell_dep(always_success_dep)
+        .input(proof_input)
+        .output(refund_output)
+        .output_data(Bytes::new().pack())
         .build();
     let tx = context.complete_tx(tx);
 
@@ -77,5 +249,324 @@ fn test_create_proof() {
     let cycles = context
         .verify_tx(&tx, 10_000_000)
         .expect("pass verification");
-    println!("consume cycles for proof create: {}", cycles);
+    println!("consume cycles for proof withdrawal: {}", cycles);
+}
+
+#[test]
+fn test_cleanup_expired_proof() {
+    // deploy contracts
+    let mut context = Context::default();
+    let proof_bin: Bytes = Loader::default().load_binary("proof-type");
+    let proof_out_point = context.deploy_cell(proof_bin);
+    let proof_cell_dep = CellDep::new_builder()
+        .out_point(proof_out_point.clone())
+        .build();
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .unwrap();
+    let subscriber_lock_hash =
+        Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare data
+    let expiry = 1_000_000u64;
+    let campaign_id = Byte32::from_slice(&[2; 32]).unwrap();
+    let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id)
+        .as_builder()
+        .expiry(expiry.pack())
+        .build();
+
+    // prepare inputs: the expired proof cell, consumed by a third party
+    let proof_type_script = context
+        .build_script(
+            &proof_out_point,
+            populate_proof_type_args(
+                &Byte32::from_slice(&[0; 32]).unwrap(), // dummy type id
+                &populate_script_ref(&Byte32::from_slice(&[7; 32]).unwrap()),
+                &populate_script_ref(&Byte32::from_slice(&[8; 32]).unwrap()),
+            ),
+        )
+        .unwrap();
+    let proof_cell_capacity = 254 * 100_000_000u64;
+    let proof_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(proof_cell_capacity.pack())
+            .lock(subscriber_lock_script.clone())
+            .type_(Some(proof_type_script).pack())
+            .build(),
+        to_versioned(&proof_data),
+    );
+    let since = 0x4000_0000_0000_0000u64 | expiry; // absolute timestamp
+    let proof_input = CellInput::new_builder()
+        .previous_output(proof_input_out_point)
+        .since(since.pack())
+        .build();
+
+    // prepare outputs: the exact capacity goes back to the subscriber
+    let refund_output = CellOutput::new_builder()
+        .capacity(proof_cell_capacity.pack())
+        .lock(subscriber_lock_script)
+        .build();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .cell_dep(proof_cell_dep)
+        .cell_dep(always_success_dep)
+        .input(proof_input)
+        .output(refund_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, 10_000_000)
+        .expect("pass verification");
+    println!("consume cycles for expired proof cleanup: {}", cycles);
+}
+
+#[test]
+fn test_cleanup_expired_proof_by_third_party() {
+    let cycles = verify_third_party_cleanup(1_000_000).expect("pass verification");
+    println!(
+        "consume cycles for expired proof cleanup by a third party: {}",
+        cycles
+    );
+}
+
+#[test]
+fn test_cleanup_unexpired_proof_by_third_party() {
+    let err = verify_third_party_cleanup(999_999).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::ProofLock,
+        ProofLockError::UnauthorizedAction.name(),
+    );
+}
+
+/// Cleans up a proof expiring at 1,000,000, guarded by the proof lock, from a
+/// third party's transaction locked until `since_timestamp`.
+fn verify_third_party_cleanup(since_timestamp: u64) -> Result<Cycle, Error> {
+    // deploy contracts
+    let mut context = Context::default();
+    let proof_bin: Bytes = Loader::default().load_binary("proof-type");
+    let proof_out_point = context.deploy_cell(proof_bin);
+    let proof_cell_dep = CellDep::new_builder()
+        .out_point(proof_out_point.clone())
+        .build();
+
+    let proof_lock_bin: Bytes = Loader::default().load_binary("proof-lock");
+    let proof_lock_out_point = context.deploy_cell(proof_lock_bin);
+    let proof_lock_code_hash = get_code_hash(&mut context, &proof_lock_out_point);
+    let proof_lock_cell_dep = CellDep::new_builder()
+        .out_point(proof_lock_out_point.clone())
+        .build();
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts: the subscriber takes no part in the transaction
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .unwrap();
+    let subscriber_lock_hash =
+        Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+    let proof_lock_script = context
+        .build_script(&proof_lock_out_point, subscriber_lock_hash.as_bytes())
+        .unwrap();
+    let third_party_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![3]))
+        .unwrap();
+
+    // prepare data
+    let expiry = 1_000_000u64;
+    let campaign_id = Byte32::from_slice(&[2; 32]).unwrap();
+    let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id)
+        .as_builder()
+        .expiry(expiry.pack())
+        .build();
+
+    // prepare inputs: the proof cell, and a cell of the third party paying the fee
+    let proof_type_script = context
+        .build_script(
+            &proof_out_point,
+            populate_proof_type_args_with_proof_lock(
+                &Byte32::from_slice(&[0; 32]).unwrap(), // dummy type id
+                &populate_script_ref(&Byte32::from_slice(&[7; 32]).unwrap()),
+                &populate_script_ref(&Byte32::from_slice(&[8; 32]).unwrap()),
+                &populate_script_ref(&Byte32::from_slice(proof_lock_code_hash.as_slice()).unwrap()),
+            ),
+        )
+        .unwrap();
+    let proof_cell_capacity = 254 * 100_000_000u64;
+    let proof_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(proof_cell_capacity.pack())
+            .lock(proof_lock_script)
+            .type_(Some(proof_type_script).pack())
+            .build(),
+        to_versioned(&proof_data),
+    );
+    let since = 0x4000_0000_0000_0000u64 | since_timestamp; // absolute timestamp
+    let proof_input = CellInput::new_builder()
+        .previous_output(proof_input_out_point)
+        .since(since.pack())
+        .build();
+
+    let third_party_capacity = 100 * 100_000_000u64;
+    let third_party_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(third_party_capacity.pack())
+            .lock(third_party_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let third_party_input = CellInput::new_builder()
+        .previous_output(third_party_input_out_point)
+        .build();
+
+    // prepare outputs: the exact capacity goes back to the subscriber
+    let refund_output = CellOutput::new_builder()
+        .capacity(proof_cell_capacity.pack())
+        .lock(subscriber_lock_script)
+        .build();
+    let change_output = CellOutput::new_builder()
+        .capacity(third_party_capacity.pack())
+        .lock(third_party_lock_script)
+        .build();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .cell_dep(proof_cell_dep)
+        .cell_dep(proof_lock_cell_dep)
+        .cell_dep(always_success_dep)
+        .inputs([proof_input, third_party_input])
+        .outputs([refund_output, change_output])
+        .outputs_data([Bytes::new(), Bytes::new()].pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    context.verify_tx(&tx, 10_000_000)
+}
+
+#[test]
+fn test_consume_proof_with_fake_shard() {
+    // deploy contracts
+    let mut context = Context::default();
+    let proof_bin: Bytes = Loader::default().load_binary("proof-type");
+    let proof_out_point = context.deploy_cell(proof_bin);
+    let proof_cell_dep = CellDep::new_builder()
+        .out_point(proof_out_point.clone())
+        .build();
+    let proof_code_hash = get_code_hash(&mut context, &proof_out_point);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let subscriber_lock_script = context
+        .build_script(&always_success_out_point, Default::default())
+        .unwrap();
+    let subscriber_lock_hash =
+        Byte32::from_slice(subscriber_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let attacker_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let attacker_lock_hash =
+        Byte32::from_slice(attacker_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare data
+    let campaign_id = Byte32::from_slice(&[2; 32]).unwrap();
+    let proof_data = populate_proof_data(&subscriber_lock_hash, &campaign_id);
+    let entity_registry = populate_script_ref(&Byte32::from_slice(&[7; 32]).unwrap());
+
+    // prepare inputs: the proof cell, naming the real distribution type
+    let proof_type_script = context
+        .build_script(
+            &proof_out_point,
+            populate_proof_type_args(
+                &Byte32::from_slice(&[0; 32]).unwrap(), // dummy type id
+                &entity_registry,
+                &populate_script_ref(&Byte32::from_slice(&[8; 32]).unwrap()),
+            ),
+        )
+        .unwrap();
+    let proof_cell_capacity = 254 * 100_000_000u64;
+    let proof_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(proof_cell_capacity.pack())
+            .lock(subscriber_lock_script)
+            .type_(Some(proof_type_script).pack())
+            .build(),
+        to_versioned(&proof_data),
+    );
+    let proof_input = CellInput::new_builder()
+        .previous_output(proof_input_out_point)
+        .build();
+
+    // and a cell carrying shard data for the proof's campaign under another type
+    let fake_shard_data = populate_distribution_data(
+        &campaign_id,
+        &attacker_lock_hash,
+        &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
+        &entity_registry,
+        &[1; 32],
+        100 * 100_000_000u64,
+        1_000_000,
+        1,
+    );
+    let fake_shard_type_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let fake_shard_capacity = 200 * 100_000_000u64;
+    let fake_shard_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(fake_shard_capacity.pack())
+            .lock(attacker_lock_script.clone())
+            .type_(Some(fake_shard_type_script).pack())
+            .build(),
+        to_versioned(&fake_shard_data),
+    );
+    let fake_shard_input = CellInput::new_builder()
+        .previous_output(fake_shard_out_point)
+        .build();
+
+    // prepare outputs: the attacker takes the proof's capacity
+    let attacker_output = CellOutput::new_builder()
+        .capacity((proof_cell_capacity + fake_shard_capacity).pack())
+        .lock(attacker_lock_script)
+        .build();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .cell_dep(proof_cell_dep)
+        .cell_dep(always_success_dep)
+        .input(proof_input)
+        .input(fake_shard_input)
+        .output(attacker_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context
+        .verify_tx(&tx, 10_000_000)
+        .expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::ProofType,
+        ProofTypeError::ProofConsumptionInvalid.name(),
+    );
 }
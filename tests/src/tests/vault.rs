@@ -1,57 +1,54 @@
 use ckb_testtool::{
     builtin::ALWAYS_SUCCESS,
-    ckb_types::{bytes::Bytes, core::TransactionBuilder, packed::*, prelude::*},
+    ckb_error::Error,
+    ckb_types::{
+        bytes::Bytes,
+        core::{Cycle, ScriptHashType, TransactionBuilder},
+        packed::*,
+        prelude::*,
+    },
     context::Context,
 };
-use common::base::Byte32;
-
-use crate::{data::populate_vault_data, hash::get_code_hash, Loader};
+use common::{
+    base::Byte32,
+    error::{ContractScript, VaultTypeError},
+    schema::vault::VaultCellDataV1,
+    version::{parse_vault_data, LATEST_DATA_VERSION},
+};
+use polymeer_sdk::{RefundBuilder, VaultBuilder};
+
+use crate::{
+    assert_script_error,
+    data::{
+        populate_fee_model, populate_script_ref, populate_vault_data,
+        populate_vault_data_with_funders, to_versioned, to_versioned_v1,
+    },
+    hash::get_code_hash,
+    sdk::{create_live_cell, deploy_contracts},
+    Loader,
+};
 
 #[test]
 fn test_create_vault() {
     // deploy contracts
     let mut context = Context::default();
-    let vault_type_bin = Loader::default().load_binary("vault-type");
-    let vault_type_out_point = context.deploy_cell(vault_type_bin);
-    let vault_type_dep = CellDep::new_builder()
-        .out_point(vault_type_out_point.clone())
-        .build();
-
-    let vault_lock_bin = Loader::default().load_binary("vault-lock");
-    let vault_lock_out_point = context.deploy_cell(vault_lock_bin);
-    let vault_lock_dep = CellDep::new_builder()
-        .out_point(vault_lock_out_point.clone())
-        .build();
+    let deployment = deploy_contracts(&mut context);
 
     let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
     let always_success_dep = CellDep::new_builder()
         .out_point(always_success_out_point.clone())
         .build();
 
-    let dist_lock_bin = Loader::default().load_binary("distribution-lock");
-    let dist_lock_out_point = context.deploy_cell(dist_lock_bin);
-    let dist_lock_code_hash = get_code_hash(&mut context, &dist_lock_out_point);
-
-    let dist_type_bin = Loader::default().load_binary("distribution-type");
-    let dist_type_out_point = context.deploy_cell(dist_type_bin);
-    let dist_type_code_hash = get_code_hash(&mut context, &dist_type_out_point);
-
-    let proof_bin = Loader::default().load_binary("proof-type");
-    let proof_out_point = context.deploy_cell(proof_bin);
-    let proof_code_hash = get_code_hash(&mut context, &proof_out_point);
-
     // prepare scripts
     let admin_lock_script = context
         .build_script(&always_success_out_point, Bytes::from(vec![1]))
         .unwrap();
-    let admin_lock_hash =
-        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+    let admin_lock_hash: [u8; 32] = admin_lock_script.calc_script_hash().unpack();
 
     let creator_lock_script = context
         .build_script(&always_success_out_point, Bytes::from(vec![2]))
         .unwrap();
-    let creator_lock_hash =
-        Byte32::from_slice(creator_lock_script.calc_script_hash().as_slice()).unwrap();
+    let creator_lock_hash: [u8; 32] = creator_lock_script.calc_script_hash().unpack();
 
     // prepare input from creator
     let capacity = 10000 * 100_000_000u64;
@@ -66,48 +63,24 @@ fn test_create_vault() {
         .previous_output(input_out_point)
         .build();
 
-    // prepare vault lock script
-    let mut vault_lock_args = vec![];
-    vault_lock_args.extend_from_slice(creator_lock_hash.as_slice());
-    vault_lock_args.extend_from_slice(admin_lock_hash.as_slice());
-    let vault_lock_script = context
-        .build_script(&vault_lock_out_point, Bytes::from(vault_lock_args))
-        .unwrap();
-
-    // prepare vault type script args
-    let mut vault_type_args = vec![];
-    vault_type_args.extend_from_slice(dist_lock_code_hash.as_slice());
-    vault_type_args.extend_from_slice(dist_type_code_hash.as_slice());
-    let vault_type_script = context
-        .build_script(&vault_type_out_point, Bytes::from(vault_type_args))
-        .unwrap();
-
-    // prepare ouput data
+    // build the vault
     let vault_capacity = 10000 * 100_000_000u64;
     let fee_percentage = 500u16; // 5.00%
-    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
-    let vault_data = populate_vault_data(
-        &campaign_id,
-        &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
-        fee_percentage,
-    );
-
-    //prepare output
-    let vault_output = CellOutput::new_builder()
-        .lock(vault_lock_script)
-        .type_(Some(vault_type_script).pack())
-        .capacity(vault_capacity.pack())
-        .build();
+    let tx = VaultBuilder::new(deployment, creator_lock_hash, admin_lock_hash)
+        .campaign_id([1; 32])
+        .capacity(vault_capacity)
+        .fee_percentage(fee_percentage)
+        .build()
+        .expect("build vault");
+
+    // balance the transaction with the creator's input and change
     let change_output = CellOutput::new_builder().lock(creator_lock_script).build();
-
-    // build transaction
-    let tx = TransactionBuilder::default()
+    let tx = tx
+        .as_advanced_builder()
         .cell_dep(always_success_dep)
-        .cell_dep(vault_type_dep)
-        .cell_dep(vault_lock_dep)
         .input(input)
-        .outputs([vault_output, change_output])
-        .outputs_data([vault_data.as_bytes(), Bytes::from("")].pack())
+        .output(change_output)
+        .output_data(Bytes::new().pack())
         .witness(WitnessArgs::new_builder().build().as_bytes().pack())
         .build();
     let tx = context.complete_tx(tx);
@@ -120,91 +93,374 @@ fn test_create_vault() {
 }
 
 #[test]
-fn test_partial_refund_vault() {
+fn test_create_vault_malformed_type_args() {
     // deploy contracts
     let mut context = Context::default();
-    let vault_type_bin = Loader::default().load_binary("vault-type");
-    let vault_type_out_point = context.deploy_cell(vault_type_bin);
-    let vault_type_dep = CellDep::new_builder()
-        .out_point(vault_type_out_point.clone())
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
         .build();
 
-    let vault_lock_bin = Loader::default().load_binary("vault-lock");
-    let vault_lock_out_point = context.deploy_cell(vault_lock_bin);
-    let vault_lock_dep = CellDep::new_builder()
-        .out_point(vault_lock_out_point.clone())
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let admin_lock_hash: [u8; 32] = admin_lock_script.calc_script_hash().unpack();
+
+    let creator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let creator_lock_hash: [u8; 32] = creator_lock_script.calc_script_hash().unpack();
+
+    // prepare input from creator
+    let capacity = 10000 * 100_000_000u64;
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(capacity.pack())
+            .lock(creator_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
         .build();
 
+    // build the vault
+    let vault_capacity = 10000 * 100_000_000u64;
+    let fee_percentage = 500u16; // 5.00%
+    let tx = VaultBuilder::new(deployment, creator_lock_hash, admin_lock_hash)
+        .campaign_id([1; 32])
+        .capacity(vault_capacity)
+        .fee_percentage(fee_percentage)
+        .build()
+        .expect("build vault");
+
+    // append a byte to the vault type args, so they no longer decode as `VaultTypeArgs`
+    let vault_output = tx.outputs().get(0).unwrap();
+    let vault_type = vault_output.type_().to_opt().unwrap();
+    let mut vault_type_args = vault_type.args().raw_data().to_vec();
+    vault_type_args.push(0);
+    let vault_output = vault_output
+        .as_builder()
+        .type_(
+            Some(
+                vault_type
+                    .as_builder()
+                    .args(Bytes::from(vault_type_args).pack())
+                    .build(),
+            )
+            .pack(),
+        )
+        .build();
+    let mut outputs: Vec<CellOutput> = tx.outputs().into_iter().collect();
+    outputs[0] = vault_output;
+
+    // balance the transaction with the creator's input and change
+    let change_output = CellOutput::new_builder().lock(creator_lock_script).build();
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs(outputs)
+        .cell_dep(always_success_dep)
+        .input(input)
+        .output(change_output)
+        .output_data(Bytes::new().pack())
+        .witness(WitnessArgs::new_builder().build().as_bytes().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context
+        .verify_tx(&tx, 10_000_000)
+        .expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::VaultType,
+        VaultTypeError::ArgumentLengthInvalid.name(),
+    );
+}
+
+#[test]
+fn test_create_vault_malformed_lock_args() {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
     let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
     let always_success_dep = CellDep::new_builder()
         .out_point(always_success_out_point.clone())
         .build();
 
-    let dist_lock_bin = Loader::default().load_binary("distribution-lock");
-    let dist_lock_out_point = context.deploy_cell(dist_lock_bin);
-    let dist_lock_code_hash = get_code_hash(&mut context, &dist_lock_out_point);
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let admin_lock_hash: [u8; 32] = admin_lock_script.calc_script_hash().unpack();
 
-    let dist_type_bin = Loader::default().load_binary("distribution-type");
-    let dist_type_out_point = context.deploy_cell(dist_type_bin);
-    let dist_type_code_hash = get_code_hash(&mut context, &dist_type_out_point);
+    let creator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let creator_lock_hash: [u8; 32] = creator_lock_script.calc_script_hash().unpack();
 
-    let proof_bin = Loader::default().load_binary("proof-type");
-    let proof_out_point = context.deploy_cell(proof_bin);
-    let proof_code_hash = get_code_hash(&mut context, &proof_out_point);
+    // prepare input from creator
+    let capacity = 10000 * 100_000_000u64;
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(capacity.pack())
+            .lock(creator_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+
+    // build the vault
+    let vault_capacity = 10000 * 100_000_000u64;
+    let fee_percentage = 500u16; // 5.00%
+    let tx = VaultBuilder::new(deployment, creator_lock_hash, admin_lock_hash)
+        .campaign_id([1; 32])
+        .capacity(vault_capacity)
+        .fee_percentage(fee_percentage)
+        .build()
+        .expect("build vault");
+
+    // append a byte to the vault lock args, so they no longer decode as `VaultLockArgs`
+    let vault_output = tx.outputs().get(0).unwrap();
+    let vault_lock = vault_output.lock();
+    let mut vault_lock_args = vault_lock.args().raw_data().to_vec();
+    vault_lock_args.push(0);
+    let vault_output = vault_output
+        .as_builder()
+        .lock(
+            vault_lock
+                .as_builder()
+                .args(Bytes::from(vault_lock_args).pack())
+                .build(),
+        )
+        .build();
+    let mut outputs: Vec<CellOutput> = tx.outputs().into_iter().collect();
+    outputs[0] = vault_output;
+
+    // balance the transaction with the creator's input and change
+    let change_output = CellOutput::new_builder().lock(creator_lock_script).build();
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs(outputs)
+        .cell_dep(always_success_dep)
+        .input(input)
+        .output(change_output)
+        .output_data(Bytes::new().pack())
+        .witness(WitnessArgs::new_builder().build().as_bytes().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context
+        .verify_tx(&tx, 10_000_000)
+        .expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::VaultType,
+        VaultTypeError::ArgumentLengthInvalid.name(),
+    );
+}
+
+#[test]
+fn test_create_vault_flat_fee_exceeds_capacity() {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
 
     // prepare scripts
     let admin_lock_script = context
         .build_script(&always_success_out_point, Bytes::from(vec![1]))
         .unwrap();
-    let admin_lock_hash =
-        Byte32::from_slice(admin_lock_script.calc_script_hash().as_slice()).unwrap();
+    let admin_lock_hash: [u8; 32] = admin_lock_script.calc_script_hash().unpack();
 
     let creator_lock_script = context
         .build_script(&always_success_out_point, Bytes::from(vec![2]))
         .unwrap();
-    let creator_lock_hash =
-        Byte32::from_slice(creator_lock_script.calc_script_hash().as_slice()).unwrap();
+    let creator_lock_hash: [u8; 32] = creator_lock_script.calc_script_hash().unpack();
 
-    // prepare vault lock script
-    let mut vault_lock_args = vec![];
-    vault_lock_args.extend_from_slice(creator_lock_hash.as_slice());
-    vault_lock_args.extend_from_slice(admin_lock_hash.as_slice());
-    let vault_lock_script = context
-        .build_script(&vault_lock_out_point, Bytes::from(vault_lock_args))
+    // prepare input from creator
+    let capacity = 10000 * 100_000_000u64;
+    let input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(capacity.pack())
+            .lock(creator_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let input = CellInput::new_builder()
+        .previous_output(input_out_point)
+        .build();
+
+    // build the vault
+    let vault_capacity = 10000 * 100_000_000u64;
+    let tx = VaultBuilder::new(deployment, creator_lock_hash, admin_lock_hash)
+        .campaign_id([1; 32])
+        .capacity(vault_capacity)
+        .build()
+        .expect("build vault");
+
+    // switch to a flat fee the vault can never pay, which the SDK refuses to build
+    let vault_data = parse_vault_data(&tx.outputs_data().get(0).unwrap().raw_data()).unwrap();
+    let vault_data = vault_data
+        .as_builder()
+        .fee_percentage(0u16.pack())
+        .fee_model(populate_fee_model(1, vault_capacity + 1, 0, 0))
+        .build();
+    let mut outputs_data: Vec<Bytes> = tx
+        .outputs_data()
+        .into_iter()
+        .map(|data| data.raw_data())
+        .collect();
+    outputs_data[0] = to_versioned(&vault_data);
+
+    // balance the transaction with the creator's input and change
+    let change_output = CellOutput::new_builder().lock(creator_lock_script).build();
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs_data(outputs_data.into_iter().map(|data| data.pack()).collect())
+        .cell_dep(always_success_dep)
+        .input(input)
+        .output(change_output)
+        .output_data(Bytes::new().pack())
+        .witness(WitnessArgs::new_builder().build().as_bytes().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context
+        .verify_tx(&tx, 10_000_000)
+        .expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::VaultType,
+        VaultTypeError::FeeModelInvalid.name(),
+    );
+}
+
+#[test]
+fn test_partial_refund_vault() {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
         .unwrap();
+    let admin_lock_hash: [u8; 32] = admin_lock_script.calc_script_hash().unpack();
 
-    // prepare vault type script
-    let mut vault_type_args = vec![];
-    vault_type_args.extend_from_slice(dist_lock_code_hash.as_slice());
-    vault_type_args.extend_from_slice(dist_type_code_hash.as_slice());
-    let vault_type_script = context
-        .build_script(&vault_type_out_point, Bytes::from(vault_type_args))
+    let creator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
         .unwrap();
+    let creator_lock_hash: [u8; 32] = creator_lock_script.calc_script_hash().unpack();
 
-    // prepare data
+    // prepare vault
     let vault_capacity = 10000 * 100_000_000u64;
     let refund_capacity = 1000 * 100_000_000u64;
     let fee_percentage = 500u16; // 5.00%
-    let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
-
-    let vault_data = populate_vault_data(
-        &campaign_id,
-        &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
-        fee_percentage,
+    let vault_tx = VaultBuilder::new(deployment.clone(), creator_lock_hash, admin_lock_hash)
+        .campaign_id([1; 32])
+        .capacity(vault_capacity)
+        .fee_percentage(fee_percentage)
+        .build()
+        .expect("build vault");
+    let vault = create_live_cell(
+        &mut context,
+        vault_tx.outputs().get(0).unwrap(),
+        vault_tx.outputs_data().get(0).unwrap().raw_data(),
     );
 
-    let vault_input_out_point = context.create_cell(
+    // Add a cell for the creator to pay for fees and provide signature
+    let creator_fee_input_out_point = context.create_cell(
         CellOutput::new_builder()
-            .capacity(vault_capacity.pack())
-            .lock(vault_lock_script.clone())
-            .type_(Some(vault_type_script.clone()).pack())
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(creator_lock_script.clone())
             .build(),
-        vault_data.clone().as_bytes(),
+        Bytes::new(),
     );
-    let vault_input = CellInput::new_builder()
-        .previous_output(vault_input_out_point)
+    let creator_fee_input = CellInput::new_builder()
+        .previous_output(creator_fee_input_out_point)
+        .build();
+
+    // build the refund
+    let tx = RefundBuilder::new(deployment, vault)
+        .expect("load vault")
+        .funder_lock(creator_lock_script.clone())
+        .partial(creator_lock_hash, refund_capacity)
+        .build()
+        .expect("build refund");
+
+    // balance the transaction with the creator's input and change
+    let creator_change_output = CellOutput::new_builder().lock(creator_lock_script).build();
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .input(creator_fee_input)
+        .output(creator_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, 10_000_000)
+        .expect("pass verification");
+    println!("consume cycles for vault partial refund: {}", cycles);
+}
+
+#[test]
+fn test_full_refund_vault() {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
         .build();
 
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let admin_lock_hash: [u8; 32] = admin_lock_script.calc_script_hash().unpack();
+
+    let creator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let creator_lock_hash: [u8; 32] = creator_lock_script.calc_script_hash().unpack();
+
+    // prepare vault
+    let vault_capacity = 10000 * 100_000_000u64;
+    let fee_percentage = 500u16; // 5.00%
+    let vault_tx = VaultBuilder::new(deployment.clone(), creator_lock_hash, admin_lock_hash)
+        .campaign_id([1; 32])
+        .capacity(vault_capacity)
+        .fee_percentage(fee_percentage)
+        .build()
+        .expect("build vault");
+    let vault = create_live_cell(
+        &mut context,
+        vault_tx.outputs().get(0).unwrap(),
+        vault_tx.outputs_data().get(0).unwrap().raw_data(),
+    );
+
     // Add a cell for the creator to pay for fees and provide signature
     let creator_fee_input_out_point = context.create_cell(
         CellOutput::new_builder()
@@ -217,26 +473,21 @@ fn test_partial_refund_vault() {
         .previous_output(creator_fee_input_out_point)
         .build();
 
-    // prepare output
-    let vault_output = CellOutput::new_builder()
-        .capacity((vault_capacity - refund_capacity).pack())
-        .lock(vault_lock_script.clone())
-        .type_(Some(vault_type_script).pack())
-        .build();
-    let refund_output = CellOutput::new_builder()
-        .capacity(refund_capacity.pack())
-        .lock(creator_lock_script.clone())
-        .build();
-    let creator_change_output = CellOutput::new_builder().lock(creator_lock_script).build();
+    // build the refund: without a ledger, everything goes back to the creator
+    let tx = RefundBuilder::new(deployment, vault)
+        .expect("load vault")
+        .funder_lock(creator_lock_script.clone())
+        .build()
+        .expect("build refund");
 
-    // build transaction
-    let tx = TransactionBuilder::default()
+    // balance the transaction with the creator's input and change
+    let creator_change_output = CellOutput::new_builder().lock(creator_lock_script).build();
+    let tx = tx
+        .as_advanced_builder()
         .cell_dep(always_success_dep)
-        .cell_dep(vault_type_dep)
-        .cell_dep(vault_lock_dep)
-        .inputs([vault_input, creator_fee_input])
-        .outputs([vault_output, refund_output, creator_change_output])
-        .outputs_data([vault_data.as_bytes(), Bytes::from(""), Bytes::from("")].pack())
+        .input(creator_fee_input)
+        .output(creator_change_output)
+        .output_data(Bytes::new().pack())
         .build();
     let tx = context.complete_tx(tx);
 
@@ -244,11 +495,11 @@ fn test_partial_refund_vault() {
     let cycles = context
         .verify_tx(&tx, 10_000_000)
         .expect("pass verification");
-    println!("consume cycles for vault partial refund: {}", cycles);
+    println!("consume cycles for vault full refund: {}", cycles);
 }
 
 #[test]
-fn test_full_refund_vault() {
+fn test_top_up_vault_by_sponsor() {
     // deploy contracts
     let mut context = Context::default();
     let vault_type_bin = Loader::default().load_binary("vault-type");
@@ -280,6 +531,12 @@ fn test_full_refund_vault() {
     let proof_out_point = context.deploy_cell(proof_bin);
     let proof_code_hash = get_code_hash(&mut context, &proof_out_point);
 
+    let entity_bin = Loader::default().load_binary("entity-type");
+    let entity_out_point = context.deploy_cell(entity_bin);
+    let entity_registry = populate_script_ref(
+        &Byte32::from_slice(get_code_hash(&mut context, &entity_out_point).as_slice()).unwrap(),
+    );
+
     // prepare scripts
     let admin_lock_script = context
         .build_script(&always_success_out_point, Bytes::from(vec![1]))
@@ -293,6 +550,12 @@ fn test_full_refund_vault() {
     let creator_lock_hash =
         Byte32::from_slice(creator_lock_script.calc_script_hash().as_slice()).unwrap();
 
+    let sponsor_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![3]))
+        .unwrap();
+    let sponsor_lock_hash =
+        Byte32::from_slice(sponsor_lock_script.calc_script_hash().as_slice()).unwrap();
+
     // prepare vault lock script
     let mut vault_lock_args = vec![];
     vault_lock_args.extend_from_slice(creator_lock_hash.as_slice());
@@ -304,20 +567,35 @@ fn test_full_refund_vault() {
     // prepare vault type script
     let mut vault_type_args = vec![];
     vault_type_args.extend_from_slice(dist_lock_code_hash.as_slice());
+    vault_type_args.push(ScriptHashType::Type.into());
     vault_type_args.extend_from_slice(dist_type_code_hash.as_slice());
+    vault_type_args.push(ScriptHashType::Type.into());
     let vault_type_script = context
         .build_script(&vault_type_out_point, Bytes::from(vault_type_args))
         .unwrap();
 
-    // prepare data
+    // prepare data: the vault starts without a ledger, so it belongs to the creator
     let vault_capacity = 10000 * 100_000_000u64;
+    let top_up_capacity = 5000 * 100_000_000u64;
     let fee_percentage = 500u16; // 5.00%
     let campaign_id = Byte32::from_slice(&[1; 32]).unwrap();
+    let proof_code_hash = Byte32::from_slice(proof_code_hash.as_slice()).unwrap();
 
-    let vault_data = populate_vault_data(
+    let input_vault_data = populate_vault_data(
+        &campaign_id,
+        &proof_code_hash,
+        &entity_registry,
+        fee_percentage,
+    );
+    let output_vault_data = populate_vault_data_with_funders(
         &campaign_id,
-        &Byte32::from_slice(proof_code_hash.as_slice()).unwrap(),
+        &proof_code_hash,
+        &entity_registry,
         fee_percentage,
+        &[
+            (creator_lock_hash.clone(), vault_capacity),
+            (sponsor_lock_hash.clone(), top_up_capacity),
+        ],
     );
 
     let vault_input_out_point = context.create_cell(
@@ -326,40 +604,60 @@ fn test_full_refund_vault() {
             .lock(vault_lock_script.clone())
             .type_(Some(vault_type_script.clone()).pack())
             .build(),
-        vault_data.clone().as_bytes(),
+        to_versioned(&input_vault_data),
     );
     let vault_input = CellInput::new_builder()
         .previous_output(vault_input_out_point)
         .build();
 
-    // Add a cell for the creator to pay for fees and provide signature
-    let creator_fee_input_out_point = context.create_cell(
+    // Add a cell for the creator to provide signature
+    let creator_input_out_point = context.create_cell(
         CellOutput::new_builder()
             .capacity((200 * 100_000_000u64).pack())
             .lock(creator_lock_script.clone())
             .build(),
         Bytes::new(),
     );
-    let creator_fee_input = CellInput::new_builder()
-        .previous_output(creator_fee_input_out_point)
+    let creator_input = CellInput::new_builder()
+        .previous_output(creator_input_out_point)
         .build();
 
-    // prepare output
-    let refund_output = CellOutput::new_builder()
-        .capacity(vault_capacity.pack())
-        .lock(creator_lock_script.clone())
+    // Add a cell for the sponsor to fund the top-up
+    let sponsor_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((top_up_capacity + 200 * 100_000_000u64).pack())
+            .lock(sponsor_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let sponsor_input = CellInput::new_builder()
+        .previous_output(sponsor_input_out_point)
         .build();
 
+    // prepare output
+    let vault_output = CellOutput::new_builder()
+        .capacity((vault_capacity + top_up_capacity).pack())
+        .lock(vault_lock_script)
+        .type_(Some(vault_type_script).pack())
+        .build();
     let creator_change_output = CellOutput::new_builder().lock(creator_lock_script).build();
+    let sponsor_change_output = CellOutput::new_builder().lock(sponsor_lock_script).build();
 
     // build transaction
     let tx = TransactionBuilder::default()
         .cell_dep(always_success_dep)
         .cell_dep(vault_type_dep)
         .cell_dep(vault_lock_dep)
-        .inputs([vault_input, creator_fee_input])
-        .outputs([refund_output, creator_change_output])
-        .outputs_data([Bytes::new().pack(), Bytes::new().pack()])
+        .inputs([vault_input, creator_input, sponsor_input])
+        .outputs([vault_output, creator_change_output, sponsor_change_output])
+        .outputs_data(
+            [
+                to_versioned(&output_vault_data),
+                Bytes::from(""),
+                Bytes::from(""),
+            ]
+            .pack(),
+        )
         .build();
     let tx = context.complete_tx(tx);
 
@@ -367,5 +665,207 @@ fn test_full_refund_vault() {
     let cycles = context
         .verify_tx(&tx, 10_000_000)
         .expect("pass verification");
-    println!("consume cycles for vault full refund: {}", cycles);
+    println!("consume cycles for vault sponsor top-up: {}", cycles);
+}
+
+#[test]
+fn test_full_refund_vault_pro_rata() {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let admin_lock_hash: [u8; 32] = admin_lock_script.calc_script_hash().unpack();
+
+    let creator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let creator_lock_hash: [u8; 32] = creator_lock_script.calc_script_hash().unpack();
+
+    let sponsor_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![3]))
+        .unwrap();
+    let sponsor_lock_hash: [u8; 32] = sponsor_lock_script.calc_script_hash().unpack();
+
+    // prepare vault: the creator funded 2/3 of the vault and the sponsor 1/3
+    let creator_amount = 10000 * 100_000_000u64;
+    let sponsor_amount = 5000 * 100_000_000u64;
+    let vault_capacity = creator_amount + sponsor_amount;
+    let fee_percentage = 500u16; // 5.00%
+    let vault_tx = VaultBuilder::new(deployment.clone(), creator_lock_hash, admin_lock_hash)
+        .campaign_id([1; 32])
+        .capacity(vault_capacity)
+        .fee_percentage(fee_percentage)
+        .funder(creator_lock_hash, creator_amount)
+        .funder(sponsor_lock_hash, sponsor_amount)
+        .build()
+        .expect("build vault");
+    let vault = create_live_cell(
+        &mut context,
+        vault_tx.outputs().get(0).unwrap(),
+        vault_tx.outputs_data().get(0).unwrap().raw_data(),
+    );
+
+    // Add a cell for the creator to pay for fees and provide signature
+    let creator_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(creator_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let creator_fee_input = CellInput::new_builder()
+        .previous_output(creator_fee_input_out_point)
+        .build();
+
+    // build the refund: every funder gets its share back
+    let tx = RefundBuilder::new(deployment, vault)
+        .expect("load vault")
+        .funder_lock(creator_lock_script.clone())
+        .funder_lock(sponsor_lock_script)
+        .build()
+        .expect("build refund");
+
+    // balance the transaction with the creator's input and change
+    let creator_change_output = CellOutput::new_builder().lock(creator_lock_script).build();
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .input(creator_fee_input)
+        .output(creator_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, 10_000_000)
+        .expect("pass verification");
+    println!("consume cycles for vault pro-rata refund: {}", cycles);
+}
+
+#[test]
+fn test_full_refund_vault_v1() {
+    // a vault created with the first data version is still refunded
+    let vault_data = VaultCellDataV1::new_builder()
+        .campaign_id(Byte32::from_slice(&[1; 32]).unwrap())
+        .fee_percentage(500u16.pack())
+        .proof_script_code_hash(Byte32::from_slice(&[2; 32]).unwrap())
+        .build();
+    verify_full_refund_of(to_versioned_v1(&vault_data)).expect("pass verification");
+}
+
+#[test]
+fn test_full_refund_vault_unknown_version() {
+    let vault_data = populate_vault_data(
+        &Byte32::from_slice(&[1; 32]).unwrap(),
+        &Byte32::from_slice(&[2; 32]).unwrap(),
+        &populate_script_ref(&Byte32::from_slice(&[3; 32]).unwrap()),
+        500,
+    );
+    let mut data = vec![LATEST_DATA_VERSION + 1];
+    data.extend_from_slice(vault_data.as_slice());
+    let err = verify_full_refund_of(data.into()).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::VaultType,
+        VaultTypeError::VaultDataInvalid.name(),
+    );
+}
+
+#[test]
+fn test_full_refund_vault_unversioned() {
+    // the bare layout from before data versioning: campaign id, fee percentage
+    // and proof script code hash, which is not misread as a known version
+    let mut data = vec![1u8; 32];
+    data.extend_from_slice(&500u16.to_le_bytes());
+    data.extend_from_slice(&[2u8; 32]);
+    let err = verify_full_refund_of(data.into()).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::VaultType,
+        VaultTypeError::VaultDataInvalid.name(),
+    );
+}
+
+/// Fully refunds to its creator a vault holding `vault_data`. The SDK builds
+/// the refund of a vault with the latest data, which is then swapped for it.
+fn verify_full_refund_of(vault_data: Bytes) -> Result<Cycle, Error> {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let admin_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let admin_lock_hash: [u8; 32] = admin_lock_script.calc_script_hash().unpack();
+
+    let creator_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![2]))
+        .unwrap();
+    let creator_lock_hash: [u8; 32] = creator_lock_script.calc_script_hash().unpack();
+
+    // prepare vault
+    let vault_capacity = 10000 * 100_000_000u64;
+    let vault_tx = VaultBuilder::new(deployment.clone(), creator_lock_hash, admin_lock_hash)
+        .campaign_id([1; 32])
+        .capacity(vault_capacity)
+        .fee_percentage(500)
+        .build()
+        .expect("build vault");
+    let vault = create_live_cell(
+        &mut context,
+        vault_tx.outputs().get(0).unwrap(),
+        vault_tx.outputs_data().get(0).unwrap().raw_data(),
+    );
+    let old_vault = create_live_cell(&mut context, vault_tx.outputs().get(0).unwrap(), vault_data);
+
+    // Add a cell for the creator to pay for fees and provide signature
+    let creator_fee_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((200 * 100_000_000u64).pack())
+            .lock(creator_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let creator_fee_input = CellInput::new_builder()
+        .previous_output(creator_fee_input_out_point)
+        .build();
+
+    // build the refund: without a ledger, everything goes back to the creator
+    let tx = RefundBuilder::new(deployment, vault)
+        .expect("load vault")
+        .funder_lock(creator_lock_script.clone())
+        .build()
+        .expect("build refund");
+
+    // balance the transaction with the creator's input and change, spending
+    // the vault under test instead
+    let creator_change_output = CellOutput::new_builder().lock(creator_lock_script).build();
+    let tx = tx
+        .as_advanced_builder()
+        .set_inputs(vec![old_vault.input(0)])
+        .cell_dep(always_success_dep)
+        .input(creator_fee_input)
+        .output(creator_change_output)
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    context.verify_tx(&tx, 10_000_000)
 }
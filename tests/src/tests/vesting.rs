@@ -0,0 +1,302 @@
+use ckb_testtool::{
+    builtin::ALWAYS_SUCCESS,
+    ckb_error::Error,
+    ckb_types::{
+        bytes::Bytes,
+        core::{Cycle, EpochNumberWithFraction, TransactionBuilder},
+        packed::*,
+        prelude::*,
+    },
+    context::Context,
+};
+use common::{
+    base::Byte32,
+    error::{ContractScript, VestingLockError},
+};
+use polymeer_sdk::VestingWithdrawalBuilder;
+
+use crate::{
+    assert_script_error,
+    data::populate_vesting_lock_args,
+    sdk::{create_live_cell, deploy_contracts},
+    Loader,
+};
+
+// The `since` flag for an absolute epoch number.
+const SINCE_ABSOLUTE_EPOCH_FLAG: u64 = 0x2000_0000_0000_0000;
+
+#[test]
+fn test_withdraw_vested_reward() {
+    let cycles = verify_vested_reward_withdrawal(1000 * 100_000_000u64).expect("pass verification");
+    println!("consume cycles for vested reward withdrawal: {}", cycles);
+}
+
+#[test]
+fn test_withdraw_vested_reward_relocked_below_occupied_capacity() {
+    // The unvested 50 CKB are relocked in a cell occupying more than that
+    let err = verify_vested_reward_withdrawal(100 * 100_000_000u64).expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::VestingLock,
+        VestingLockError::RelockInvalid.name(),
+    );
+}
+
+/// Withdraws the vested half of a vesting cell locking `locked_amount`, and
+/// relocks the unvested half as it is.
+fn verify_vested_reward_withdrawal(locked_amount: u64) -> Result<Cycle, Error> {
+    // deploy contracts
+    let mut context = Context::default();
+    let vesting_lock_bin = Loader::default().load_binary("vesting-lock");
+    let vesting_lock_out_point = context.deploy_cell(vesting_lock_bin);
+    let vesting_lock_dep = CellDep::new_builder()
+        .out_point(vesting_lock_out_point.clone())
+        .build();
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let beneficiary_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let beneficiary_lock_hash =
+        Byte32::from_slice(beneficiary_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    let start_epoch = 10u64;
+    let end_epoch = 20u64;
+    let vesting_lock_args = populate_vesting_lock_args(
+        &beneficiary_lock_hash,
+        locked_amount,
+        start_epoch,
+        end_epoch,
+    );
+    let vesting_lock_script = context
+        .build_script(&vesting_lock_out_point, vesting_lock_args.as_bytes())
+        .unwrap();
+
+    // prepare inputs: the vesting cell, halfway through the vesting period
+    let vesting_cell_capacity = locked_amount + 254 * 100_000_000u64;
+    let vesting_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(vesting_cell_capacity.pack())
+            .lock(vesting_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let current_epoch = EpochNumberWithFraction::new(15, 0, 1);
+    let vesting_input = CellInput::new_builder()
+        .previous_output(vesting_input_out_point)
+        .since((SINCE_ABSOLUTE_EPOCH_FLAG | current_epoch.full_value()).pack())
+        .build();
+
+    // The beneficiary authorizes the withdrawal with one of its own cells
+    let beneficiary_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((100 * 100_000_000u64).pack())
+            .lock(beneficiary_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let beneficiary_input = CellInput::new_builder()
+        .previous_output(beneficiary_input_out_point)
+        .build();
+
+    // prepare outputs: half of the locked amount has vested and is withdrawn,
+    // the other half vests from now on
+    let unvested_amount = locked_amount / 2;
+    let relock_args = populate_vesting_lock_args(
+        &beneficiary_lock_hash,
+        unvested_amount,
+        current_epoch.number(),
+        end_epoch,
+    );
+    let relock_script = context
+        .build_script(&vesting_lock_out_point, relock_args.as_bytes())
+        .unwrap();
+    let relocked_output = CellOutput::new_builder()
+        .capacity(unvested_amount.pack())
+        .lock(relock_script)
+        .build();
+    let beneficiary_output = CellOutput::new_builder()
+        .capacity((vesting_cell_capacity - unvested_amount).pack())
+        .lock(beneficiary_lock_script)
+        .build();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .cell_dep(vesting_lock_dep)
+        .cell_dep(always_success_dep)
+        .inputs([vesting_input, beneficiary_input])
+        .outputs([relocked_output, beneficiary_output])
+        .outputs_data([Bytes::new(), Bytes::new()].pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    context.verify_tx(&tx, 10_000_000)
+}
+
+#[test]
+fn test_withdraw_vested_rewards_of_a_position() {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let beneficiary_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let beneficiary_lock_hash =
+        Byte32::from_slice(beneficiary_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare inputs: a reward vesting from epoch 10, and the relock of another
+    // reward of the same position, vesting from epoch 15
+    let end_epoch = 20u64;
+    let vesting_cells: Vec<_> = [(1000 * 100_000_000u64, 10u64), (500 * 100_000_000, 15)]
+        .into_iter()
+        .map(|(locked_amount, start_epoch)| {
+            let args = populate_vesting_lock_args(
+                &beneficiary_lock_hash,
+                locked_amount,
+                start_epoch,
+                end_epoch,
+            );
+            create_live_cell(
+                &mut context,
+                CellOutput::new_builder()
+                    .capacity(locked_amount.pack())
+                    .lock(deployment.vesting_lock.script(args.as_bytes()))
+                    .build(),
+                Bytes::new(),
+            )
+        })
+        .collect();
+
+    // The beneficiary authorizes the withdrawal with one of its own cells
+    let beneficiary_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((100 * 100_000_000u64).pack())
+            .lock(beneficiary_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let beneficiary_input = CellInput::new_builder()
+        .previous_output(beneficiary_input_out_point)
+        .build();
+
+    // build the withdrawal at epoch 18: a fifth of the first reward and two
+    // fifths of the relock have not vested yet
+    let builder =
+        VestingWithdrawalBuilder::new(deployment, vesting_cells, beneficiary_lock_script.clone())
+            .expect("load vesting cells")
+            .epoch(18);
+    assert_eq!(builder.unvested(), 400 * 100_000_000);
+    let tx = builder.build().expect("build withdrawal");
+
+    let tx = tx
+        .as_advanced_builder()
+        .cell_dep(always_success_dep)
+        .input(beneficiary_input)
+        .output(
+            CellOutput::new_builder()
+                .lock(beneficiary_lock_script)
+                .build(),
+        )
+        .output_data(Bytes::new().pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let cycles = context
+        .verify_tx(&tx, 10_000_000)
+        .expect("pass verification");
+    println!("consume cycles for vested rewards withdrawal: {}", cycles);
+}
+
+#[test]
+fn test_withdraw_vested_reward_keeping_start_epoch() {
+    // deploy contracts
+    let mut context = Context::default();
+    let deployment = deploy_contracts(&mut context);
+
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success_dep = CellDep::new_builder()
+        .out_point(always_success_out_point.clone())
+        .build();
+
+    // prepare scripts
+    let beneficiary_lock_script = context
+        .build_script(&always_success_out_point, Bytes::from(vec![1]))
+        .unwrap();
+    let beneficiary_lock_hash =
+        Byte32::from_slice(beneficiary_lock_script.calc_script_hash().as_slice()).unwrap();
+
+    // prepare inputs: the vesting cell, halfway through the vesting period
+    let locked_amount = 1000 * 100_000_000u64;
+    let vesting_lock_args =
+        populate_vesting_lock_args(&beneficiary_lock_hash, locked_amount, 10, 20);
+    let vesting_cell = create_live_cell(
+        &mut context,
+        CellOutput::new_builder()
+            .capacity(locked_amount.pack())
+            .lock(deployment.vesting_lock.script(vesting_lock_args.as_bytes()))
+            .build(),
+        Bytes::new(),
+    );
+    let current_epoch = EpochNumberWithFraction::new(15, 0, 1);
+
+    let beneficiary_input_out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity((100 * 100_000_000u64).pack())
+            .lock(beneficiary_lock_script.clone())
+            .build(),
+        Bytes::new(),
+    );
+    let beneficiary_input = CellInput::new_builder()
+        .previous_output(beneficiary_input_out_point)
+        .build();
+
+    // prepare outputs: the unvested half is relocked with the original start
+    // epoch, which would release it twice as fast
+    let unvested_amount = locked_amount / 2;
+    let relock_args = populate_vesting_lock_args(&beneficiary_lock_hash, unvested_amount, 10, 20);
+    let relocked_output = CellOutput::new_builder()
+        .capacity(unvested_amount.pack())
+        .lock(deployment.vesting_lock.script(relock_args.as_bytes()))
+        .build();
+    let beneficiary_output = CellOutput::new_builder()
+        .capacity((locked_amount - unvested_amount).pack())
+        .lock(beneficiary_lock_script)
+        .build();
+
+    // build transaction
+    let tx = TransactionBuilder::default()
+        .cell_dep(deployment.vesting_lock.cell_dep.clone())
+        .cell_dep(always_success_dep)
+        .inputs([
+            vesting_cell.input(SINCE_ABSOLUTE_EPOCH_FLAG | current_epoch.full_value()),
+            beneficiary_input,
+        ])
+        .outputs([relocked_output, beneficiary_output])
+        .outputs_data([Bytes::new(), Bytes::new()].pack())
+        .build();
+    let tx = context.complete_tx(tx);
+
+    // run
+    let err = context
+        .verify_tx(&tx, 10_000_000)
+        .expect_err("fail verification");
+    assert_script_error(
+        &err,
+        ContractScript::VestingLock,
+        VestingLockError::RelockInvalid.name(),
+    );
+}